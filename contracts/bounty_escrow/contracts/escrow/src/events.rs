@@ -705,3 +705,71 @@ pub fn emit_settlement_completed(env: &Env, event: SettlementCompleted) {
     let topics = (symbol_short!("settle_done"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
+
+// ------------------------------------------------------------------------
+// Bounty Dispute Events
+// ------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaised {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_raised(env: &Env, event: DisputeRaised) {
+    let topics = (symbol_short!("disp_open"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolved {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub paid_contributor: bool,
+    pub recipient: Address,
+    pub amount: i128,
+    pub resolved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("disp_res"), event.bounty_id);
+    env.events().publish(topics, event.clone());
+}
+
+// ------------------------------------------------------------------------
+// Admin Rotation Events
+// ------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    pub version: u32,
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_proposed(env: &Env, event: AdminProposed) {
+    let topics = (symbol_short!("adm_prop"),);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChanged {
+    pub version: u32,
+    pub previous_admin: Address,
+    pub new_admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_changed(env: &Env, event: AdminChanged) {
+    let topics = (symbol_short!("adm_chg"),);
+    env.events().publish(topics, event.clone());
+}
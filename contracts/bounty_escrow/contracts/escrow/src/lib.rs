@@ -29,6 +29,8 @@ use events::{
     emit_event_batch, emit_funds_locked, emit_funds_locked_anon, emit_funds_refunded,
     emit_funds_released, emit_new_cycle_created, emit_settlement_completed,
     emit_settlement_grace_period_entered, emit_ticket_claimed, emit_ticket_issued,
+    emit_dispute_raised, emit_dispute_resolved, DisputeRaised, DisputeResolved,
+    emit_admin_changed, emit_admin_proposed, AdminChanged, AdminProposed,
     ActionSummary, AddressFrozenEvent, AddressUnfrozenEvent, BatchFundsLocked, BatchFundsReleased,
     BountyEscrowInitialized, ClaimCancelled, ClaimCreated, ClaimExecuted, EscrowArchivedEvent,
     EscrowClonedEvent, EscrowFrozenEvent, EscrowLockedEvent, EscrowRenewedEvent,
@@ -647,6 +649,14 @@ pub enum Error {
     ScheduleNotDue = 45,
     /// Returned when settlement action is blocked due to active grace period
     GraceperiodActive = 46,
+    /// Returned when resolving a bounty that has no open dispute
+    NotDisputed = 47,
+    /// Returned when a signed admin action carries a stale or future nonce
+    InvalidNonce = 48,
+    /// Returned when split shares do not sum to 10000 basis points
+    InvalidShares = 49,
+    /// Returned when accepting an admin role nobody has proposed
+    NoPendingAdmin = 50,
 }
 
 #[contracttype]
@@ -666,6 +676,8 @@ pub enum EscrowStatus {
     Refunded,
     PartiallyRefunded,
     Template,
+    /// Funds are on hold until the admin calls `resolve_dispute`.
+    Disputed,
 }
 
 #[contracttype]
@@ -786,6 +798,12 @@ pub enum DataKey {
     AutoArchiveConfig,
     /// Settlement grace period configuration: global config for grace periods before auto-settlement
     SettlementGracePeriodConfig,
+    /// Open dispute on a bounty: bounty_id -> BountyDispute
+    BountyDispute(u64),
+    /// Admin proposed by `propose_admin`, awaiting `accept_admin`
+    PendingAdmin,
+    /// Nonce namespace for per-bounty admin actions (NonceMode)
+    NonceMode,
 }
 
 #[contracttype]
@@ -1114,6 +1132,26 @@ pub struct AutoArchiveConfig {
     pub cooldown_seconds: u64,
 }
 
+/// An open dispute placed on a bounty by its depositor or contributor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyDispute {
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub contributor: Address,
+    pub raised_at: u64,
+}
+
+/// How per-bounty admin actions (`resolve_dispute`, `release_split`) count nonces.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NonceMode {
+    /// One counter per signer shared by every bounty (default).
+    Global,
+    /// One counter per `(signer, bounty_id)`, so different bounties don't contend.
+    PerBounty,
+}
+
 #[contract]
 pub struct BountyEscrowContract;
 
@@ -1626,12 +1664,16 @@ impl BountyEscrowContract {
             {
                 if escrow.status == EscrowStatus::Locked
                     || escrow.status == EscrowStatus::PartiallyRefunded
+                    || escrow.status == EscrowStatus::Disputed
                 {
                     escrow.remaining_amount = 0;
                     escrow.status = EscrowStatus::Refunded;
                     env.storage()
                         .persistent()
                         .set(&DataKey::Escrow(bounty_id), &escrow);
+                    env.storage()
+                        .persistent()
+                        .remove(&DataKey::BountyDispute(bounty_id));
                 }
             }
             if let Some(mut anon) = env
@@ -2566,6 +2608,21 @@ impl BountyEscrowContract {
         )
     }
 
+    /// Deployment config as `(admin, token)`, as set by `init`.
+    pub fn get_config(env: Env) -> Result<(Address, Address), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        Ok((admin, token))
+    }
+
     /// Update multisig configuration (admin only)
     pub fn update_multisig_config(
         env: Env,
@@ -2719,7 +2776,14 @@ impl BountyEscrowContract {
             return Err(Error::NotInitialized);
         }
 
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+        // A bounty id is a single namespace shared by regular and anonymous
+        // escrows; reusing an id would overwrite another depositor's record.
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::EscrowAnon(bounty_id))
+        {
             return Err(Error::BountyExists);
         }
 
@@ -3333,6 +3397,10 @@ impl BountyEscrowContract {
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
+        // A disputed bounty only moves through resolve_dispute.
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::FundsNotLocked);
+        }
         escrow.status = EscrowStatus::Released;
         escrow.remaining_amount = 0;
         env.storage()
@@ -3401,6 +3469,15 @@ impl BountyEscrowContract {
         if claim.claimed {
             return Err(Error::FundsNotLocked);
         }
+        let disputed = env
+            .storage()
+            .persistent()
+            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            .map(|escrow| escrow.status == EscrowStatus::Disputed)
+            .unwrap_or(false);
+        if disputed {
+            return Err(Error::FundsNotLocked);
+        }
 
         Self::consume_capability(
             &env,
@@ -5169,7 +5246,7 @@ impl BountyEscrowContract {
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
                 match escrow.status {
-                    EscrowStatus::Locked => {
+                    EscrowStatus::Locked | EscrowStatus::Disputed => {
                         stats.total_locked = stats.total_locked.checked_add(escrow.amount).unwrap();
                         stats.count_locked = stats.count_locked.checked_add(1).unwrap();
                     }
@@ -5202,6 +5279,60 @@ impl BountyEscrowContract {
         index.len()
     }
 
+    /// Sum of the remaining amounts of all bounties still holding funds.
+    ///
+    /// Counts locked, disputed and partially refunded escrows, anonymous
+    /// ones included.
+    pub fn get_total_locked(env: Env) -> i128 {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut total: i128 = 0;
+        for bounty_id in index.iter() {
+            let (status, remaining) = if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                (escrow.status, escrow.remaining_amount)
+            } else if let Some(anon) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, AnonymousEscrow>(&DataKey::EscrowAnon(bounty_id))
+            {
+                (anon.status, anon.remaining_amount)
+            } else {
+                continue;
+            };
+            if matches!(
+                status,
+                EscrowStatus::Locked | EscrowStatus::Disputed | EscrowStatus::PartiallyRefunded
+            ) {
+                total = total.checked_add(remaining).unwrap();
+            }
+        }
+        total
+    }
+
+    /// List bounty ids in creation order, `limit` at a time starting at `start`.
+    ///
+    /// Covers both regular and anonymous escrows. Refunded and released
+    /// bounties stay listed; filter with `get_escrow_ids_by_status` if needed.
+    pub fn get_bounty_ids(env: Env, start: u32, limit: u32) -> Vec<u64> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let end = start.saturating_add(limit).min(index.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        index.slice(start..end)
+    }
+
     /// Set the minimum and maximum allowed lock amount (admin only).
     ///
     /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
@@ -6215,6 +6346,395 @@ impl BountyEscrowContract {
             (false, false, false)
         }
     }
+
+    // ==================== Bounty Disputes ====================
+
+    /// Current admin-action nonce for `signer`.
+    pub fn get_nonce(env: Env, signer: Address) -> u64 {
+        grainlify_core::nonce::get_nonce(&env, &signer)
+    }
+
+    fn consume_nonce(env: &Env, signer: &Address, nonce: u64) -> Result<(), Error> {
+        grainlify_core::nonce::validate_and_increment_nonce(env, signer, nonce)
+            .map_err(|_| Error::InvalidNonce)
+    }
+
+    /// Nonce namespace used by per-bounty admin actions.
+    pub fn get_nonce_mode(env: Env) -> NonceMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::NonceMode)
+            .unwrap_or(NonceMode::Global)
+    }
+
+    /// Switch the nonce namespace for per-bounty admin actions (admin only).
+    ///
+    /// Consumes the admin's global nonce. Counters of the other mode are kept,
+    /// so switching back resumes where it left off.
+    pub fn set_nonce_mode(env: Env, mode: NonceMode, nonce: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce)?;
+
+        env.storage().instance().set(&DataKey::NonceMode, &mode);
+        Ok(())
+    }
+
+    /// Nonce `signer` must supply next for an action on `bounty_id`.
+    pub fn get_bounty_nonce(env: Env, signer: Address, bounty_id: u64) -> u64 {
+        match Self::get_nonce_mode(env.clone()) {
+            NonceMode::Global => grainlify_core::nonce::get_nonce(&env, &signer),
+            NonceMode::PerBounty => {
+                grainlify_core::nonce::get_nonce_with_scope(&env, &signer, bounty_id)
+            }
+        }
+    }
+
+    fn consume_bounty_nonce(
+        env: &Env,
+        signer: &Address,
+        bounty_id: u64,
+        nonce: u64,
+    ) -> Result<(), Error> {
+        match Self::get_nonce_mode(env.clone()) {
+            NonceMode::Global => Self::consume_nonce(env, signer, nonce),
+            NonceMode::PerBounty => grainlify_core::nonce::validate_and_increment_nonce_with_scope(
+                env, signer, bounty_id, nonce,
+            )
+            .map_err(|_| Error::InvalidNonce),
+        }
+    }
+
+    /// Put a locked bounty on hold until the admin resolves it.
+    ///
+    /// `contributor` must be the recipient of the bounty's pending claim, so
+    /// `resolve_dispute` can only ever pay the contributor the admin
+    /// authorized. `caller` must be the bounty's depositor or that
+    /// contributor. While disputed, `release_funds` and `refund` fail with
+    /// `FundsNotLocked`; only `resolve_dispute` moves funds.
+    pub fn raise_dispute(
+        env: Env,
+        bounty_id: u64,
+        caller: Address,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        caller.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        // The contributor is whoever the bounty's pending claim names, never
+        // an address of the caller's choosing.
+        let claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .ok_or(Error::Unauthorized)?;
+        if claim.recipient != contributor {
+            return Err(Error::Unauthorized);
+        }
+        if caller != escrow.depositor && caller != contributor {
+            return Err(Error::Unauthorized);
+        }
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        escrow.status = EscrowStatus::Disputed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let now = env.ledger().timestamp();
+        let dispute = BountyDispute {
+            bounty_id,
+            raised_by: caller.clone(),
+            contributor: contributor.clone(),
+            raised_at: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::BountyDispute(bounty_id), &dispute);
+
+        emit_dispute_raised(
+            &env,
+            DisputeRaised {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                raised_by: caller,
+                contributor,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Resolve an open dispute (admin only).
+    ///
+    /// Pays the remaining escrowed amount to the disputed contributor when
+    /// `pay_contributor` is true, otherwise refunds it to the depositor.
+    pub fn resolve_dispute(
+        env: Env,
+        bounty_id: u64,
+        pay_contributor: bool,
+        nonce: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::consume_bounty_nonce(&env, &admin, bounty_id, nonce)?;
+
+        let dispute: BountyDispute = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyDispute(bounty_id))
+            .ok_or(Error::NotDisputed)?;
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::NotDisputed);
+        }
+
+        reentrancy_guard::acquire(&env);
+
+        let amount = escrow.remaining_amount;
+        let recipient = if pay_contributor {
+            escrow.status = EscrowStatus::Released;
+            dispute.contributor.clone()
+        } else {
+            escrow.status = EscrowStatus::Refunded;
+            escrow.depositor.clone()
+        };
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BountyDispute(bounty_id));
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        let outcome = if pay_contributor {
+            CriticalOperationOutcome::Released
+        } else {
+            CriticalOperationOutcome::Refunded
+        };
+        Self::record_receipt(&env, outcome, bounty_id, amount, recipient.clone());
+
+        emit_dispute_resolved(
+            &env,
+            DisputeResolved {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                paid_contributor: pay_contributor,
+                recipient,
+                amount,
+                resolved_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Open dispute on a bounty, if any.
+    pub fn get_dispute(env: Env, bounty_id: u64) -> Option<BountyDispute> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BountyDispute(bounty_id))
+    }
+
+    // ==================== Split Release ====================
+
+    /// Release a locked bounty's full amount to several collaborators (admin only).
+    ///
+    /// `shares_bps[i]` is recipient `i`'s share in basis points; shares must
+    /// sum to 10000. Rounding dust goes to the last recipient so the whole
+    /// locked amount leaves escrow. Emits one `FundsReleased` per share.
+    pub fn release_split(
+        env: Env,
+        bounty_id: u64,
+        recipients: Vec<Address>,
+        shares_bps: Vec<u32>,
+        nonce: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if recipients.is_empty() {
+            return Err(Error::InvalidBatchSize);
+        }
+        if recipients.len() != shares_bps.len() {
+            return Err(Error::BatchSizeMismatch);
+        }
+        let total_bps: i128 = shares_bps.iter().map(|bps| bps as i128).sum();
+        if total_bps != token_math::BASIS_POINTS {
+            return Err(Error::InvalidShares);
+        }
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::consume_bounty_nonce(&env, &admin, bounty_id, nonce)?;
+
+        if Self::is_escrow_locked(&env, bounty_id) {
+            return Err(Error::EscrowLocked);
+        }
+        if Self::is_escrow_frozen(&env, bounty_id) {
+            return Err(Error::EscrowFrozen);
+        }
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if Self::is_address_frozen(&env, &escrow.depositor) {
+            return Err(Error::AddressFrozen);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        reentrancy_guard::acquire(&env);
+
+        let total = escrow.remaining_amount;
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let timestamp = env.ledger().timestamp();
+        let last = recipients.len() - 1;
+        let mut paid: i128 = 0;
+        for (i, recipient) in recipients.iter().enumerate() {
+            let share = if i as u32 == last {
+                total - paid
+            } else {
+                total * shares_bps.get(i as u32).unwrap() as i128 / token_math::BASIS_POINTS
+            };
+            paid += share;
+            if share == 0 {
+                continue;
+            }
+            client.transfer(&env.current_contract_address(), &recipient, &share);
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: share,
+                    recipient: recipient.clone(),
+                    timestamp,
+                },
+            );
+            Self::record_receipt(
+                &env,
+                CriticalOperationOutcome::Released,
+                bounty_id,
+                share,
+                recipient,
+            );
+        }
+
+        multitoken_invariants::assert_after_disbursement(&env);
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    // ==================== Admin Rotation ====================
+
+    /// Propose `new_admin` as the next admin (current admin only).
+    ///
+    /// Nothing changes until `new_admin` calls `accept_admin`, so a mistyped
+    /// address can simply be overwritten by a fresh proposal.
+    pub fn propose_admin(env: Env, new_admin: Address, nonce: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+
+        emit_admin_proposed(
+            &env,
+            AdminProposed {
+                version: EVENT_VERSION_V2,
+                current_admin: admin,
+                proposed_admin: new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Take over the admin role proposed by `propose_admin`.
+    ///
+    /// Must be signed by the proposed admin.
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let new_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        new_admin.require_auth();
+
+        let previous_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        emit_admin_changed(
+            &env,
+            AdminChanged {
+                version: EVENT_VERSION_V2,
+                previous_admin,
+                new_admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Admin awaiting `accept_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
 }
 
 impl traits::EscrowInterface for BountyEscrowContract {
@@ -6744,6 +7264,20 @@ mod test_query_filters;
 mod test_receipts;
 #[cfg(test)]
 mod test_status_transitions;
+#[cfg(test)]
+mod test_bounty_isolation;
+#[cfg(test)]
+mod test_bounty_disputes;
+#[cfg(test)]
+mod test_bounty_enumeration;
+#[cfg(test)]
+mod test_release_split;
+#[cfg(test)]
+mod test_config_introspection;
+#[cfg(test)]
+mod test_admin_rotation;
+#[cfg(test)]
+mod test_per_bounty_nonce;
 
 #[cfg(test)]
 mod test_global_rate_limit {
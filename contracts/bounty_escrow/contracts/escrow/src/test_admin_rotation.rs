@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+//! Two-step admin rotation: `propose_admin` by the current admin, then
+//! `accept_admin` signed by the proposed one.
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal,
+};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(env, &contract_id);
+    escrow.init(&admin, &token_addr);
+
+    (escrow, admin)
+}
+
+fn pause_release_as(
+    env: &Env,
+    escrow: &BountyEscrowContractClient,
+    signer: &Address,
+) -> bool {
+    let args = (None::<bool>, Some(true), None::<bool>, None::<soroban_sdk::String>);
+    env.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &escrow.address,
+            fn_name: "set_paused",
+            args: args.clone().into_val(env),
+            sub_invokes: &[],
+        },
+    }]);
+    escrow
+        .try_set_paused(&args.0, &args.1, &args.2, &args.3)
+        .is_ok()
+}
+
+#[test]
+fn test_propose_and_accept_rotates_admin() {
+    let env = Env::default();
+    let (escrow, old_admin) = setup(&env);
+    let new_admin = Address::generate(&env);
+
+    escrow.propose_admin(&new_admin, &0);
+    assert_eq!(escrow.get_pending_admin(), Some(new_admin.clone()));
+    // Nothing changes until the proposal is accepted.
+    assert_eq!(escrow.get_config().0, old_admin);
+
+    escrow.accept_admin();
+    assert_eq!(escrow.get_config().0, new_admin);
+    assert_eq!(escrow.get_pending_admin(), None);
+
+    assert!(!pause_release_as(&env, &escrow, &old_admin));
+    assert!(pause_release_as(&env, &escrow, &new_admin));
+}
+
+#[test]
+fn test_accept_without_proposal_fails() {
+    let env = Env::default();
+    let (escrow, _admin) = setup(&env);
+
+    assert_eq!(escrow.try_accept_admin(), Err(Ok(Error::NoPendingAdmin)));
+}
+
+#[test]
+fn test_proposal_can_be_overwritten_before_acceptance() {
+    let env = Env::default();
+    let (escrow, _admin) = setup(&env);
+    let typo = Address::generate(&env);
+    let intended = Address::generate(&env);
+
+    escrow.propose_admin(&typo, &0);
+    escrow.propose_admin(&intended, &1);
+
+    escrow.accept_admin();
+    assert_eq!(escrow.get_config().0, intended);
+}
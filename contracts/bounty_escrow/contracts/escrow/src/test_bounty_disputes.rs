@@ -0,0 +1,202 @@
+#![cfg(test)]
+
+//! `raise_dispute` puts a bounty on hold; only the admin's
+//! `resolve_dispute` can then pay the contributor or refund the depositor.
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, DisputeReason, Error, EscrowStatus,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env,
+};
+
+fn create_token_contract<'a>(
+    env: &Env,
+    admin: &Address,
+) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let contract = env.register_stellar_asset_contract_v2(admin.clone());
+    let contract_address = contract.address();
+    (
+        token::Client::new(env, &contract_address),
+        token::StellarAssetClient::new(env, &contract_address),
+    )
+}
+
+struct DisputeSetup<'a> {
+    env: Env,
+    admin: Address,
+    depositor: Address,
+    contributor: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> DisputeSetup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let (token, token_admin) = create_token_contract(&env, &admin);
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+
+        escrow.init(&admin, &token.address);
+        token_admin.mint(&depositor, &10_000);
+
+        Self {
+            env,
+            admin,
+            depositor,
+            contributor,
+            token,
+            escrow,
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) -> u64 {
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+        deadline
+    }
+
+    /// Authorizes `self.contributor` to claim `bounty_id`, which is what
+    /// binds them as the party a dispute can pay.
+    fn authorize(&self, bounty_id: u64) {
+        self.escrow
+            .authorize_claim(&bounty_id, &self.contributor, &DisputeReason::Other);
+    }
+}
+
+#[test]
+fn test_disputed_bounty_cannot_be_released_or_refunded() {
+    let s = DisputeSetup::new();
+    let deadline = s.lock(1, 1_000);
+
+    s.authorize(1);
+    s.escrow.raise_dispute(&1, &s.depositor, &s.contributor);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Disputed);
+
+    let release = s.escrow.try_release_funds(&1, &s.contributor);
+    assert_eq!(release, Err(Ok(Error::ClaimPending)));
+    let claim = s.escrow.try_claim(&1);
+    assert_eq!(claim, Err(Ok(Error::FundsNotLocked)));
+
+    s.env.ledger().set_timestamp(deadline + 1);
+    let refund = s.escrow.try_refund(&1);
+    assert_eq!(refund, Err(Ok(Error::FundsNotLocked)));
+
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_resolve_dispute_pays_contributor() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+    s.authorize(1);
+    s.escrow.raise_dispute(&1, &s.depositor, &s.contributor);
+
+    s.escrow.resolve_dispute(&1, &true, &0);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.escrow.get_dispute(&1), None);
+    assert_eq!(s.escrow.get_nonce(&s.admin), 1);
+}
+
+#[test]
+fn test_resolve_dispute_refunds_depositor() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+    s.authorize(1);
+    s.escrow.raise_dispute(&1, &s.depositor, &s.contributor);
+
+    s.escrow.resolve_dispute(&1, &false, &0);
+
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_authorized_contributor_can_raise_dispute() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+    s.authorize(1);
+
+    s.escrow.raise_dispute(&1, &s.contributor, &s.contributor);
+
+    let dispute = s.escrow.get_dispute(&1).unwrap();
+    assert_eq!(dispute.raised_by, s.contributor);
+    assert_eq!(dispute.contributor, s.contributor);
+}
+
+#[test]
+fn test_unrelated_address_cannot_raise_dispute() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+    let stranger = Address::generate(&s.env);
+
+    let res = s.escrow.try_raise_dispute(&1, &stranger, &stranger);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_resolve_requires_open_dispute_and_fresh_nonce() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+
+    let res = s.escrow.try_resolve_dispute(&1, &true, &0);
+    assert_eq!(res, Err(Ok(Error::NotDisputed)));
+
+    s.authorize(1);
+    s.escrow.raise_dispute(&1, &s.depositor, &s.contributor);
+    let stale = s.escrow.try_resolve_dispute(&1, &true, &5);
+    assert_eq!(stale, Err(Ok(Error::InvalidNonce)));
+}
+
+#[test]
+fn test_depositor_cannot_name_own_contributor() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+
+    let unclaimed = s.escrow.try_raise_dispute(&1, &s.depositor, &s.depositor);
+    assert_eq!(unclaimed, Err(Ok(Error::Unauthorized)));
+
+    s.authorize(1);
+    let other = s.escrow.try_raise_dispute(&1, &s.depositor, &s.depositor);
+    assert_eq!(other, Err(Ok(Error::Unauthorized)));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_emergency_withdraw_zeroes_disputed_escrow() {
+    let s = DisputeSetup::new();
+    s.lock(1, 1_000);
+    s.authorize(1);
+    s.escrow.raise_dispute(&1, &s.depositor, &s.contributor);
+
+    let reason = soroban_sdk::String::from_str(&s.env, "incident");
+    s.escrow
+        .set_paused(&Some(true), &None, &None, &Some(reason));
+    let target = Address::generate(&s.env);
+    s.escrow.emergency_withdraw(&target);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&target), 1_000);
+    assert_eq!(s.escrow.get_dispute(&1), None);
+
+    let res = s.escrow.try_resolve_dispute(&1, &true, &0);
+    assert_eq!(res, Err(Ok(Error::NotDisputed)));
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(env, &contract_id);
+    escrow.init(&admin, &token_addr);
+
+    (escrow, depositor)
+}
+
+#[test]
+fn test_get_bounty_ids_returns_all_created() {
+    let env = Env::default();
+    let (escrow, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    assert_eq!(escrow.get_bounty_ids(&0, &10), vec![&env]);
+
+    escrow.lock_funds(&depositor, &11, &100, &deadline);
+    escrow.lock_funds(&depositor, &22, &100, &deadline);
+    escrow.lock_funds(&depositor, &33, &100, &deadline);
+
+    assert_eq!(escrow.get_bounty_ids(&0, &10), vec![&env, 11u64, 22, 33]);
+}
+
+#[test]
+fn test_get_bounty_ids_paginates() {
+    let env = Env::default();
+    let (escrow, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+
+    for id in 1..=5u64 {
+        escrow.lock_funds(&depositor, &id, &100, &deadline);
+    }
+
+    assert_eq!(escrow.get_bounty_ids(&0, &2), vec![&env, 1u64, 2]);
+    assert_eq!(escrow.get_bounty_ids(&2, &2), vec![&env, 3u64, 4]);
+    assert_eq!(escrow.get_bounty_ids(&4, &2), vec![&env, 5u64]);
+    assert_eq!(escrow.get_bounty_ids(&5, &2), vec![&env]);
+    assert_eq!(escrow.get_bounty_ids(&0, &u32::MAX).len(), 5);
+}
@@ -0,0 +1,131 @@
+#![cfg(test)]
+
+//! Each `bounty_id` owns an independent escrow record: its own depositor,
+//! amount and status. Operations on one bounty must never touch another.
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+fn create_token_contract<'a>(
+    env: &Env,
+    admin: &Address,
+) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+    let contract = env.register_stellar_asset_contract_v2(admin.clone());
+    let contract_address = contract.address();
+    (
+        token::Client::new(env, &contract_address),
+        token::StellarAssetClient::new(env, &contract_address),
+    )
+}
+
+struct IsolationSetup<'a> {
+    env: Env,
+    depositor_a: Address,
+    depositor_b: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> IsolationSetup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor_a = Address::generate(&env);
+        let depositor_b = Address::generate(&env);
+
+        let (token, token_admin) = create_token_contract(&env, &admin);
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+
+        escrow.init(&admin, &token.address);
+        token_admin.mint(&depositor_a, &10_000);
+        token_admin.mint(&depositor_b, &10_000);
+
+        Self {
+            env,
+            depositor_a,
+            depositor_b,
+            token,
+            escrow,
+        }
+    }
+}
+
+#[test]
+fn test_release_one_bounty_leaves_other_untouched() {
+    let s = IsolationSetup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let contributor = Address::generate(&s.env);
+
+    s.escrow.lock_funds(&s.depositor_a, &1, &1_000, &deadline);
+    s.escrow.lock_funds(&s.depositor_b, &2, &2_500, &deadline);
+
+    s.escrow.release_funds(&1, &contributor);
+
+    let released = s.escrow.get_escrow_info(&1);
+    assert_eq!(released.status, EscrowStatus::Released);
+    assert_eq!(released.depositor, s.depositor_a);
+
+    let untouched = s.escrow.get_escrow_info(&2);
+    assert_eq!(untouched.status, EscrowStatus::Locked);
+    assert_eq!(untouched.depositor, s.depositor_b);
+    assert_eq!(untouched.amount, 2_500);
+    assert_eq!(untouched.remaining_amount, 2_500);
+
+    assert_eq!(s.token.balance(&contributor), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 2_500);
+}
+
+#[test]
+fn test_refund_one_bounty_leaves_other_untouched() {
+    let s = IsolationSetup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    s.escrow.lock_funds(&s.depositor_a, &1, &1_000, &deadline);
+    s.escrow.lock_funds(&s.depositor_b, &2, &2_500, &deadline);
+
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.escrow.refund(&2);
+
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&s.depositor_b), 10_000);
+
+    let untouched = s.escrow.get_escrow_info(&1);
+    assert_eq!(untouched.status, EscrowStatus::Locked);
+    assert_eq!(untouched.remaining_amount, 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_lock_rejects_reused_bounty_id() {
+    let s = IsolationSetup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    s.escrow.lock_funds(&s.depositor_a, &7, &1_000, &deadline);
+
+    let res = s.escrow.try_lock_funds(&s.depositor_b, &7, &500, &deadline);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+
+    let escrow = s.escrow.get_escrow_info(&7);
+    assert_eq!(escrow.depositor, s.depositor_a);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(s.token.balance(&s.depositor_b), 10_000);
+}
+
+#[test]
+fn test_lock_rejects_id_used_by_anonymous_escrow() {
+    let s = IsolationSetup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let commitment = BytesN::from_array(&s.env, &[7u8; 32]);
+
+    s.escrow
+        .lock_funds_anonymous(&s.depositor_a, &commitment, &9, &1_000, &deadline);
+
+    let res = s.escrow.try_lock_funds(&s.depositor_b, &9, &500, &deadline);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+}
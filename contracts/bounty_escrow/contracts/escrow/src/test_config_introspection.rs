@@ -0,0 +1,32 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+#[test]
+fn test_config_and_total_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(&env, &token_addr).mint(&depositor, &10_000);
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &contract_id);
+    escrow.init(&admin, &token_addr);
+
+    assert_eq!(escrow.get_config(), (admin, token_addr));
+    assert_eq!(escrow.get_total_locked(), 0);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_500, &deadline);
+    escrow.lock_funds(&depositor, &2, &2_500, &deadline);
+    assert_eq!(escrow.get_total_locked(), 4_000);
+
+    escrow.release_funds(&1, &Address::generate(&env));
+    assert_eq!(escrow.get_total_locked(), 2_500);
+}
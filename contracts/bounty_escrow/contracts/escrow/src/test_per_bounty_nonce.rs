@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+//! In `NonceMode::PerBounty`, admin actions on different bounties draw from
+//! independent `(signer, bounty_id)` counters.
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, NonceMode};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, Vec};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(env, &contract_id);
+    escrow.init(&admin, &token_addr);
+
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+    escrow.lock_funds(&depositor, &2, &1_000, &deadline);
+
+    (escrow, admin)
+}
+
+fn whole_share(env: &Env) -> (Vec<Address>, Vec<u32>) {
+    (vec![env, Address::generate(env)], vec![env, 10_000u32])
+}
+
+#[test]
+fn test_global_mode_is_default_and_shared() {
+    let env = Env::default();
+    let (escrow, admin) = setup(&env);
+    assert_eq!(escrow.get_nonce_mode(), NonceMode::Global);
+
+    let (recipients, shares) = whole_share(&env);
+    escrow.release_split(&1, &recipients, &shares, &0);
+
+    let replay = escrow.try_release_split(&2, &recipients, &shares, &0);
+    assert_eq!(replay, Err(Ok(Error::InvalidNonce)));
+    assert_eq!(escrow.get_bounty_nonce(&admin, &2), 1);
+}
+
+#[test]
+fn test_per_bounty_mode_counts_each_bounty_from_zero() {
+    let env = Env::default();
+    let (escrow, admin) = setup(&env);
+    escrow.set_nonce_mode(&NonceMode::PerBounty, &0);
+
+    let (recipients, shares) = whole_share(&env);
+    escrow.release_split(&1, &recipients, &shares, &0);
+    escrow.release_split(&2, &recipients, &shares, &0);
+
+    assert_eq!(escrow.get_bounty_nonce(&admin, &1), 1);
+    assert_eq!(escrow.get_bounty_nonce(&admin, &2), 1);
+    assert_eq!(escrow.get_bounty_nonce(&admin, &3), 0);
+    // The global counter only moved for `set_nonce_mode`.
+    assert_eq!(escrow.get_nonce(&admin), 1);
+}
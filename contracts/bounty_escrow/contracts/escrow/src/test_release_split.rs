@@ -0,0 +1,69 @@
+#![cfg(test)]
+
+//! `release_split` pays one bounty out to several collaborators by
+//! basis-point share.
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+fn setup<'a>(env: &Env) -> (BountyEscrowContractClient<'a>, token::Client<'a>, Address) {
+    env.mock_all_auths();
+
+    let admin = Address::generate(env);
+    let depositor = Address::generate(env);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &10_000);
+
+    let contract_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(env, &contract_id);
+    escrow.init(&admin, &token_addr);
+
+    (escrow, token::Client::new(env, &token_addr), depositor)
+}
+
+#[test]
+fn test_release_split_70_30() {
+    let env = Env::default();
+    let (escrow, token, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    escrow.release_split(
+        &1,
+        &vec![&env, alice.clone(), bob.clone()],
+        &vec![&env, 7_000u32, 3_000],
+        &0,
+    );
+
+    assert_eq!(token.balance(&alice), 700);
+    assert_eq!(token.balance(&bob), 300);
+    assert_eq!(token.balance(&escrow.address), 0);
+
+    let info = escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.remaining_amount, 0);
+}
+
+#[test]
+fn test_release_split_rejects_invalid_shares() {
+    let env = Env::default();
+    let (escrow, token, depositor) = setup(&env);
+    let deadline = env.ledger().timestamp() + 1_000;
+    escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    let short = escrow.try_release_split(&1, &recipients, &vec![&env, 7_000u32, 2_000], &0);
+    assert_eq!(short, Err(Ok(Error::InvalidShares)));
+
+    let mismatched = escrow.try_release_split(&1, &recipients, &vec![&env, 10_000u32], &0);
+    assert_eq!(mismatched, Err(Ok(Error::BatchSizeMismatch)));
+
+    assert_eq!(escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(token.balance(&escrow.address), 1_000);
+}
@@ -11,6 +11,7 @@ pub enum NonceError {
 pub enum NonceKey {
     Signer(Address),
     SignerWithDomain(Address, Symbol),
+    SignerWithScope(Address, u64),
 }
 
 /// Get the current nonce for a signer
@@ -25,6 +26,12 @@ pub fn get_nonce_with_domain(env: &Env, signer: &Address, domain: Symbol) -> u64
     env.storage().persistent().get(&key).unwrap_or(0)
 }
 
+/// Get the current nonce for a signer within a numeric scope (e.g. a bounty id)
+pub fn get_nonce_with_scope(env: &Env, signer: &Address, scope: u64) -> u64 {
+    let key = NonceKey::SignerWithScope(signer.clone(), scope);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
 /// Validate and increment nonce for a signer.
 /// Returns Ok(()) if valid, otherwise Err(NonceError::InvalidNonce).
 pub fn validate_and_increment_nonce(
@@ -60,3 +67,21 @@ pub fn validate_and_increment_nonce_with_domain(
     env.storage().persistent().set(&key, &(current_nonce + 1));
     Ok(())
 }
+
+/// Validate and increment nonce for a signer within a numeric scope.
+pub fn validate_and_increment_nonce_with_scope(
+    env: &Env,
+    signer: &Address,
+    scope: u64,
+    provided_nonce: u64,
+) -> Result<(), NonceError> {
+    let current_nonce = get_nonce_with_scope(env, signer, scope);
+
+    if provided_nonce != current_nonce {
+        return Err(NonceError::InvalidNonce);
+    }
+
+    let key = NonceKey::SignerWithScope(signer.clone(), scope);
+    env.storage().persistent().set(&key, &(current_nonce + 1));
+    Ok(())
+}
@@ -17,8 +17,8 @@
 //
 // ============================================================
 
-use crate::{DataKey, ProgramData, PROGRAM_DATA};
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+use crate::{DataKey, EscrowError, ProgramData, PROGRAM_DATA};
+use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env, String, Symbol};
 
 /// The status of a pending claim record.
 #[contracttype]
@@ -66,7 +66,7 @@ fn get_program(env: &Env) -> ProgramData {
     env.storage()
         .instance()
         .get(&PROGRAM_DATA)
-        .unwrap_or_else(|| panic!("Program not initialized"))
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotInitialized))
 }
 
 fn save_program(env: &Env, data: &ProgramData) {
@@ -101,13 +101,13 @@ pub fn create_pending_claim(
     program.authorized_payout_key.require_auth();
 
     if amount <= 0 {
-        panic!("Amount must be greater than zero");
+        panic_with_error!(env, EscrowError::InvalidAmount);
     }
     if amount > program.remaining_balance {
-        panic!("Insufficient escrow balance");
+        panic_with_error!(env, EscrowError::InsufficientBalance);
     }
     if claim_deadline <= env.ledger().timestamp() {
-        panic!("Claim deadline must be in the future");
+        panic_with_error!(env, EscrowError::InvalidTimestamp);
     }
     // Reserve the funds (deduct from remaining balance)
     program.remaining_balance -= amount;
@@ -156,21 +156,21 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
         .storage()
         .persistent()
         .get(&key)
-        .unwrap_or_else(|| panic!("Claim not found"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
     // only the designated recipient can execute their own claim
     if record.recipient != *caller {
-        panic!("Unauthorized: only the claim recipient can execute this claim");
+        panic_with_error!(env, EscrowError::Unauthorized);
     }
 
     // checks if is still pending.
     match record.status {
         ClaimStatus::Pending => {}
-        _ => panic!("ClaimAlreadyProcessed"),
+        _ => panic_with_error!(env, EscrowError::AlreadyProcessed),
     }
 
     // checks if claim deadline has not expired
     if env.ledger().timestamp() > record.claim_deadline {
-        panic!("ClaimExpired");
+        panic_with_error!(env, EscrowError::Expired);
     }
 
     // transfer funds to recipient
@@ -203,10 +203,10 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
         .storage()
         .instance()
         .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("Not initialized"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotInitialized));
 
     if *admin != stored_admin {
-        panic!("Unauthorized: only admin can cancel claims");
+        panic_with_error!(env, EscrowError::Unauthorized);
     }
     admin.require_auth();
 
@@ -215,12 +215,12 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
         .storage()
         .persistent()
         .get(&key)
-        .unwrap_or_else(|| panic!("Claim not found"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
 
     // can only cancel Pending claims (completed claims are final)
     match record.status {
         ClaimStatus::Pending => {}
-        _ => panic!("ClaimAlreadyProcessed"),
+        _ => panic_with_error!(env, EscrowError::AlreadyProcessed),
     }
     // return reserved funds to escrow balance
     let mut program = get_program(env);
@@ -249,7 +249,7 @@ pub fn get_claim(env: &Env, program_id: &String, claim_id: u64) -> ClaimRecord {
     env.storage()
         .persistent()
         .get(&claim_key(program_id, claim_id))
-        .unwrap_or_else(|| panic!("Claim not found"))
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound))
 }
 
 /// Set the global default claim window in seconds.
@@ -259,9 +259,9 @@ pub fn set_claim_window(env: &Env, admin: &Address, window_seconds: u64) {
         .storage()
         .instance()
         .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("Not initialized"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotInitialized));
     if *admin != stored_admin {
-        panic!("Unauthorized");
+        panic_with_error!(env, EscrowError::Unauthorized);
     }
     admin.require_auth();
     env.storage()
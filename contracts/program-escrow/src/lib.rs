@@ -140,8 +140,8 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token, vec,
+    xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
 
 // Event symbols
@@ -149,6 +149,22 @@ const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FundLock");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
+const DRAINED: Symbol = symbol_short!("Drained");
+const FINALIZED: Symbol = symbol_short!("Finalized");
+const RECLAIMED: Symbol = symbol_short!("reclaim");
+/// XDR of an `ScVal::Address` holding an ed25519 account, minus the key.
+const ACCOUNT_ADDRESS_XDR_PREFIX: [u8; 12] = [0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0];
+const RECLAIM_SKIPPED: Symbol = symbol_short!("rcl_skip");
+const PROGRAM_EXPIRED: Symbol = symbol_short!("prg_exp");
+const HISTORY_PRUNED: Symbol = symbol_short!("h_pruned");
+/// Longest accepted `reason` label on fund-moving admin operations.
+const MAX_REASON_LEN: u32 = 128;
+const SWEEP: Symbol = symbol_short!("sweep");
+const LOW_BALANCE: Symbol = symbol_short!("low_bal");
+const BALANCE_SYNCED: Symbol = symbol_short!("bal_sync");
+const EARMARKED: Symbol = symbol_short!("earmark");
+/// Most recent `ConfigChange` entries kept by `record_config_change`.
+const MAX_CONFIG_CHANGES: u32 = 50;
 const DEPENDENCY_CREATED: Symbol = symbol_short!("dep_add");
 const DEPENDENCY_CLEARED: Symbol = symbol_short!("dep_clr");
 const DEPENDENCY_STATUS_UPDATED: Symbol = symbol_short!("dep_sts");
@@ -160,6 +176,7 @@ pub use claim_period::{ClaimRecord, ClaimStatus};
 #[cfg(test)]
 mod test_claim_period_expiry_cancellation;
 mod error_recovery;
+mod payout_digest;
 mod reentrancy_guard;
 
 // Storage keys
@@ -194,6 +211,14 @@ extern crate grainlify_core;
 
 // Event types
 const EVENT_VERSION_V2: u32 = 2;
+
+// Event verbosity (see `set_event_level`)
+/// Only breach, pause and admin/config events.
+pub const EVENT_LEVEL_MINIMAL: u32 = 0;
+/// Default: adds per-call payout, batch summary and fee events.
+pub const EVENT_LEVEL_NORMAL: u32 = 1;
+/// Adds one payout event per batch recipient and every nonce change.
+pub const EVENT_LEVEL_VERBOSE: u32 = 2;
 const PAUSE_STATE_CHANGED: Symbol = symbol_short!("PauseSt");
 const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgRgd");
@@ -232,6 +257,9 @@ pub struct FundsLockedEvent {
     pub amount: i128,
     pub remaining_balance: i128,
     pub receipt_id: u64,
+    /// Share of `max_total_funds` received so far, in basis points (capped
+    /// at 10000); `None` when the program has no cap.
+    pub percent_funded_bps: Option<u32>,
 }
 
 #[contracttype]
@@ -302,6 +330,62 @@ pub enum DataKey {
     IsPaused,                               // Global contract pause state
     ProgramSpendingConfig(String, Address), // (program_id, token) -> ProgramSpendingConfig
     ProgramSpendingState(String, Address),  // (program_id, token) -> ProgramSpendingState
+    ProgramOwner(String),                   // program_id -> Address (governance owner)
+    PendingProgramOwner(String),            // program_id -> Address awaiting accept_ownership
+    MaxBatchSize,                           // u32 cap on batch_payout recipients
+    SignerRateLimit(Address),               // signer -> SignerRateLimit
+    HashedProgram(BytesN<32>),              // program hash -> ProgramData of a hashed program
+    PayoutDigest(String),                   // program_id -> running payout_history hash
+    EventLevel,                             // u32 event verbosity (EVENT_LEVEL_*)
+    PreparedLock(u64),                      // prepared lock id -> PreparedLock
+    NextPreparedLockId,                     // u64 counter for prepare_lock ids
+    PayoutDelegate(String),                 // program_id -> PayoutDelegate
+    MaxTotalFunds(String),                  // program_id -> i128 lifetime cap (0 = unlimited)
+    ClawbackWindow,                         // u64 seconds a payout stays reclaimable (0 = disabled)
+    ClawedBack(String, u32),                // (program_id, payout_index) -> bool
+    RoundingMode,                           // token_math::RoundingMode for fee/share math
+    RecipientStats(Address),                // recipient -> (payout_count, total_received)
+    AuthMode,                               // PayoutAuthMode
+    TokenDecimals(String),                  // program_id -> u32 token decimals read at init
+    Finalized,                              // bool; governance setters frozen once set
+    Stream(u64),                            // stream id -> PaymentStream
+    NextStreamId,                           // u64 counter for create_stream ids
+    StorageFootprint,                       // StorageFootprint running counters
+    RequireThresholdConfig,                 // bool; payouts refused until a ThresholdConfig is set
+    HistoryRetention(String),               // program_id -> u32 payout records kept by prune_history
+    PrunedPayouts(String),                  // program_id -> u32 payout records pruned so far
+    SafeModeConfig,                         // SafeModeConfig; whitelist-only payouts after a breach
+    SignerLastSequence(Address),            // signer -> u32 ledger sequence of its last payout
+    MinFundingBeforePayout(String),         // program_id -> i128 total_funds required before any payout
+    ReclaimDelay,                           // u64 seconds a program's funds stay locked before reclaim
+    LastLockAt(String),                     // program_id -> u64 timestamp of the last credited lock
+    RecipientCooldown,                      // u64 minimum seconds between payouts to one recipient
+    RecipientLastPayout(Address),           // recipient -> u64 timestamp of its last payout
+    Recurring(u64),                         // recurring payout id -> RecurringPayout
+    NextRecurringId,                        // u64 counter for create_recurring ids
+    ForbidSelfPayout,                       // bool; reject payouts to the payout key, owner or escrow (default true)
+    NonceLastUsed(Address),                 // signer -> u64 timestamp its nonce was last consumed
+    FreezeFundingOnBreach,                  // bool; locks refused while the circuit breaker is open
+    ConfigVersion,                          // u64; bumped by every contract-level config setter
+    ConfigChanges,                          // Vec<ConfigChange>, newest last, bounded by MAX_CONFIG_CHANGES
+    LowBalanceThreshold(String),            // program_id -> i128 remaining_balance that triggers LOW_BALANCE
+    LowBalanceAlerted(String),              // program_id -> bool; LOW_BALANCE fired and not yet reset
+    AllowedTokens,                          // Vec<Address>; when non-empty, only these tokens may be locked
+    NotifyRecipient,                        // bool; call `on_payout` on contract recipients after payouts
+    PayoutQueue(String),                    // program_id -> Vec<QueuedPayout>, oldest first
+    Guardian,                               // Address allowed to pause / open the breaker only
+    ProgramExpiry(String),                  // program_id -> u64 timestamp after which execute_expiry may run
+    AutoReclaimDestination(String),         // program_id -> Address receiving funds on expiry
+    ProgramClosed(String),                  // program_id -> bool; set by execute_expiry, blocks further locks
+    PauseReason,                            // String shown by front-ends while paused; cleared on unpause
+    ProgramStorageTier(String),             // program_id -> ProgramStorageTier; absent means Instance
+    MaxSingleLock,                          // i128 per-call deposit cap (0 = unlimited)
+    Earmark(String, Address),               // (program_id, recipient) -> i128 payable only to recipient
+    EarmarkTotal(String),                   // program_id -> i128 sum of all earmarks
+    TrackedBalance(Address),                // token -> i128 owed: balances, reservations, pending locks
+    ProgramHash(String),                    // display program_id -> hash keying its ProgramData
+    RecipientStreams(Address),              // recipient -> Vec<u64> ids of their active streams
+    RateLimitedSigners,                     // Vec<Address> signers ever given a SignerRateLimit
 }
 
 #[contracttype]
@@ -413,6 +497,54 @@ pub enum BatchError {
     DuplicateProgramId = 3,
 }
 
+/// Stable error codes raised by contract entrypoints via `panic_with_error!`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum EscrowError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    InvalidAmount = 3,
+    InsufficientBalance = 4,
+    ProgramNotFound = 5,
+    FundsPaused = 6,
+    NotPaused = 7,
+    Unauthorized = 8,
+    InvalidNonce = 9,
+    InvalidConfig = 10,
+    InvalidTimestamp = 11,
+    EmptyBatch = 12,
+    BatchTooLarge = 13,
+    BatchLengthMismatch = 14,
+    AmountOverflow = 15,
+    DailyLimitExceeded = 16,
+    SpendingLimitExceeded = 17,
+    MaxTotalFundsExceeded = 18,
+    SinglePayoutExceeded = 19,
+    CircuitBreakerOpen = 20,
+    RateLimitExceeded = 21,
+    ScheduleNotFound = 22,
+    ScheduleAlreadyReleased = 23,
+    ScheduleNotDue = 24,
+    NotFound = 25,
+    AlreadyProcessed = 26,
+    Expired = 27,
+    ClawbackDisabled = 28,
+    Finalized = 29,
+    ThresholdConfigMissing = 30,
+    ReasonTooLong = 31,
+    RecipientNotWhitelisted = 32,
+    NoSurplus = 33,
+    LedgerSequenceUsed = 34,
+    BelowMinFunding = 35,
+    ReclaimTooSoon = 36,
+    InstallmentNotDue = 37,
+    SelfPayout = 38,
+    TokenNotAllowed = 39,
+    ProgramNotExpired = 40,
+    SingleLockExceeded = 41,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MultisigConfig {
@@ -471,7 +603,7 @@ fn path_exists_to_target(
     visited.push_back(from_program.clone());
     let deps = get_program_dependencies_internal(env, from_program);
     for dep in deps.iter() {
-        if env.storage().instance().has(&DataKey::Program(dep.clone()))
+        if ProgramEscrowContract::has_program(&env, &dep)
             && path_exists_to_target(env, &dep, target_program, visited)
         {
             return true;
@@ -678,7 +810,8 @@ mod monitoring {
 
 // ==================== ANTI-ABUSE MODULE ====================
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use crate::EscrowError;
+    use soroban_sdk::{contracttype, panic_with_error, symbol_short, Address, Env};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -786,7 +919,7 @@ pub enum DataKey {
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
-            panic!("Operation in cooldown period");
+            panic_with_error!(env, EscrowError::RateLimitExceeded);
         }
 
         // 2. Window check
@@ -805,7 +938,7 @@ pub enum DataKey {
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
-                panic!("Rate limit exceeded");
+                panic_with_error!(env, EscrowError::RateLimitExceeded);
             }
             state.operation_count += 1;
         }
@@ -826,6 +959,7 @@ mod threshold_monitor;
 pub mod token_math;
 
 pub use claim_period::{ClaimRecord, ClaimStatus};
+pub use token_math::RoundingMode;
 
 #[cfg(test)]
 mod test_claim_period_expiry_cancellation;
@@ -877,6 +1011,37 @@ pub struct ConfigSnapshot {
     pub is_paused: bool,
 }
 
+/// Contract-wide configuration as exported by `export_config`, for
+/// replaying onto a fresh deployment with `import_config`.
+///
+/// Per-program settings (token, owner, payout key, caps) are recreated by
+/// `init_program` on the new deployment and are not included here, nor are
+/// individual whitelist entries, which are not enumerable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigExport {
+    pub admin: Address,
+    pub anti_abuse_admin: Option<Address>,
+    pub fee_config: FeeConfig,
+    pub anti_abuse_config: anti_abuse::AntiAbuseConfig,
+    /// `None` when the source never called `set_threshold_config`.
+    pub threshold_config: Option<threshold_monitor::ThresholdConfig>,
+    /// Per-token threshold configurations set with `set_threshold_config_for`.
+    pub token_thresholds: Vec<(Address, threshold_monitor::ThresholdConfig)>,
+    pub require_threshold_config: bool,
+    pub daily_outflow_limit: i128,
+    pub max_batch_size: u32,
+    pub event_level: u32,
+    pub auth_mode: PayoutAuthMode,
+    pub rounding_mode: RoundingMode,
+    /// `(signer, min_interval_secs)` for every signer given a rate limit;
+    /// last-payout timestamps are not carried over.
+    pub signer_rate_limits: Vec<(Address, u64)>,
+    pub is_paused: bool,
+    /// Empty unless `is_paused`.
+    pub pause_reason: String,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ConfigSnapshotKey {
@@ -963,7 +1128,8 @@ pub struct ProgramScheduleReleased {
 /// - `remaining_balance <= total_funds` (always)
 /// - `remaining_balance = total_funds - sum(payout_history.amounts)`
 /// - `payout_history` is append-only
-/// - `program_id` and `authorized_payout_key` are immutable after init
+/// - `program_id` is immutable after init
+/// - `authorized_payout_key` can only be rotated by the program owner
 ///
 /// # Example
 /// ```rust
@@ -1203,6 +1369,225 @@ mod twa {
     }
 }
 
+/// Consolidated health view for monitoring tools, returned by
+/// `program_health_check`. Aggregates several existing views into one
+/// cheap read suitable for polling.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramHealthStatus {
+    pub paused: bool,
+    pub breaker_open: bool,
+    pub cooldown_ends: u64,
+    pub remaining_balance: i128,
+    pub payout_count: u64,
+    pub nonce_admin: u64,
+}
+
+/// Running counters of storage-heavy records across all programs, kept so
+/// operators can estimate storage costs without scanning.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StorageFootprint {
+    pub payout_count: u64,
+    pub recipient_count: u32,
+    pub schedule_count: u32,
+}
+
+/// Post-breach safe mode: while active, only whitelisted recipients can be paid.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SafeModeConfig {
+    pub safe_mode_after_breach: bool,
+    /// How long after the last outflow breach payouts stay whitelist-only.
+    pub safe_mode_duration_secs: u64,
+}
+
+/// One entry of the config change log: which setter ran, and when.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigChange {
+    pub version: u64,
+    pub field: Symbol,
+    pub timestamp: u64,
+}
+
+/// Compact view of a program for light clients; omits `payout_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramSummary {
+    pub program_id: String,
+    pub total_funds: i128,
+    pub remaining_balance: i128,
+    pub total_disbursed: i128,
+    pub payout_count: u32,
+    pub paused: bool,
+    pub breaker_open: bool,
+}
+
+/// Minimum spacing between payouts authorized by the same signer.
+///
+/// Lives in persistent storage next to the signer's nonce so a stolen key
+/// can only drain one payout per `min_interval_secs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignerRateLimit {
+    pub min_interval_secs: u64,
+    pub last_payout_ts: Option<u64>,
+}
+
+/// Lifecycle of a two-phase funding lock.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PreparedLockStatus {
+    Pending,
+    Committed,
+    Aborted,
+}
+
+/// Tokens pulled from a depositor by `prepare_lock` and held outside the
+/// program's `remaining_balance` until `commit_lock` or `abort_lock`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PreparedLock {
+    pub id: u64,
+    pub program_id: String,
+    pub depositor: Address,
+    pub amount: i128,
+    pub created_at: u64,
+    pub status: PreparedLockStatus,
+}
+
+/// Hot key allowed to authorize payouts on behalf of a program's
+/// `authorized_payout_key` until `expiry` (exclusive).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDelegate {
+    pub delegate: Address,
+    pub expiry: u64,
+}
+
+/// How payout signers authorize `single_payout` / `batch_payout`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutAuthMode {
+    /// `require_auth` over the whole invocation (default).
+    Invocation,
+    /// `require_auth_for_args` over `(recipient, amount, nonce)`, where
+    /// `nonce` is the signer's current nonce and is consumed on success.
+    ScopedArgs,
+    /// `require_auth` over the whole invocation, with at most one payout
+    /// per signer per ledger sequence instead of explicit nonces.
+    LedgerSequence,
+}
+
+/// Failure handling for `batch_payout_with_mode`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchMode {
+    /// Any invalid entry aborts the whole batch (same as `batch_payout`).
+    Atomic,
+    /// Invalid entries are skipped and the rest are paid.
+    BestEffort,
+}
+
+/// Storage tier holding a program's `ProgramData`, chosen once at
+/// `initialize_program`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProgramStorageTier {
+    /// Contract instance storage, sharing the instance's size and TTL.
+    Instance,
+    /// A persistent entry of its own, for large or long-lived programs.
+    Persistent,
+}
+
+/// Lifecycle of a continuous payment stream.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamStatus {
+    Active,
+    Completed,
+    Cancelled,
+}
+
+/// Continuous grant paying `rate_per_sec` from `start` to `stop`.
+///
+/// The full `rate_per_sec * (stop - start)` is reserved out of the
+/// program's `remaining_balance` when the stream is created.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentStream {
+    pub id: u64,
+    pub program_id: String,
+    pub recipient: Address,
+    pub rate_per_sec: i128,
+    pub start: u64,
+    pub stop: u64,
+    pub last_withdraw: u64,
+    pub withdrawn: i128,
+    pub status: StreamStatus,
+}
+
+/// Fixed stipend of `amount` paid every `interval_secs`, `count` times.
+///
+/// `amount * count` is reserved out of the program's `remaining_balance`
+/// when the schedule is created; the first installment is due one
+/// interval after creation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecurringPayout {
+    pub id: u64,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub interval_secs: u64,
+    pub count: u32,
+    pub paid: u32,
+    pub last_paid_at: u64,
+    pub status: StreamStatus,
+}
+
+/// One outstanding commitment against a program's funds, as listed by
+/// `get_reservations`.
+///
+/// `kind` is `schedule`, `stream` or `recurring`; `id` is the schedule,
+/// stream or recurring-payout id. `amount` is what is still owed and
+/// `unlock_ts` is when the last of it becomes payable.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reservation {
+    pub kind: Symbol,
+    pub id: u64,
+    pub amount: i128,
+    pub unlock_ts: u64,
+}
+
+/// Compact result of `single_payout_v2`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReceipt {
+    /// Absolute index of the payout in the program's history, as used by
+    /// `get_payout_at` and `clawback_payout`.
+    pub payout_index: u64,
+    /// The address that authorized the payout: the payout key or its
+    /// active delegate.
+    pub signer: Address,
+    /// `signer`'s next payout nonce. Delegates spend the payout key's
+    /// nonce, so this is the payout key's nonce after the payout.
+    pub new_nonce: u64,
+    pub remaining_balance: i128,
+    pub timestamp: u64,
+}
+
+/// A payout waiting in a program's FIFO queue for `process_queue`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedPayout {
+    pub recipient: Address,
+    pub amount: i128,
+    pub enqueued_at: u64,
+}
+
 // ============================================================================
 // Contract Implementation
 // ============================================================================
@@ -1229,2326 +1614,7083 @@ impl ProgramEscrowContract {
         count
     }
 
-    /// Initialize a new program escrow
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - Unique identifier for this program/hackathon
-    /// * `authorized_payout_key` - Address authorized to trigger payouts (backend)
-    /// * `token_address` - Address of the token contract for transfers (e.g., USDC)
-    ///
-    /// # Returns
-    /// * `ProgramData` - The initialized program configuration
-    ///
-    /// # Panics
-    /// * If program is already initialized
-    ///
-    /// # State Changes
-    /// - Creates ProgramData with zero balances
-    /// - Sets authorized payout key (immutable after this)
-    /// - Initializes empty payout history
-    /// - Emits ProgramInitialized event
-    ///
-    /// # Security Considerations
-    /// - Can only be called once (prevents re-configuration)
-    /// - No authorization required (first-caller initialization)
-    /// - Authorized payout key should be a secure backend service
-    /// - Token address must be a valid Stellar Asset Contract
-    /// - Program ID should be unique and descriptive
-    ///
-    /// # Events
-    /// Emits: `ProgramInit(program_id, authorized_payout_key, token_address, 0)`
-    ///
-    /// # Example
-    /// ```rust
-    /// use soroban_sdk::{Address, String, Env};
-    ///
-    /// let program_id = String::from_str(&env, "ETHGlobal2024");
-    /// let backend = Address::from_string("GBACKEND...");
-    /// let usdc = Address::from_string("CUSDC...");
-    ///
-    /// let program = escrow_client.init_program(
-    ///     &program_id,
-    ///     &backend,
-    ///     &usdc
-    /// );
-    ///
-    /// println!("Program created: {}", program.program_id);
-    /// ```
-    ///
-    /// # Production Setup
-    /// ```bash
-    /// # Deploy contract
-    /// stellar contract deploy \
-    ///   --wasm target/wasm32-unknown-unknown/release/escrow.wasm \
-    ///   --source ORGANIZER_KEY
-    ///
-    /// # Initialize program
-    /// stellar contract invoke \
-    ///   --id CONTRACT_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- init_program \
-    ///   --program_id "Hackathon2024" \
-    ///   --authorized_payout_key GBACKEND... \
-    ///   --token_address CUSDC...
-    /// ```
-    ///
-    /// # Gas Cost
-    /// Low - Initial storage writes
-
-    // ========================================================================
-    // Pause and Emergency Functions
-    // ========================================================================
+    /// Validate `nonce` against `signer`'s replay counter and advance it.
+    fn consume_nonce(env: &Env, signer: &Address, nonce: u64) {
+        if grainlify_core::nonce::validate_and_increment_nonce(env, signer, nonce).is_err() {
+            panic_with_error!(env, EscrowError::InvalidNonce);
+        }
+        env.storage().persistent().set(
+            &DataKey::NonceLastUsed(signer.clone()),
+            &env.ledger().timestamp(),
+        );
+        if Self::emits_at(env, EVENT_LEVEL_VERBOSE) {
+            env.events()
+                .publish((symbol_short!("nonce"), signer.clone()), nonce + 1);
+        }
+    }
 
-    /// Check if contract is paused (internal helper)
-    fn is_paused_internal(env: &Env) -> bool {
-        env.storage()
+    /// Whether events tagged with `level` are emitted under the current
+    /// verbosity setting.
+    fn emits_at(env: &Env, level: u32) -> bool {
+        let current: u32 = env
+            .storage()
             .instance()
-            .get::<_, bool>(&DataKey::IsPaused)
-            .unwrap_or(false)
+            .get(&DataKey::EventLevel)
+            .unwrap_or(EVENT_LEVEL_NORMAL);
+        current >= level
     }
 
-    /// Get pause status (view function)
-    pub fn is_paused(env: Env) -> bool {
-        Self::is_paused_internal(&env)
+    /// Panics if raising the program's lifetime `total_funds` to `new_total`
+    /// would exceed its configured `max_total_funds`.
+    fn enforce_max_total_funds(env: &Env, program_id: &String, new_total: i128) {
+        let cap: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxTotalFunds(program_id.clone()))
+            .unwrap_or(0);
+        if cap > 0 && new_total > cap {
+            panic_with_error!(env, EscrowError::MaxTotalFundsExceeded);
+        }
     }
 
-    /// Pause the contract (authorized payout key only)
-    /// Prevents new fund locking, payouts, and schedule releases
-    pub fn pause(env: Env) -> () {
-        // For program-escrow, pause is triggered by the first authorized key that calls it
-        // In a multi-program setup, this would need to be per-program
+    /// Panics if a single deposit of `amount` exceeds `max_single_lock`.
+    fn enforce_max_single_lock(env: &Env, amount: i128) {
+        let cap: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxSingleLock)
+            .unwrap_or(0);
+        if cap > 0 && amount > cap {
+            panic_with_error!(env, EscrowError::SingleLockExceeded);
+        }
+    }
 
-        if Self::is_paused_internal(&env) {
-            return; // Already paused, idempotent
+    /// Funding progress against `max_total_funds` in basis points, where
+    /// funds received are `remaining_balance` plus everything disbursed
+    /// (i.e. `total_funds`). `None` when the program is uncapped.
+    fn percent_funded_bps(env: &Env, program_data: &ProgramData) -> Option<u32> {
+        let cap: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MaxTotalFunds(program_data.program_id.clone()))
+            .unwrap_or(0);
+        if cap <= 0 {
+            return None;
         }
+        let bps = program_data
+            .total_funds
+            .saturating_mul(BASIS_POINTS)
+            / cap;
+        Some(bps.clamp(0, BASIS_POINTS) as u32)
+    }
 
-        env.storage().instance().set(&DataKey::IsPaused, &true);
+    /// Bumps `recipient`'s lifetime payout count and total received.
+    fn record_recipient_payout(env: &Env, recipient: &Address, amount: i128) {
+        let key = DataKey::RecipientStats(recipient.clone());
+        let stats: Option<(u32, i128)> = env.storage().persistent().get(&key);
+        let (count, total) = stats.unwrap_or((0, 0));
+        env.storage()
+            .persistent()
+            .set(&key, &(count.saturating_add(1), total.saturating_add(amount)));
 
-        env.events()
-            .publish((symbol_short!("pause"),), (env.ledger().timestamp(),));
+        Self::update_storage_footprint(env, |footprint| {
+            footprint.payout_count = footprint.payout_count.saturating_add(1);
+            if stats.is_none() {
+                footprint.recipient_count = footprint.recipient_count.saturating_add(1);
+            }
+        });
     }
 
-    /// Unpause the contract (authorized payout key only)
-    /// Resumes normal operations
-    pub fn unpause(env: Env) -> () {
-        if !Self::is_paused_internal(&env) {
-            return; // Already unpaused, idempotent
+    /// Reverses `record_recipient_payout` for a payout that was clawed back.
+    fn unrecord_recipient_payout(env: &Env, recipient: &Address, amount: i128) {
+        let key = DataKey::RecipientStats(recipient.clone());
+        if let Some((count, total)) = env.storage().persistent().get::<_, (u32, i128)>(&key) {
+            env.storage()
+                .persistent()
+                .set(&key, &(count.saturating_sub(1), total.saturating_sub(amount)));
         }
+    }
 
-        env.storage().instance().set(&DataKey::IsPaused, &false);
-
-        env.events()
-            .publish((symbol_short!("unpause"),), (env.ledger().timestamp(),));
+    fn update_storage_footprint(env: &Env, update: impl FnOnce(&mut StorageFootprint)) {
+        let mut footprint: StorageFootprint = env
+            .storage()
+            .instance()
+            .get(&DataKey::StorageFootprint)
+            .unwrap_or_default();
+        update(&mut footprint);
+        env.storage()
+            .instance()
+            .set(&DataKey::StorageFootprint, &footprint);
     }
 
-    /// Emergency withdrawal for all contract funds (authorized payout key only, only when paused)
-    pub fn emergency_withdraw(env: Env, program_id: String, recipient: Address) -> i128 {
-        // Only allow emergency withdrawal when contract is paused
-        if !Self::is_paused_internal(&env) {
-            panic!("Contract must be paused for emergency withdrawal");
+    /// Rejects accounting labels longer than `MAX_REASON_LEN` bytes.
+    fn validate_reason(env: &Env, reason: &String) {
+        if reason.len() > MAX_REASON_LEN {
+            panic_with_error!(env, EscrowError::ReasonTooLong);
         }
+    }
 
-        // Get program data to access token address
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| {
-                    panic!("Program not found");
-                });
-
-        let client = token::Client::new(&env, &program_data.token_address);
-        let balance = client.balance(&env.current_contract_address());
+    /// Storage tier `program_id`'s data lives in (`Instance` by default).
+    fn program_storage_tier(env: &Env, program_id: &String) -> ProgramStorageTier {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramStorageTier(program_id.clone()))
+            .unwrap_or(ProgramStorageTier::Instance)
+    }
 
-        if balance <= 0 {
-            return 0; // No funds to withdraw
+    /// Storage key of `program_id`'s data: its hash for programs created by
+    /// `init_program_hashed`, the String id otherwise.
+    fn program_key(env: &Env, program_id: &String) -> DataKey {
+        match env
+            .storage()
+            .instance()
+            .get::<_, BytesN<32>>(&DataKey::ProgramHash(program_id.clone()))
+        {
+            Some(program_hash) => DataKey::HashedProgram(program_hash),
+            None => DataKey::Program(program_id.clone()),
         }
+    }
 
-        // Transfer all funds to recipient
-        client.transfer(&env.current_contract_address(), &recipient, &balance);
-
-        env.events().publish(
-            (symbol_short!("ewith"),),
-            (balance, env.ledger().timestamp()),
-        );
+    /// Loads `program_id`'s data from its storage tier.
+    fn load_program(env: &Env, program_id: &String) -> Option<ProgramData> {
+        let key = Self::program_key(env, program_id);
+        match Self::program_storage_tier(env, program_id) {
+            ProgramStorageTier::Instance => env.storage().instance().get(&key),
+            ProgramStorageTier::Persistent => env.storage().persistent().get(&key),
+        }
+    }
 
-        balance
+    /// Whether `program_id` exists in its storage tier.
+    fn has_program(env: &Env, program_id: &String) -> bool {
+        let key = Self::program_key(env, program_id);
+        match Self::program_storage_tier(env, program_id) {
+            ProgramStorageTier::Instance => env.storage().instance().has(&key),
+            ProgramStorageTier::Persistent => env.storage().persistent().has(&key),
+        }
     }
 
-    /// High-level initialization entrypoint used by tests and integrators.
-    /// Delegates to `initialize_program` and currently ignores `creator` and
-    /// `initial_liquidity` for backwards compatibility.
-    /// Initialize a new program escrow
-    pub fn init_program(
-        env: Env,
-        program_id: String,
-        authorized_payout_key: Address,
-        token_address: Address,
-        _creator: Address,
-        _initial_liquidity: Option<i128>,
-    ) -> ProgramData {
-        Self::initialize_program(env, program_id, authorized_payout_key, token_address)
-        creator: Address,
-        initial_liquidity: Option<i128>,
-        reference_hash: Option<soroban_sdk::Bytes>,
-    ) -> ProgramData {
-        Self::initialize_program(
+    /// Writes `program_data` back to its program's storage tier, carrying
+    /// any change in `remaining_balance` into the token's tracked total.
+    fn save_program(env: &Env, program_data: &ProgramData) {
+        let previous = Self::load_program(env, &program_data.program_id)
+            .map(|data| data.remaining_balance)
+            .unwrap_or(0);
+        Self::adjust_tracked_balance(
             env,
-            program_id,
-            authorized_payout_key,
-            token_address,
-            creator,
-            initial_liquidity,
-            reference_hash,
-        )
+            &program_data.token_address,
+            program_data.remaining_balance - previous,
+        );
+
+        let key = Self::program_key(env, &program_data.program_id);
+        match Self::program_storage_tier(env, &program_data.program_id) {
+            ProgramStorageTier::Instance => env.storage().instance().set(&key, program_data),
+            ProgramStorageTier::Persistent => env.storage().persistent().set(&key, program_data),
+        }
     }
 
-    pub fn initialize_program(
-        env: Env,
-        program_id: String,
-        authorized_payout_key: Address,
-        token_address: Address,
-        creator: Address,
-        initial_liquidity: Option<i128>,
-        reference_hash: Option<soroban_sdk::Bytes>,
-    ) -> ProgramData {
-        let receipt_id = Self::increment_receipt_id(&env);
-        let program_key = DataKey::Program(program_id.clone());
+    /// Number of payout records `prune_history` has removed from `program_id`.
+    fn pruned_payout_count(env: &Env, program_id: &String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PrunedPayouts(program_id.clone()))
+            .unwrap_or(0)
+    }
 
-        // Check if program already exists
-        if env.storage().instance().has(&program_key) {
-            panic!("Program already initialized");
+    /// Panics once `finalize_program` has frozen governance configuration.
+    fn require_not_finalized(env: &Env) {
+        if env.storage().instance().has(&DataKey::Finalized) {
+            panic_with_error!(env, EscrowError::Finalized);
         }
+    }
 
-        let mut total_funds = 0i128;
-        let mut remaining_balance = 0i128;
-        let mut init_liquidity = 0i128;
+    /// Panics with `BelowMinFunding` until the program has received the
+    /// `min_funding_before_payout` set at init.
+    fn require_min_funding(env: &Env, program_data: &ProgramData) {
+        let min: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinFundingBeforePayout(program_data.program_id.clone()))
+            .unwrap_or(0);
+        if program_data.total_funds < min {
+            reentrancy_guard::clear_entered(env);
+            panic_with_error!(env, EscrowError::BelowMinFunding);
+        }
+    }
 
-        if let Some(amount) = initial_liquidity {
-            if amount > 0 {
-                // Transfer initial liquidity from creator to contract
-                let contract_address = env.current_contract_address();
-                let token_client = token::Client::new(&env, &token_address);
-                creator.require_auth();
-                token_client.transfer(&creator, &contract_address, &amount);
-                total_funds = amount;
-                remaining_balance = amount;
-                init_liquidity = amount;
+    /// Whether `reclaim_delay_secs` has elapsed since the program's last
+    /// credited lock. Programs that were never locked are always reclaimable.
+    fn reclaim_delay_elapsed(env: &Env, program_id: &String) -> bool {
+        let last_lock: Option<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::LastLockAt(program_id.clone()));
+        match last_lock {
+            Some(locked_at) => {
+                env.ledger().timestamp().saturating_sub(locked_at)
+                    >= Self::get_reclaim_delay(env.clone())
             }
+            None => true,
         }
+    }
 
-        // Create program data
-        let program_data = ProgramData {
-            program_id: program_id.clone(),
-            total_funds: 0,
-            remaining_balance: 0,
-            authorized_payout_key: authorized_payout_key.clone(),
-            payout_history: vec![&env],
-            token_address: token_address.clone(),
-            initial_liquidity: init_liquidity,
-            reference_hash: reference_hash.clone(),
-        };
+    /// Panics with `RecipientNotWhitelisted` while post-breach safe mode is
+    /// active and `recipient` is not whitelisted.
+    fn enforce_safe_mode(env: &Env, recipient: &Address) {
+        if Self::is_safe_mode_active(env.clone()) && !anti_abuse::is_whitelisted(env, recipient.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic_with_error!(env, EscrowError::RecipientNotWhitelisted);
+        }
+    }
 
-        // Initialize fee config with zero fees (disabled by default)
-        let fee_config = FeeConfig {
-            lock_fee_rate: 0,
-            payout_fee_rate: 0,
-            fee_recipient: authorized_payout_key.clone(),
-            fee_enabled: false,
-        };
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+    /// Emits `LOW_BALANCE` once when `remaining_balance` drops below the
+    /// program's `low_balance_threshold`, and re-arms the alert once the
+    /// balance is back at or above it.
+    fn update_low_balance_alert(env: &Env, program_data: &ProgramData) {
+        let program_id = program_data.program_id.clone();
+        let threshold: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LowBalanceThreshold(program_id.clone()))
+            .unwrap_or(0);
+        if threshold <= 0 {
+            return;
+        }
+        let alert_key = DataKey::LowBalanceAlerted(program_id.clone());
+        let alerted = env.storage().instance().has(&alert_key);
+        let below = program_data.remaining_balance < threshold;
+        if below && !alerted {
+            env.storage().instance().set(&alert_key, &true);
+            env.events().publish(
+                (LOW_BALANCE, program_id),
+                (program_data.remaining_balance, threshold),
+            );
+        } else if !below && alerted {
+            env.storage().instance().remove(&alert_key);
+        }
+    }
 
-        // Store program data
-        env.storage().instance().set(&program_key, &program_data);
+    /// Bumps `config_version` and appends a `ConfigChange` for `field`,
+    /// dropping the oldest entry once `MAX_CONFIG_CHANGES` are kept.
+    fn record_config_change(env: &Env, field: Symbol) {
+        let version = Self::get_config_version(env.clone()) + 1;
         env.storage()
             .instance()
-            .set(&SCHEDULES, &Vec::<ProgramReleaseSchedule>::new(&env));
+            .set(&DataKey::ConfigVersion, &version);
+
+        let mut changes: Vec<ConfigChange> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChanges)
+            .unwrap_or(Vec::new(env));
+        if changes.len() >= MAX_CONFIG_CHANGES {
+            changes.pop_front();
+        }
+        changes.push_back(ConfigChange {
+            version,
+            field,
+            timestamp: env.ledger().timestamp(),
+        });
         env.storage()
             .instance()
-            .set(&RELEASE_HISTORY, &Vec::<ProgramReleaseHistory>::new(&env));
-        env.storage().instance().set(&NEXT_SCHEDULE_ID, &1_u64);
-
-        // Emit ProgramInitialized event
-        env.events().publish(
-            (PROGRAM_INITIALIZED,),
-            ProgramInitializedEvent {
-                version: EVENT_VERSION_V2,
-                program_id,
-                authorized_payout_key,
-                token_address,
-                total_funds,
-                reference_hash,
-                receipt_id,
-            },
-        );
-
-        program_data
+            .set(&DataKey::ConfigChanges, &changes);
     }
 
-    /// Batch-initialize multiple programs in one transaction (all-or-nothing).
-    pub fn batch_initialize_programs(
-        env: Env,
-        items: Vec<ProgramInitItem>,
-    ) -> Result<u32, BatchError> {
-        let batch_size = items.len() as u32;
-        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
-            return Err(BatchError::InvalidBatchSize);
-        }
-        for i in 0..batch_size {
-            for j in (i + 1)..batch_size {
-                if items.get(i).unwrap().program_id == items.get(j).unwrap().program_id {
-                    return Err(BatchError::DuplicateProgramId);
-                }
-            }
-        }
-        for i in 0..batch_size {
-            let program_key = DataKey::Program(items.get(i).unwrap().program_id.clone());
-            if env.storage().instance().has(&program_key) {
-                return Err(BatchError::ProgramAlreadyExists);
-            }
+    /// Panics with `TokenNotAllowed` when the token allow-list is non-empty
+    /// and does not contain `token`.
+    fn require_token_allowed(env: &Env, token: &Address) {
+        if !Self::is_token_allowed(env.clone(), token.clone()) {
+            panic_with_error!(env, EscrowError::TokenNotAllowed);
         }
+    }
 
-        let mut registry: Vec<String> = env
+    /// Panics with `Expired` once `execute_expiry` has closed the program.
+    fn require_program_open(env: &Env, program_id: &String) {
+        if env
             .storage()
             .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(Vec::new(&env));
-
-        let start_time = env.ledger().timestamp();
-        for i in 0..batch_size {
-            let item = items.get(i).unwrap();
-            let program_id = item.program_id.clone();
-            let authorized_payout_key = item.authorized_payout_key.clone();
-            let token_address = item.token_address.clone();
-
-            if program_id.is_empty() {
-                return Err(BatchError::InvalidBatchSize);
-            }
+            .has(&DataKey::ProgramClosed(program_id.clone()))
+        {
+            panic_with_error!(env, EscrowError::Expired);
+        }
+    }
 
-            let program_data = ProgramData {
-                program_id: program_id.clone(),
-                total_funds: 0,
-                remaining_balance: 0,
-                authorized_payout_key: authorized_payout_key.clone(),
-                payout_history: Vec::new(&env),
-                token_address: token_address.clone(),
-                initial_liquidity: 0,
-                reference_hash: item.reference_hash.clone(),
-            };
-            let program_key = DataKey::Program(program_id.clone());
-            env.storage().instance().set(&program_key, &program_data);
+    /// Panics with `CircuitBreakerOpen` when `freeze_funding_on_breach` is
+    /// set and the circuit breaker is open, so no more funds get trapped.
+    fn enforce_funding_not_frozen(env: &Env) {
+        if Self::get_freeze_funding_on_breach(env.clone())
+            && error_recovery::get_state(env) == error_recovery::CircuitState::Open
+        {
+            panic_with_error!(env, EscrowError::CircuitBreakerOpen);
+        }
+    }
 
-            if i == 0 {
-                let fee_config = FeeConfig {
-                    lock_fee_rate: 0,
-                    payout_fee_rate: 0,
-                    fee_recipient: authorized_payout_key.clone(),
-                    fee_enabled: false,
-                };
-                env.storage().instance().set(&FEE_CONFIG, &fee_config);
-            }
+    /// Panics with `SelfPayout` when `forbid_self_payout` is on and
+    /// `recipient` is the program's payout key, its owner or the escrow.
+    fn enforce_no_self_payout(env: &Env, program_data: &ProgramData, recipient: &Address) {
+        if !Self::get_forbid_self_payout(env.clone()) {
+            return;
+        }
+        if *recipient == program_data.authorized_payout_key
+            || *recipient == env.current_contract_address()
+            || *recipient == Self::get_program_owner_internal(env, &program_data.program_id)
+        {
+            reentrancy_guard::clear_entered(env);
+            panic_with_error!(env, EscrowError::SelfPayout);
+        }
+    }
 
-            let multisig_config = MultisigConfig {
-                threshold_amount: i128::MAX,
-                signers: Vec::new(&env),
-                required_signatures: 0,
-            };
-            env.storage().persistent().set(
-                &DataKey::MultisigConfig(program_id.clone()),
-                &multisig_config,
-            );
+    /// When `notify_recipient` is on and `recipient` is a contract, calls its
+    /// `on_payout(from, amount)`. Must run after state is stored; the
+    /// reentrancy guard is still held, so callbacks cannot re-enter payouts.
+    /// Callback failures are ignored and never undo the transfer.
+    fn notify_recipient(env: &Env, recipient: &Address, amount: i128) {
+        if !Self::get_notify_recipient(env.clone()) || !Self::is_contract_address(recipient) {
+            return;
+        }
+        let args: Vec<Val> = vec![
+            env,
+            env.current_contract_address().into_val(env),
+            amount.into_val(env),
+        ];
+        let _ = env.try_invoke_contract::<Val, soroban_sdk::Error>(
+            recipient,
+            &Symbol::new(env, "on_payout"),
+            args,
+        );
+    }
 
-            registry.push_back(program_id.clone());
-            let receipt_id = Self::increment_receipt_id(&env);
-            
-            env.events().publish(
-                (PROGRAM_INITIALIZED,),
-                ProgramInitializedEvent {
-                    version: EVENT_VERSION_V2,
-                    program_id,
-                    authorized_payout_key: authorized_payout_key.clone(),
-                    token_address,
-                    total_funds: 0,
-                    reference_hash: item.reference_hash,
-                    receipt_id,
-                },
-            );
+    /// Contract addresses are `C...` strkeys; accounts are `G...`.
+    fn is_contract_address(address: &Address) -> bool {
+        let strkey = address.to_string();
+        let mut buf = [0u8; 56];
+        if strkey.len() as usize != buf.len() {
+            return false;
+        }
+        strkey.copy_into_slice(&mut buf);
+        buf[0] == b'C'
+    }
 
-            // Emit registration event
-            env.events().publish(
-                (PROGRAM_REGISTERED,),
-                (item.program_id.clone(), authorized_payout_key.clone(), item.token_address.clone(), 0i128),
+    /// Panics while the program token's own thresholds are breached; other
+    /// tokens are unaffected.
+    fn enforce_token_thresholds(env: &Env, program_data: &ProgramData) {
+        let token = &program_data.token_address;
+        if let Err(breach) = threshold_monitor::check_token_thresholds(env, token) {
+            threshold_monitor::emit_program_threshold_breach_event(
+                env,
+                &program_data.program_id,
+                &breach,
             );
-
-            // Track successful operation
-            monitoring::track_operation(&env, symbol_short!("init_prg"), authorized_payout_key, true);
+            reentrancy_guard::clear_entered(env);
+            panic_with_error!(env, EscrowError::CircuitBreakerOpen);
         }
-        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start_time);
-        monitoring::emit_performance(&env, symbol_short!("init_prg"), duration);
-
-        Ok(batch_size)
     }
 
-    /// Calculate fee amount based on rate (in basis points)
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        if fee_rate == 0 {
-            return 0;
+    /// Panics if the deployment opted into default-deny and no
+    /// `ThresholdConfig` has been set yet.
+    fn require_threshold_config_if_needed(env: &Env) {
+        let required: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::RequireThresholdConfig)
+            .unwrap_or(false);
+        if required && !threshold_monitor::has_threshold_config(env) {
+            panic_with_error!(env, EscrowError::ThresholdConfigMissing);
         }
-        // Fee = (amount * fee_rate) / BASIS_POINTS
-        amount
-            .checked_mul(fee_rate)
-            .and_then(|x| x.checked_div(BASIS_POINTS))
-            .unwrap_or(0)
     }
 
-    /// Get fee configuration (internal helper)
-    fn get_fee_config_internal(env: &Env) -> FeeConfig {
-        env.storage()
+    /// Requires payout authorization and returns the address that gave it.
+    ///
+    /// `requested` picks the signer explicitly and must be `payout_key` or
+    /// the program's unexpired delegate; `None` means the active delegate
+    /// if one is set, else `payout_key`.
+    ///
+    /// A delegate spends `payout_key`'s nonce, so every delegated payout
+    /// advances the authorized key's nonce whatever the auth mode. Under
+    /// `PayoutAuthMode::ScopedArgs` the signature must cover
+    /// `(recipient, amount, nonce)` with `payout_key`'s current nonce, which
+    /// is then consumed. Under `PayoutAuthMode::LedgerSequence` the current
+    /// ledger sequence is claimed for the signer, so a second payout in the
+    /// same ledger fails.
+    fn require_payout_auth(
+        env: &Env,
+        program_id: &String,
+        payout_key: &Address,
+        requested: Option<Address>,
+        recipient: Val,
+        amount: Val,
+    ) -> Address {
+        let delegate = env
+            .storage()
             .instance()
-            .get(&FEE_CONFIG)
-            .unwrap_or_else(|| FeeConfig {
-                lock_fee_rate: 0,
-                payout_fee_rate: 0,
-                fee_recipient: env.current_contract_address(),
-                fee_enabled: false,
-            })
+            .get::<_, PayoutDelegate>(&DataKey::PayoutDelegate(program_id.clone()))
+            .filter(|entry| env.ledger().timestamp() < entry.expiry)
+            .map(|entry| entry.delegate);
+        let signer = match requested {
+            Some(signer) if signer == *payout_key || Some(signer.clone()) == delegate => signer,
+            Some(_) => panic_with_error!(env, EscrowError::Unauthorized),
+            None => delegate.unwrap_or_else(|| payout_key.clone()),
+        };
+
+        let nonce = grainlify_core::nonce::get_nonce(env, payout_key);
+        match Self::get_auth_mode(env.clone()) {
+            PayoutAuthMode::Invocation => {
+                signer.require_auth();
+                if signer != *payout_key {
+                    Self::consume_nonce(env, payout_key, nonce);
+                }
+            }
+            PayoutAuthMode::ScopedArgs => {
+                signer.require_auth_for_args(vec![env, recipient, amount, nonce.into_val(env)]);
+                Self::consume_nonce(env, payout_key, nonce);
+            }
+            PayoutAuthMode::LedgerSequence => {
+                signer.require_auth();
+                let key = DataKey::SignerLastSequence(signer.clone());
+                let sequence = env.ledger().sequence();
+                if env.storage().persistent().get::<_, u32>(&key) == Some(sequence) {
+                    panic_with_error!(env, EscrowError::LedgerSequenceUsed);
+                }
+                env.storage().persistent().set(&key, &sequence);
+                if signer != *payout_key {
+                    Self::consume_nonce(env, payout_key, nonce);
+                }
+            }
+        }
+        signer
     }
 
-    /// Lock initial funds into the program escrow
-    ///
-    /// Lists all registered program IDs in the contract.
-    ///
-    /// # Returns
-    /// * `Vec<String>` - List of all program IDs
-    ///
-    /// # Example
-    /// ```rust
-    /// let programs = escrow_client.list_programs();
-    /// for program_id in programs.iter() {
-    ///     println!("Program: {}", program_id);
-    /// }
-    /// ```
-    pub fn list_programs(env: Env) -> Vec<String> {
+    /// Rejects a payout that arrives before the signer's minimum interval
+    /// has elapsed, then stamps the signer's last payout time.
+    fn enforce_signer_interval(env: &Env, signer: &Address) {
+        let key = DataKey::SignerRateLimit(signer.clone());
+        let mut limit: SignerRateLimit = match env.storage().persistent().get(&key) {
+            Some(limit) => limit,
+            None => return,
+        };
+        let now = env.ledger().timestamp();
+        if let Some(last) = limit.last_payout_ts {
+            if now < last.saturating_add(limit.min_interval_secs) {
+                reentrancy_guard::clear_entered(env);
+                panic_with_error!(env, EscrowError::RateLimitExceeded);
+            }
+        }
+        limit.last_payout_ts = Some(now);
+        env.storage().persistent().set(&key, &limit);
+    }
+
+    /// Rejects a payout to `recipient` within `recipient_cooldown_secs` of
+    /// its previous one, then stamps the recipient's last payout time.
+    fn enforce_recipient_cooldown(env: &Env, recipient: &Address) {
+        let cooldown = Self::get_recipient_cooldown(env.clone());
+        if cooldown == 0 {
+            return;
+        }
+        let key = DataKey::RecipientLastPayout(recipient.clone());
+        let now = env.ledger().timestamp();
+        if let Some(last) = env.storage().persistent().get::<_, u64>(&key) {
+            if now < last.saturating_add(cooldown) {
+                reentrancy_guard::clear_entered(env);
+                panic_with_error!(env, EscrowError::RateLimitExceeded);
+            }
+        }
+        env.storage().persistent().set(&key, &now);
+    }
+
+    /// Whether a single batch entry would pass the per-entry checks that
+    /// `batch_payout` otherwise panics on. Read-only.
+    fn is_payable_entry(
+        env: &Env,
+        program_data: &ProgramData,
+        recipient: &Address,
+        amount: i128,
+    ) -> bool {
+        if amount <= 0 || threshold_monitor::check_single_payout_threshold(env, amount).is_err() {
+            return false;
+        }
+        if Self::is_safe_mode_active(env.clone()) && !anti_abuse::is_whitelisted(env, recipient.clone()) {
+            return false;
+        }
+        let cooldown = Self::get_recipient_cooldown(env.clone());
+        if let Some(last) = env
+            .storage()
+            .persistent()
+            .get::<_, u64>(&DataKey::RecipientLastPayout(recipient.clone()))
+        {
+            if cooldown > 0 && env.ledger().timestamp() < last.saturating_add(cooldown) {
+                return false;
+            }
+        }
+        !(Self::get_forbid_self_payout(env.clone())
+            && (*recipient == program_data.authorized_payout_key
+                || *recipient == env.current_contract_address()
+                || *recipient == Self::get_program_owner_internal(env, &program_data.program_id)))
+    }
+
+    /// Maximum number of recipients accepted by `batch_payout`.
+    fn get_max_batch_size_internal(env: &Env) -> u32 {
         env.storage()
             .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env])
+            .get(&DataKey::MaxBatchSize)
+            .unwrap_or(MAX_BATCH_SIZE)
     }
 
-    /// Checks if a program exists.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID to check
-    ///
-    /// # Returns
-    /// * `bool` - True if program exists, false otherwise
-    pub fn program_exists(env: Env, program_id: String) -> bool {
-        let program_key = DataKey::Program(program_id);
-        env.storage().instance().has(&program_key)
-    }
+    /// Owner of `program_id`; programs created without an explicit owner
+    /// fall back to their authorized payout key.
+    fn get_program_owner_internal(env: &Env, program_id: &String) -> Address {
+        if let Some(owner) = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProgramOwner(program_id.clone()))
+        {
+            return owner;
+        }
 
-    // ========================================================================
-    // Fund Management
-    // ========================================================================
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key
+    }
 
-    /// Locks funds into the program escrow for prize distribution.
+    /// Initialize a new program escrow
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    /// * `program_id` - Unique identifier for this program/hackathon
+    /// * `authorized_payout_key` - Address authorized to trigger payouts (backend)
+    /// * `token_address` - Address of the token contract for transfers (e.g., USDC)
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data with new balance
+    /// * `ProgramData` - The initialized program configuration
     ///
     /// # Panics
-    /// * If amount is zero or negative
-    /// * If program is not initialized
+    /// * If program is already initialized
     ///
     /// # State Changes
-    /// - Increases `total_funds` by amount
-    /// - Increases `remaining_balance` by amount
-    /// - Emits FundsLocked event
-    ///
-    /// # Prerequisites
-    /// Before calling this function:
-    /// 1. Caller must have sufficient token balance
-    /// 2. Caller must approve contract for token transfer
-    /// 3. Tokens must actually be transferred to contract
+    /// - Creates ProgramData with zero balances
+    /// - Sets authorized payout key (immutable after this)
+    /// - Initializes empty payout history
+    /// - Emits ProgramInitialized event
     ///
     /// # Security Considerations
-    /// - Amount must be positive
-    /// - This function doesn't perform the actual token transfer
-    /// - Caller is responsible for transferring tokens to contract
-    /// - Consider verifying contract balance matches recorded amount
-    /// - Multiple lock operations are additive (cumulative)
+    /// - Can only be called once (prevents re-configuration)
+    /// - No authorization required (first-caller initialization)
+    /// - Authorized payout key should be a secure backend service
+    /// - Token address must be a valid Stellar Asset Contract
+    /// - Program ID should be unique and descriptive
     ///
     /// # Events
-    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    /// Emits: `ProgramInit(program_id, authorized_payout_key, token_address, 0)`
     ///
     /// # Example
     /// ```rust
-    /// use soroban_sdk::token;
+    /// use soroban_sdk::{Address, String, Env};
     ///
-    /// // 1. Transfer tokens to contract
-    /// let amount = 10_000_0000000; // 10,000 USDC
-    /// token_client.transfer(
-    ///     &organizer,
-    ///     &contract_address,
-    ///     &amount
+    /// let program_id = String::from_str(&env, "ETHGlobal2024");
+    /// let backend = Address::from_string("GBACKEND...");
+    /// let usdc = Address::from_string("CUSDC...");
+    ///
+    /// let program = escrow_client.init_program(
+    ///     &program_id,
+    ///     &backend,
+    ///     &usdc
     /// );
     ///
-    /// // 2. Record the locked funds
-    /// let updated = escrow_client.lock_program_funds(&amount);
-    /// println!("Locked: {} USDC", amount / 10_000_000);
-    /// println!("Remaining: {}", updated.remaining_balance);
+    /// println!("Program created: {}", program.program_id);
     /// ```
     ///
-    /// # Production Usage
+    /// # Production Setup
     /// ```bash
-    /// # 1. Transfer USDC to contract
-    /// stellar contract invoke \
-    ///   --id USDC_TOKEN_ID \
-    ///   --source ORGANIZER_KEY \
-    ///   -- transfer \
-    ///   --from ORGANIZER_ADDRESS \
-    ///   --to CONTRACT_ADDRESS \
-    ///   --amount 10000000000
+    /// # Deploy contract
+    /// stellar contract deploy \
+    ///   --wasm target/wasm32-unknown-unknown/release/escrow.wasm \
+    ///   --source ORGANIZER_KEY
     ///
-    /// # 2. Record locked funds
+    /// # Initialize program
     /// stellar contract invoke \
     ///   --id CONTRACT_ID \
     ///   --source ORGANIZER_KEY \
-    ///   -- lock_program_funds \
-    ///   --amount 10000000000
+    ///   -- init_program \
+    ///   --program_id "Hackathon2024" \
+    ///   --authorized_payout_key GBACKEND... \
+    ///   --token_address CUSDC...
     /// ```
     ///
     /// # Gas Cost
-    /// Low - Storage update + event emission
+    /// Low - Initial storage writes
+
+    // ========================================================================
+    // Pause and Emergency Functions
+    // ========================================================================
+
+    /// Check if contract is paused (internal helper)
+    fn is_paused_internal(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get::<_, bool>(&DataKey::IsPaused)
+            .unwrap_or(false)
+    }
+
+    /// Get pause status (view function)
+    pub fn is_paused(env: Env) -> bool {
+        Self::is_paused_internal(&env)
+    }
+
+    /// Pause the contract (admin or guardian)
+    /// Prevents new fund locking, payouts, and schedule releases.
+    /// `pause_reason` (at most `MAX_REASON_LEN` bytes) is kept for
+    /// `get_pause_reason` until the contract is unpaused.
+    pub fn pause(env: Env, caller: Address, pause_reason: String) -> () {
+        Self::require_admin_or_guardian(&env, &caller);
+        Self::validate_reason(&env, &pause_reason);
+
+        if Self::is_paused_internal(&env) {
+            return; // Already paused, idempotent
+        }
+
+        env.storage().instance().set(&DataKey::IsPaused, &true);
+        env.storage()
+            .instance()
+            .set(&DataKey::PauseReason, &pause_reason);
+
+        env.events()
+            .publish((symbol_short!("pause"),), (env.ledger().timestamp(),));
+    }
+
+    /// Why the contract was paused; empty while it is not paused.
+    pub fn get_pause_reason(env: Env) -> String {
+        env.storage()
+            .instance()
+            .get(&DataKey::PauseReason)
+            .unwrap_or_else(|| String::from_str(&env, ""))
+    }
+
+    /// Unpause the contract (admin only; the guardian cannot unpause)
+    /// Resumes normal operations
+    pub fn unpause(env: Env) -> () {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        if !Self::is_paused_internal(&env) {
+            return; // Already unpaused, idempotent
+        }
+
+        env.storage().instance().set(&DataKey::IsPaused, &false);
+        env.storage().instance().remove(&DataKey::PauseReason);
+
+        env.events()
+            .publish((symbol_short!("unpause"),), (env.ledger().timestamp(),));
+    }
+
+    /// Opens the circuit breaker immediately (admin or guardian). Closing it
+    /// again goes through the normal admin-driven recovery.
+    pub fn force_open_breaker(env: Env, caller: Address) {
+        Self::require_admin_or_guardian(&env, &caller);
+        error_recovery::open_circuit(&env);
+    }
+
+    /// Sets or clears the guardian (admin only): a low-privilege
+    /// incident-response key that may `pause` and `force_open_breaker`
+    /// but cannot unpause, pay out or move funds.
+    pub fn set_guardian(env: Env, guardian: Option<Address>, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        match guardian {
+            Some(guardian) => env.storage().instance().set(&DataKey::Guardian, &guardian),
+            None => env.storage().instance().remove(&DataKey::Guardian),
+        }
+        Self::record_config_change(&env, symbol_short!("guardian"));
+    }
+
+    /// Returns the guardian, if one is set.
+    pub fn get_guardian(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Guardian)
+    }
+
+    /// Authenticates `caller` and panics with `Unauthorized` unless it is
+    /// the admin or the guardian.
+    fn require_admin_or_guardian(env: &Env, caller: &Address) {
+        caller.require_auth();
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotInitialized));
+        if *caller != admin && Self::get_guardian(env.clone()).as_ref() != Some(caller) {
+            panic_with_error!(env, EscrowError::Unauthorized);
+        }
+    }
+
+    /// Emergency withdrawal for all contract funds (authorized payout key only, only when paused)
+    pub fn emergency_withdraw(env: Env, program_id: String, recipient: Address) -> i128 {
+        // Only allow emergency withdrawal when contract is paused
+        if !Self::is_paused_internal(&env) {
+            panic_with_error!(&env, EscrowError::NotPaused);
+        }
+
+        // Get program data to access token address
+        let program_data: ProgramData =
+            Self::load_program(&env, &program_id)
+                .unwrap_or_else(|| {
+                    panic_with_error!(&env, EscrowError::ProgramNotFound);
+                });
+
+        let client = token::Client::new(&env, &program_data.token_address);
+        // Earmarked funds stay behind for their recipients
+        let balance = client.balance(&env.current_contract_address())
+            - Self::earmarked_total(&env, &program_id);
+
+        if balance <= 0 {
+            return 0; // No funds to withdraw
+        }
+
+        // Transfer all funds to recipient
+        client.transfer(&env.current_contract_address(), &recipient, &balance);
+
+        env.events().publish(
+            (symbol_short!("ewith"),),
+            (balance, env.ledger().timestamp()),
+        );
+
+        balance
+    }
+
+    /// Winds down a paused program in one call by sending its entire
+    /// unearmarked `remaining_balance` to the program owner. Earmarked
+    /// funds and unreleased schedules stay behind.
     ///
-    /// # Common Pitfalls
-    /// - Forgetting to transfer tokens before calling
-    /// -  Locking amount that exceeds actual contract balance
-    /// -  Not verifying contract received the tokens
+    /// Unlike `emergency_withdraw`, only this program's accounted balance
+    /// moves, so other programs sharing the contract are unaffected.
+    ///
+    /// `reason` labels the drain for accounting and is included in the
+    /// emitted event.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    ///
+    /// # Panics
+    /// * If the contract is not paused
+    /// * If `reason` is longer than `MAX_REASON_LEN`
+    pub fn drain_to_admin(env: Env, program_id: String, reason: String, nonce: u64) -> i128 {
+        if !Self::is_paused_internal(&env) {
+            panic_with_error!(&env, EscrowError::NotPaused);
+        }
+        Self::validate_reason(&env, &reason);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let amount = Self::take_unearmarked(&env, &mut program_data);
+        let destination = Self::get_program_owner_internal(&env, &program_id);
+
+        Self::save_program(&env, &program_data);
+
+        if amount > 0 {
+            let client = token::Client::new(&env, &program_data.token_address);
+            client.transfer(&env.current_contract_address(), &destination, &amount);
+        }
+
+        env.events().publish(
+            (DRAINED, program_id),
+            (amount, destination, reason, env.ledger().timestamp()),
+        );
+
+        amount
+    }
+
+    /// High-level initialization entrypoint used by tests and integrators.
+    /// Delegates to `initialize_program` and currently ignores `creator` and
+    /// `initial_liquidity` for backwards compatibility.
+    /// Initialize a new program escrow
+    pub fn init_program(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        _creator: Address,
+        _initial_liquidity: Option<i128>,
+    ) -> ProgramData {
+        Self::initialize_program(env, program_id, authorized_payout_key, token_address)
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        reference_hash: Option<soroban_sdk::Bytes>,
+    ) -> ProgramData {
+        Self::initialize_program(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            reference_hash,
+            false,
+            0,
+            0,
+            ProgramStorageTier::Instance,
+        )
+    }
+
+    /// Initialize a new program escrow with full control over optional setup.
+    ///
+    /// When `require_payout_key_auth` is `true`, the `authorized_payout_key`
+    /// must sign the initialization, proving the key holder controls the
+    /// address and consents to being designated. `init_program` passes
+    /// `false` to preserve the original first-caller behaviour.
+    ///
+    /// `max_total_funds` caps the cumulative amount that may ever be locked
+    /// into the program; `0` means unlimited.
+    ///
+    /// `min_funding_before_payout` blocks every payout path with
+    /// `BelowMinFunding` until `total_funds` reaches it; `0` disables it.
+    ///
+    /// `token_address` may be any SAC, including the native XLM asset
+    /// contract; custody and payouts go through the same `token::Client`.
+    ///
+    /// `storage_tier` selects where the `ProgramData` lives for the rest of
+    /// the program's life; every read and write goes through that tier.
+    pub fn initialize_program(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        reference_hash: Option<soroban_sdk::Bytes>,
+        require_payout_key_auth: bool,
+        max_total_funds: i128,
+        min_funding_before_payout: i128,
+        storage_tier: ProgramStorageTier,
+    ) -> ProgramData {
+        let receipt_id = Self::increment_receipt_id(&env);
+
+        // Check if program already exists
+        if Self::has_program(&env, &program_id) {
+            panic_with_error!(&env, EscrowError::AlreadyInitialized);
+        }
+
+        if max_total_funds < 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if max_total_funds > 0 {
+            env.storage()
+                .instance()
+                .set(&DataKey::MaxTotalFunds(program_id.clone()), &max_total_funds);
+        }
+        if min_funding_before_payout < 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if min_funding_before_payout > 0 {
+            env.storage().instance().set(
+                &DataKey::MinFundingBeforePayout(program_id.clone()),
+                &min_funding_before_payout,
+            );
+        }
+
+        // Optionally require the payout key holder to consent to the designation
+        if require_payout_key_auth {
+            authorized_payout_key.require_auth();
+        }
+
+        let mut total_funds = 0i128;
+        let mut remaining_balance = 0i128;
+        let mut init_liquidity = 0i128;
+
+        if let Some(amount) = initial_liquidity {
+            if amount > 0 {
+                // Transfer initial liquidity from creator to contract
+                let contract_address = env.current_contract_address();
+                let token_client = token::Client::new(&env, &token_address);
+                creator.require_auth();
+                token_client.transfer(&creator, &contract_address, &amount);
+                total_funds = amount;
+                remaining_balance = amount;
+                init_liquidity = amount;
+            }
+        }
+
+        // Create program data
+        let program_data = ProgramData {
+            program_id: program_id.clone(),
+            total_funds,
+            remaining_balance,
+            authorized_payout_key: authorized_payout_key.clone(),
+            payout_history: vec![&env],
+            token_address: token_address.clone(),
+            initial_liquidity: init_liquidity,
+            reference_hash: reference_hash.clone(),
+        };
+
+        // Initialize fee config with zero fees (disabled by default)
+        let fee_config = FeeConfig {
+            lock_fee_rate: 0,
+            payout_fee_rate: 0,
+            fee_recipient: authorized_payout_key.clone(),
+            fee_enabled: false,
+        };
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        // Store program data in the chosen tier
+        if storage_tier == ProgramStorageTier::Persistent {
+            env.storage().instance().set(
+                &DataKey::ProgramStorageTier(program_id.clone()),
+                &storage_tier,
+            );
+        }
+        Self::save_program(&env, &program_data);
+        let mut registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(Vec::new(&env));
+        registry.push_back(program_id.clone());
+        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramOwner(program_id.clone()), &creator);
+        if let Ok(Ok(decimals)) = token::Client::new(&env, &token_address).try_decimals() {
+            env.storage()
+                .instance()
+                .set(&DataKey::TokenDecimals(program_id.clone()), &decimals);
+            // Scale the fallback thresholds to the first program's token
+            threshold_monitor::set_default_decimals(&env, decimals);
+        }
+        env.storage()
+            .instance()
+            .set(&SCHEDULES, &Vec::<ProgramReleaseSchedule>::new(&env));
+        env.storage()
+            .instance()
+            .set(&RELEASE_HISTORY, &Vec::<ProgramReleaseHistory>::new(&env));
+        env.storage().instance().set(&NEXT_SCHEDULE_ID, &1_u64);
+
+        // Emit ProgramInitialized event
+        env.events().publish(
+            (PROGRAM_INITIALIZED,),
+            ProgramInitializedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                authorized_payout_key,
+                token_address,
+                total_funds,
+                reference_hash,
+                receipt_id,
+            },
+        );
+
+        // Zero-balance programs are funded lazily; only real liquidity is
+        // reported as locked so indexers never see 0-value funding events.
+        if init_liquidity > 0 {
+            env.events().publish(
+                (FUNDS_LOCKED, program_data.program_id.clone()),
+                FundsLockedEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: program_data.program_id.clone(),
+                    amount: init_liquidity,
+                    remaining_balance: program_data.remaining_balance,
+                    receipt_id,
+                    percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+                },
+            );
+        }
+
+        program_data
+    }
+
+    /// Initialize a program together with the threshold-monitor limits and
+    /// payout fee in one atomic call (admin only), so the program is never
+    /// live without limits.
+    ///
+    /// `threshold_config` goes through the same validation as
+    /// `set_threshold_config`; `fee_bps` is the payout fee rate, validated as
+    /// in `update_fee_config` and charged to `fee_collector` (`0` leaves fees
+    /// disabled). The admin becomes the program owner.
+    ///
+    /// Both settings are contract-wide, so this only bootstraps them: once a
+    /// threshold config or an enabled fee exists, use the dedicated setters.
+    /// The writes are versioned and logged like those setters.
+    ///
+    /// # Panics
+    /// * `InvalidConfig` if `threshold_config` or `fee_bps` is invalid
+    /// * `AlreadyInitialized` if `program_id` already exists, or a threshold
+    ///   config or enabled fee config is already installed
+    pub fn init_program_full(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        threshold_config: threshold_monitor::ThresholdConfig,
+        fee_bps: i128,
+        fee_collector: Address,
+        nonce: u64,
+    ) -> ProgramData {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+        Self::validate_fee_rate(&env, fee_bps);
+        if threshold_monitor::has_threshold_config(&env)
+            || Self::get_fee_config_internal(&env).fee_enabled
+        {
+            panic_with_error!(&env, EscrowError::AlreadyInitialized);
+        }
+
+        let program_data = Self::initialize_program(
+            env.clone(),
+            program_id,
+            authorized_payout_key,
+            token_address,
+            admin,
+            None,
+            None,
+            false,
+            0,
+            0,
+            ProgramStorageTier::Instance,
+        );
+
+        if threshold_monitor::set_threshold_config(&env, threshold_config).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if error_recovery::get_state(&env) != error_recovery::CircuitState::Open {
+            let _ = error_recovery::check_and_allow_with_thresholds(&env);
+        }
+        Self::record_config_change(&env, symbol_short!("threshold"));
+
+        // Replaces the disabled default written by `initialize_program`
+        let fee_config = FeeConfig {
+            lock_fee_rate: 0,
+            payout_fee_rate: fee_bps,
+            fee_recipient: fee_collector,
+            fee_enabled: fee_bps > 0,
+        };
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+        env.events().publish(
+            (symbol_short!("fee_cfg"),),
+            (
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
+            ),
+        );
+        Self::record_config_change(&env, symbol_short!("fee_cfg"));
+
+        program_data
+    }
+
+    /// Initialize a program keyed by a 32-byte identifier (e.g. the keccak
+    /// of the program name) instead of a free-form string.
+    ///
+    /// The hash is the canonical identity: the program's data is stored
+    /// under `DataKey::HashedProgram(program_hash)`, and a second program
+    /// with the same hash is rejected even if `display_id` differs.
+    /// `display_id` is kept as metadata in `ProgramData.program_id` and
+    /// mapped to the hash, so every String-keyed entrypoint keeps working;
+    /// resolve it from the hash with `get_program_id_by_hash`.
+    ///
+    /// # Panics
+    /// * If a program with `program_hash` already exists
+    /// * If `display_id` is already used by another program
+    pub fn init_program_hashed(
+        env: Env,
+        program_hash: BytesN<32>,
+        display_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        reference_hash: Option<soroban_sdk::Bytes>,
+    ) -> ProgramData {
+        if env
+            .storage()
+            .instance()
+            .has(&DataKey::HashedProgram(program_hash.clone()))
+            || Self::has_program(&env, &display_id)
+        {
+            panic_with_error!(&env, EscrowError::AlreadyInitialized);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramHash(display_id.clone()), &program_hash);
+
+        Self::initialize_program(
+            env.clone(),
+            display_id.clone(),
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            reference_hash,
+            false,
+            0,
+            0,
+            ProgramStorageTier::Instance,
+        )
+    }
+
+    /// Resolves a hashed program identifier to its display `program_id`.
+    pub fn get_program_id_by_hash(env: Env, program_hash: BytesN<32>) -> Option<String> {
+        env.storage()
+            .instance()
+            .get::<_, ProgramData>(&DataKey::HashedProgram(program_hash))
+            .map(|program_data| program_data.program_id)
+    }
+
+    /// Returns the program stored under `program_hash`.
+    pub fn get_program_by_hash(env: Env, program_hash: BytesN<32>) -> ProgramData {
+        env.storage()
+            .instance()
+            .get(&DataKey::HashedProgram(program_hash))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound))
+    }
+
+    /// Batch-initialize multiple programs in one transaction (all-or-nothing).
+    pub fn batch_initialize_programs(
+        env: Env,
+        items: Vec<ProgramInitItem>,
+    ) -> Result<u32, BatchError> {
+        let batch_size = items.len() as u32;
+        if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+            return Err(BatchError::InvalidBatchSize);
+        }
+        for i in 0..batch_size {
+            for j in (i + 1)..batch_size {
+                if items.get(i).unwrap().program_id == items.get(j).unwrap().program_id {
+                    return Err(BatchError::DuplicateProgramId);
+                }
+            }
+        }
+        for i in 0..batch_size {
+            if Self::has_program(&env, &items.get(i).unwrap().program_id) {
+                return Err(BatchError::ProgramAlreadyExists);
+            }
+        }
+
+        let mut registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(Vec::new(&env));
+
+        let start_time = env.ledger().timestamp();
+        for i in 0..batch_size {
+            let item = items.get(i).unwrap();
+            let program_id = item.program_id.clone();
+            let authorized_payout_key = item.authorized_payout_key.clone();
+            let token_address = item.token_address.clone();
+
+            if program_id.is_empty() {
+                return Err(BatchError::InvalidBatchSize);
+            }
+
+            let program_data = ProgramData {
+                program_id: program_id.clone(),
+                total_funds: 0,
+                remaining_balance: 0,
+                authorized_payout_key: authorized_payout_key.clone(),
+                payout_history: Vec::new(&env),
+                token_address: token_address.clone(),
+                initial_liquidity: 0,
+                reference_hash: item.reference_hash.clone(),
+            };
+            Self::save_program(&env, &program_data);
+
+            if i == 0 {
+                let fee_config = FeeConfig {
+                    lock_fee_rate: 0,
+                    payout_fee_rate: 0,
+                    fee_recipient: authorized_payout_key.clone(),
+                    fee_enabled: false,
+                };
+                env.storage().instance().set(&FEE_CONFIG, &fee_config);
+            }
+
+            let multisig_config = MultisigConfig {
+                threshold_amount: i128::MAX,
+                signers: Vec::new(&env),
+                required_signatures: 0,
+            };
+            env.storage().persistent().set(
+                &DataKey::MultisigConfig(program_id.clone()),
+                &multisig_config,
+            );
+
+            registry.push_back(program_id.clone());
+            let receipt_id = Self::increment_receipt_id(&env);
+            
+            env.events().publish(
+                (PROGRAM_INITIALIZED,),
+                ProgramInitializedEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id,
+                    authorized_payout_key: authorized_payout_key.clone(),
+                    token_address,
+                    total_funds: 0,
+                    reference_hash: item.reference_hash,
+                    receipt_id,
+                },
+            );
+
+            // Emit registration event
+            env.events().publish(
+                (PROGRAM_REGISTERED,),
+                (item.program_id.clone(), authorized_payout_key.clone(), item.token_address.clone(), 0i128),
+            );
+
+            // Track successful operation
+            monitoring::track_operation(&env, symbol_short!("init_prg"), authorized_payout_key, true);
+        }
+        env.storage().instance().set(&PROGRAM_REGISTRY, &registry);
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start_time);
+        monitoring::emit_performance(&env, symbol_short!("init_prg"), duration);
+
+        Ok(batch_size)
+    }
+
+    /// Calculate fee amount based on rate (in basis points)
+    fn calculate_fee(env: &Env, amount: i128, fee_rate: i128) -> i128 {
+        if fee_rate == 0 {
+            return 0;
+        }
+        // Fee = (amount * fee_rate) / BASIS_POINTS, rounded per config
+        token_math::mul_div(amount, fee_rate, BASIS_POINTS, Self::rounding_mode(env)).unwrap_or(0)
+    }
+
+    /// Payout fee owed on `amount` under `fee_config` (0 when fees are off).
+    fn payout_fee(env: &Env, fee_config: &FeeConfig, amount: i128) -> i128 {
+        if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
+            Self::calculate_fee(env, amount, fee_config.payout_fee_rate)
+        } else {
+            0
+        }
+    }
+
+    /// Rounding mode applied to fee and share-split math (default `Floor`).
+    fn rounding_mode(env: &Env) -> RoundingMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::RoundingMode)
+            .unwrap_or(RoundingMode::Floor)
+    }
+
+    /// Panics with `InvalidConfig` unless `rate` is within `0..=MAX_FEE_RATE`.
+    fn validate_fee_rate(env: &Env, rate: i128) {
+        if rate < 0 || rate > MAX_FEE_RATE {
+            panic_with_error!(env, EscrowError::InvalidConfig);
+        }
+    }
+
+    /// Get fee configuration (internal helper)
+    fn get_fee_config_internal(env: &Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&FEE_CONFIG)
+            .unwrap_or_else(|| FeeConfig {
+                lock_fee_rate: 0,
+                payout_fee_rate: 0,
+                fee_recipient: env.current_contract_address(),
+                fee_enabled: false,
+            })
+    }
+
+    /// Lock initial funds into the program escrow
+    ///
+    /// Lists all registered program IDs in the contract.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - List of all program IDs
+    ///
+    /// # Example
+    /// ```rust
+    /// let programs = escrow_client.list_programs();
+    /// for program_id in programs.iter() {
+    ///     println!("Program: {}", program_id);
+    /// }
+    /// ```
+    pub fn list_programs(env: Env) -> Vec<String> {
+        env.storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env])
+    }
+
+    /// Checks if a program exists.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to check
+    ///
+    /// # Returns
+    /// * `bool` - True if program exists, false otherwise
+    pub fn program_exists(env: Env, program_id: String) -> bool {
+        Self::has_program(&env, &program_id)
+    }
+
+    /// Returns the storage tier holding `program_id`'s data.
+    pub fn get_program_storage_tier(env: Env, program_id: String) -> ProgramStorageTier {
+        if !Self::has_program(&env, &program_id) {
+            panic_with_error!(&env, EscrowError::ProgramNotFound);
+        }
+        Self::program_storage_tier(&env, &program_id)
+    }
+
+    // ========================================================================
+    // Fund Management
+    // ========================================================================
+
+    /// Locks funds into the program escrow for prize distribution.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `amount` - Amount of tokens to lock (in token's smallest denomination)
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data with new balance
+    ///
+    /// # Panics
+    /// * If amount is zero or negative
+    /// * If program is not initialized
+    ///
+    /// # State Changes
+    /// - Increases `total_funds` by amount
+    /// - Increases `remaining_balance` by amount
+    /// - Emits FundsLocked event
+    ///
+    /// # Prerequisites
+    /// Before calling this function:
+    /// 1. Caller must have sufficient token balance
+    /// 2. Caller must approve contract for token transfer
+    /// 3. Tokens must actually be transferred to contract
+    ///
+    /// # Security Considerations
+    /// - Amount must be positive
+    /// - This function doesn't perform the actual token transfer
+    /// - Caller is responsible for transferring tokens to contract
+    /// - Consider verifying contract balance matches recorded amount
+    /// - Multiple lock operations are additive (cumulative)
+    ///
+    /// # Events
+    /// Emits: `FundsLocked(program_id, amount, new_remaining_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::token;
+    ///
+    /// // 1. Transfer tokens to contract
+    /// let amount = 10_000_0000000; // 10,000 USDC
+    /// token_client.transfer(
+    ///     &organizer,
+    ///     &contract_address,
+    ///     &amount
+    /// );
+    ///
+    /// // 2. Record the locked funds
+    /// let updated = escrow_client.lock_program_funds(&amount);
+    /// println!("Locked: {} USDC", amount / 10_000_000);
+    /// println!("Remaining: {}", updated.remaining_balance);
+    /// ```
+    ///
+    /// # Production Usage
+    /// ```bash
+    /// # 1. Transfer USDC to contract
+    /// stellar contract invoke \
+    ///   --id USDC_TOKEN_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- transfer \
+    ///   --from ORGANIZER_ADDRESS \
+    ///   --to CONTRACT_ADDRESS \
+    ///   --amount 10000000000
+    ///
+    /// # 2. Record locked funds
+    /// stellar contract invoke \
+    ///   --id CONTRACT_ID \
+    ///   --source ORGANIZER_KEY \
+    ///   -- lock_program_funds \
+    ///   --amount 10000000000
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Low - Storage update + event emission
+    ///
+    /// # Common Pitfalls
+    /// - Forgetting to transfer tokens before calling
+    /// -  Locking amount that exceeds actual contract balance
+    /// -  Not verifying contract received the tokens
+
+    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::enforce_funding_not_frozen(&env);
+
+        // Validate amount
+        if amount <= 0 {
+            // `caller` is not defined here, assuming it should be the authorized_payout_key or similar
+            // For now, removing the monitoring call as it would cause a compile error.
+            // monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        Self::enforce_max_single_lock(&env, amount);
+
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        Self::require_token_allowed(&env, &program_data.token_address);
+        Self::require_program_open(&env, &program_data.program_id);
+
+        // Require the authorized payout key or creator
+        program_data.authorized_payout_key.require_auth();
+
+        // Calculate fee
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = if fee_config.fee_enabled {
+            Self::calculate_fee(&env, amount, fee_config.lock_fee_rate)
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        let total_funds = program_data
+            .total_funds
+            .checked_add(net_amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        let remaining_balance = program_data
+            .remaining_balance
+            .checked_add(net_amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+
+        Self::enforce_max_total_funds(&env, &program_id, total_funds);
+
+        // Update balances with net amount
+        program_data.total_funds = total_funds;
+        program_data.remaining_balance = remaining_balance;
+
+        // Store updated data
+        Self::save_program(&env, &program_data);
+        Self::update_low_balance_alert(&env, &program_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::LastLockAt(program_id.clone()), &env.ledger().timestamp());
+
+        let receipt_id = Self::increment_receipt_id(&env);
+
+        // Emit fee collected event if applicable
+        if fee_amount > 0 {
+            env.events().publish(
+                (FEE_COLLECTED,),
+                FeeCollectedEvent {
+                    version: 2, // Changed from EVENT_VERSION_V2
+                    program_id: program_data.program_id.clone(),
+                    fee_type: symbol_short!("lock"),
+                    amount: fee_amount,
+                    recipient: fee_config.fee_recipient.clone(),
+                    receipt_id,
+                },
+            );
+        }
+
+        // Emit FundsLocked event (with net amount after fee)
+        env.events().publish(
+            (FUNDS_LOCKED, program_data.program_id.clone()),
+            FundsLockedEvent {
+                version: 2, // Changed from EVENT_VERSION_V2
+                program_id: program_data.program_id.clone(),
+                amount: net_amount, // Use net_amount here
+                remaining_balance: program_data.remaining_balance,
+                receipt_id,
+                percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+            },
+        );
+
+        program_data
+    }
+
+    // ========================================================================
+    // Initialization & Admin
+    // ========================================================================
+
+    /// Initialize the contract with an admin.
+    /// This must be called before any admin protected functions (like pause) can be used.
+    pub fn initialize_contract(env: Env, admin: Address) {
+        Self::initialize_contract_with_options(env, admin, false)
+    }
+
+    /// Initialize the contract with an admin and deployment-wide options.
+    ///
+    /// When `require_threshold_config` is `true`, `single_payout` and
+    /// `batch_payout` panic until `set_threshold_config` has been called,
+    /// instead of running under the built-in default thresholds.
+    pub fn initialize_contract_with_options(
+        env: Env,
+        admin: Address,
+        require_threshold_config: bool,
+    ) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, EscrowError::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        if require_threshold_config {
+            env.storage()
+                .instance()
+                .set(&DataKey::RequireThresholdConfig, &true);
+        }
+    }
+
+    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
+    pub fn set_admin(env: Env, admin: Address) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            current.require_auth();
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Returns the current admin address, if set.
+    pub fn get_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    /// Whether `initialize_contract` has run. Admin-gated entrypoints panic
+    /// with `NotInitialized` until it has.
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Admin)
+    }
+
+    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+        env.storage()
+            .instance()
+            .get(&SCHEDULES)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Update pause flags (admin only)
+    pub fn set_paused(
+        env: Env,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<String>,
+    ) {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, EscrowError::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut flags = Self::get_pause_flags(&env);
+        let timestamp = env.ledger().timestamp();
+
+        if reason.is_some() {
+            flags.pause_reason = reason.clone();
+        }
+
+        if let Some(paused) = lock {
+            flags.lock_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                PauseStateChanged {
+                    operation: symbol_short!("lock"),
+                    paused,
+                    admin: admin.clone(),
+                    reason: reason.clone(),
+                    timestamp,
+                    receipt_id,
+                },
+            );
+        }
+
+        if let Some(paused) = release {
+            flags.release_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                PauseStateChanged {
+                    operation: symbol_short!("release"),
+                    paused,
+                    admin: admin.clone(),
+                    reason: reason.clone(),
+                    timestamp,
+                    receipt_id,
+                },
+            );
+        }
+
+        if let Some(paused) = refund {
+            flags.refund_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                PauseStateChanged {
+                    operation: symbol_short!("refund"),
+                    paused,
+                    admin: admin.clone(),
+                    reason: reason.clone(),
+                    timestamp,
+                    receipt_id,
+                },
+            );
+        }
+
+        let any_paused = flags.lock_paused || flags.release_paused || flags.refund_paused;
+
+        if any_paused {
+            if flags.paused_at == 0 {
+                flags.paused_at = timestamp;
+            }
+        } else {
+            0
+        };
+        let net_amount = amount - fee_amount;
+
+        // Update balances with net amount
+        program_data.total_funds += net_amount;
+        program_data.remaining_balance += net_amount;
+
+        // Emit fee collected event if applicable
+        if fee_amount > 0 {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        let token_client = token::TokenClient::new(&env, &program_data.token_address);
+
+        let contract_address = env.current_contract_address();
+        let balance = token_client.balance(&contract_address);
+
+        if balance > 0 {
+            token_client.transfer(&contract_address, &target, &balance);
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (symbol_short!("em_wtd"),),
+                EmergencyWithdrawEvent {
+                    admin,
+                    target: target.clone(),
+                    amount: balance,
+                    timestamp: env.ledger().timestamp(),
+                    receipt_id,
+                },
+            );
+        }
+
+        // Time-weighted average metrics: record this lock for sliding-window analytics
+        twa::record_lock(&env, amount);
+
+        // Store updated data
+        Self::save_program(&env, &program_data);
+
+        // Emit FundsLocked event (with net amount after fee)
+        env.events().publish(
+            (FUNDS_LOCKED, program_data.program_id.clone()),
+            (
+                program_data.program_id.clone(),
+                net_amount,
+                program_data.remaining_balance,
+            ),
+        );
+
+    pub fn get_analytics(_env: Env) -> monitoring::Analytics {
+        monitoring::Analytics {
+            operation_count: 0,
+            unique_users: 0,
+            error_count: 0,
+            error_rate: 0,
+        }
+    }
+
+    // ========================================================================
+    // Initialization & Admin
+    // ========================================================================
+
+    /// Executes batch payouts to multiple recipients simultaneously.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipients` - Vector of recipient addresses
+    /// * `amounts` - Vector of amounts (must match recipients length)
+    ///
+    /// # Returns
+    /// Updated ProgramData after payouts
+    ///
+    /// # Ordering
+    /// Recipients are paid strictly in input order and their `PayoutRecord`s
+    /// are appended to `payout_history` in that same order, so index `i` of
+    /// the batch lands at `payout_history[len_before + i]`. Duplicates are
+    /// kept as separate records. The batch is atomic: if entry `k` fails,
+    /// entries `0..k` leave no persistent effect.
+    pub fn batch_payout(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        Self::execute_batch_payout(env, program_id, recipients, amounts, None)
+    }
+
+    /// `batch_payout` signed by an explicit `signer`: the program's
+    /// `authorized_payout_key` or its unexpired delegate. Lets the cold key
+    /// keep paying while a delegate is active.
+    pub fn batch_payout_by(
+        env: Env,
+        signer: Address,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let signer = Self::require_payout_auth(
+            &env,
+            &program_id,
+            &program_data.authorized_payout_key,
+            Some(signer),
+            recipients.into_val(&env),
+            amounts.into_val(&env),
+        );
+        let signers = vec![&env, signer];
+        Self::execute_batch_payout(env, program_id, recipients, amounts, Some(signers))
+    }
+
+    /// `batch_payout` with selectable failure handling, returning one
+    /// outcome per entry.
+    ///
+    /// `Atomic` behaves exactly like `batch_payout`. `BestEffort` skips
+    /// entries that would be rejected on their own (non-positive amount,
+    /// above `max_single_payout`, not whitelisted in safe mode, within the
+    /// recipient cooldown, or a self-payout) and pays the rest in input
+    /// order; skipped entries report `false`. Under a recipient cooldown a
+    /// repeated recipient is paid only at its first payable entry. Batch-wide
+    /// failures such as auth, pause, thresholds or insufficient balance
+    /// still abort the call. The signer authorizes the full input once, so
+    /// a scoped-args nonce is consumed once per call, even when every entry
+    /// is skipped; the same signed batch cannot be replayed once its
+    /// entries become payable.
+    pub fn batch_payout_with_mode(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        mode: BatchMode,
+    ) -> Vec<bool> {
+        if mode == BatchMode::Atomic {
+            let len = recipients.len();
+            Self::execute_batch_payout(env.clone(), program_id, recipients, amounts, None);
+            let mut outcomes = Vec::new(&env);
+            for _ in 0..len {
+                outcomes.push_back(true);
+            }
+            return outcomes;
+        }
+
+        if recipients.len() > Self::get_max_batch_size_internal(&env) {
+            panic_with_error!(&env, EscrowError::BatchTooLarge);
+        }
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, EscrowError::BatchLengthMismatch);
+        }
+        if recipients.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
+        let signer = Self::require_payout_auth(
+            &env,
+            &program_id,
+            &program_data.authorized_payout_key,
+            None,
+            recipients.into_val(&env),
+            amounts.into_val(&env),
+        );
+
+        let cooldown_active = Self::get_recipient_cooldown(env.clone()) > 0;
+        let mut outcomes = Vec::new(&env);
+        let mut kept_recipients: Vec<Address> = Vec::new(&env);
+        let mut kept_amounts = Vec::new(&env);
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+            // An earlier entry in this batch already starts the cooldown
+            let payable = !(cooldown_active && kept_recipients.contains(&recipient))
+                && Self::is_payable_entry(&env, &program_data, &recipient, amount);
+            if payable {
+                kept_recipients.push_back(recipient);
+                kept_amounts.push_back(amount);
+            }
+            outcomes.push_back(payable);
+        }
+        if !kept_recipients.is_empty() {
+            let signers = vec![&env, signer];
+            Self::execute_batch_payout(
+                env,
+                program_id,
+                kept_recipients,
+                kept_amounts,
+                Some(signers),
+            );
+        }
+        outcomes
+    }
+
+    /// Pays a batch co-signed by several signers, each authorizing its own
+    /// `(signer, recipient, amount, nonce)` entry against its own nonce.
+    ///
+    /// Every signer must be the program's payout key, its active delegate
+    /// or one of its multisig signers. A signer may appear more than once
+    /// with consecutive nonces. Any failure, including one wrong nonce,
+    /// reverts the whole batch; otherwise it runs like `batch_payout`.
+    pub fn multi_signer_batch(
+        env: Env,
+        program_id: String,
+        payouts: Vec<(Address, Address, i128, u64)>,
+    ) -> ProgramData {
+        if payouts.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
+        if payouts.len() > Self::get_max_batch_size_internal(&env) {
+            panic_with_error!(&env, EscrowError::BatchTooLarge);
+        }
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let mut recipients = Vec::new(&env);
+        let mut amounts = Vec::new(&env);
+        let mut signers: Vec<Address> = Vec::new(&env);
+        for (signer, recipient, amount, nonce) in payouts.iter() {
+            if !Self::is_program_signer(&env, &program_data, &signer) {
+                panic_with_error!(&env, EscrowError::Unauthorized);
+            }
+            signer.require_auth();
+            Self::consume_nonce(&env, &signer, nonce);
+            if !signers.contains(&signer) {
+                signers.push_back(signer);
+            }
+            recipients.push_back(recipient);
+            amounts.push_back(amount);
+        }
+
+        // Rate limits are charged once to each signer that actually signed
+        Self::execute_batch_payout(env, program_id, recipients, amounts, Some(signers))
+    }
+
+    /// Whether `signer` may co-sign `multi_signer_batch` entries for the
+    /// program: its payout key, active delegate or a multisig signer.
+    fn is_program_signer(env: &Env, program_data: &ProgramData, signer: &Address) -> bool {
+        if *signer == program_data.authorized_payout_key {
+            return true;
+        }
+        let program_id = program_data.program_id.clone();
+        if let Some(entry) = env
+            .storage()
+            .instance()
+            .get::<_, PayoutDelegate>(&DataKey::PayoutDelegate(program_id.clone()))
+        {
+            if entry.delegate == *signer && env.ledger().timestamp() < entry.expiry {
+                return true;
+            }
+        }
+        env.storage()
+            .persistent()
+            .get::<_, MultisigConfig>(&DataKey::MultisigConfig(program_id))
+            .map_or(false, |config| config.signers.contains(signer))
+    }
+
+    /// Body of `batch_payout`. `pre_authorized` lists the signers when the
+    /// caller has already authenticated them itself; each one is charged
+    /// the signer interval and rate limit.
+    fn execute_batch_payout(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        pre_authorized: Option<Vec<Address>>,
+    ) -> ProgramData {
+        // Bound the batch before doing any work so oversized or empty inputs
+        // fail cleanly, without consuming the signer's nonce
+        if recipients.len() > Self::get_max_batch_size_internal(&env) {
+            panic_with_error!(&env, EscrowError::BatchTooLarge);
+        }
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, EscrowError::BatchLengthMismatch);
+        }
+        if recipients.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
+        Self::require_threshold_config_if_needed(&env);
+
+        // Reentrancy guard: Check and set
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+
+        // Verify authorization
+        let program_data: ProgramData =
+            Self::load_program(&env, &program_id)
+                .unwrap_or_else(|| {
+                    reentrancy_guard::clear_entered(&env);
+                    panic_with_error!(&env, EscrowError::ProgramNotFound)
+                });
+
+        Self::assert_dependencies_satisfied(&env, &program_data.program_id);
+        Self::require_min_funding(&env, &program_data);
+
+        // Authorize via the payout key or its active delegate, then rate limit each signer
+        let signers = match pre_authorized {
+            Some(signers) => signers,
+            None => vec![
+                &env,
+                Self::require_payout_auth(
+                    &env,
+                    &program_id,
+                    &program_data.authorized_payout_key,
+                    None,
+                    recipients.into_val(&env),
+                    amounts.into_val(&env),
+                ),
+            ],
+        };
+        for signer in signers.iter() {
+            Self::enforce_signer_interval(&env, &signer);
+            anti_abuse::check_rate_limit(&env, signer);
+        }
+
+        // Calculate total with overflow protection
+        let mut total_payout: i128 = 0;
+        for i in 0..amounts.len() {
+            let amount = amounts.get(i).unwrap();
+            if amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidAmount);
+            }
+            total_payout = total_payout
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        }
+
+        // Enforce max_single_payout per recipient, not just on the aggregate
+        if let Err(breach) = threshold_monitor::check_batch_payout_amounts(&env, &amounts) {
+            threshold_monitor::emit_program_threshold_breach_event(&env, &program_id, &breach);
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::SinglePayoutExceeded);
+        }
+        for amount in amounts.iter() {
+            if let Err(breach) = threshold_monitor::check_window_single_outflow(&env, amount) {
+                threshold_monitor::emit_program_threshold_breach_event(&env, &program_id, &breach);
+                reentrancy_guard::clear_entered(&env);
+                panic_with_error!(&env, EscrowError::SinglePayoutExceeded);
+            }
+        }
+
+        // Check circuit breaker with thresholds
+        if let Err(_) = error_recovery::check_and_allow_with_thresholds(&env) {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::CircuitBreakerOpen);
+        }
+        Self::enforce_token_thresholds(&env, &program_data);
+        for recipient in recipients.iter() {
+            Self::enforce_safe_mode(&env, &recipient);
+            Self::enforce_recipient_cooldown(&env, &recipient);
+            Self::enforce_no_self_payout(&env, &program_data, &recipient);
+        }
+
+        // Validate balance
+        if total_payout > program_data.remaining_balance {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+        let mut unearmarked = Self::unearmarked_balance(&env, &program_data);
+        for i in 0..recipients.len() {
+            Self::draw_earmark(
+                &env,
+                &program_id,
+                &recipients.get(i).unwrap(),
+                amounts.get(i).unwrap(),
+                &mut unearmarked,
+            );
+        }
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            total_payout,
+        );
+
+        // Enforce the calendar-day outflow cap
+        if threshold_monitor::check_daily_limit(&env, total_payout).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::DailyLimitExceeded);
+        }
+
+        // Calculate fees if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let mut total_fees: i128 = 0;
+
+        // Execute transfers
+        let mut updated_history = program_data.payout_history.clone();
+        let timestamp = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+
+            // Calculate fee for this payout
+            let fee_amount = Self::payout_fee(&env, &fee_config, amount);
+            let net_amount = amount - fee_amount;
+            total_fees += fee_amount;
+
+            // Transfer net amount to recipient
+            token_client.transfer(&contract_address, &recipient.clone(), &net_amount);
+
+            // Transfer fee to fee recipient if applicable
+            if fee_amount > 0 {
+                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+            }
+
+            // Record payout (with net amount)
+            let payout_record = PayoutRecord {
+                recipient: recipient.clone(),
+                amount: net_amount,
+                timestamp,
+            };
+            payout_digest::record(&env, &program_id, &payout_record);
+            Self::record_recipient_payout(&env, &recipient, net_amount);
+            updated_history.push_back(payout_record);
+            
+            // Record outflow for threshold monitoring
+            threshold_monitor::record_outflow(&env, amount);
+            threshold_monitor::record_token_outflow(&env, &program_data.token_address, amount);
+        }
+        threshold_monitor::record_operation_success(&env);
+
+        // Emit fee collected event if applicable
+        if total_fees > 0 && Self::emits_at(&env, EVENT_LEVEL_NORMAL) {
+            env.events().publish(
+                (symbol_short!("fee"),),
+                (
+                    symbol_short!("payout"),
+                    total_fees,
+                    fee_config.payout_fee_rate,
+                    fee_config.fee_recipient.clone(),
+                ),
+            );
+        }
+
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= total_payout; // Total includes fees
+        updated_data.payout_history = updated_history;
+
+        // Store updated data
+        Self::save_program(&env, &updated_data);
+        Self::update_low_balance_alert(&env, &updated_data);
+        for i in 0..recipients.len() {
+            let amount = amounts.get(i).unwrap();
+            let net_amount = amount - Self::payout_fee(&env, &fee_config, amount);
+            Self::notify_recipient(&env, &recipients.get(i).unwrap(), net_amount);
+        }
+
+        // Time-weighted average: settlement time from last lock to this batch
+        let last_lock = env
+            .storage()
+            .instance()
+            .get(&DataKey::TwaLastLock)
+            .unwrap_or(0u64);
+        let settlement_time = timestamp.saturating_sub(last_lock);
+        twa::record_settlement_n(&env, settlement_time, recipients.len() as u64);
+        let receipt_id = Self::increment_receipt_id(&env);
+
+        // Emit event
+        if Self::emits_at(&env, EVENT_LEVEL_NORMAL) {
+            env.events().publish(
+                (BATCH_PAYOUT,),
+                BatchPayoutEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: updated_data.program_id.clone(),
+                    recipient_count: recipients.len() as u32,
+                    total_amount: total_payout,
+                    remaining_balance: updated_data.remaining_balance,
+                    receipt_id,
+                },
+            );
+        }
+        if Self::emits_at(&env, EVENT_LEVEL_VERBOSE) {
+            for i in 0..recipients.len() {
+                env.events().publish(
+                    (PAYOUT, updated_data.program_id.clone()),
+                    PayoutEvent {
+                        version: EVENT_VERSION_V2,
+                        program_id: updated_data.program_id.clone(),
+                        recipient: recipients.get(i).unwrap(),
+                        amount: amounts.get(i).unwrap(),
+                        remaining_balance: updated_data.remaining_balance,
+                        receipt_id,
+                    },
+                );
+            }
+        }
+
+        reentrancy_guard::clear_entered(&env);
+        updated_data
+    }
+
+    /// Executes a single payout to one recipient.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `recipient` - Address of the prize recipient
+    /// * `amount` - Amount to transfer (in token's smallest denomination)
+    /// * `valid_until` - Last ledger timestamp at which this payout may execute
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data after payout
+    ///
+    /// # Panics
+    /// * If caller is not the authorized payout key
+    /// * If program is not initialized
+    /// * If amount is zero or negative
+    /// * If amount exceeds remaining balance
+    /// * If the ledger timestamp is past `valid_until`
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # State Changes
+    /// - Transfers tokens from contract to recipient
+    /// - Adds PayoutRecord to history
+    /// - Decreases `remaining_balance` by amount
+    /// - Emits Payout event
+    ///
+    /// # Security Considerations
+    /// - Verify recipient address before calling
+    /// - Amount must be positive
+    /// - Balance check prevents overdraft
+    /// - Transfer is logged in payout history
+    ///
+    /// # Events
+    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
+    ///
+    /// # Example
+    /// ```rust
+    /// use soroban_sdk::Address;
+    ///
+    /// let winner = Address::from_string("GWINNER...");
+    /// let prize = 1_000_0000000; // $1,000 USDC
+    ///
+    /// // Execute single payout
+    /// let result = escrow_client.single_payout(&program_id, &winner, &prize, &valid_until);
+    /// println!("Paid {} to winner", prize);
+    /// ```
+    ///
+    /// # Gas Cost
+    /// Medium - Single token transfer + storage update
+    ///
+    /// # Use Cases
+    /// - Individual prize awards
+    /// - Bonus payments
+    /// - Late additions to prize pool distribution
+    pub fn single_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        valid_until: u64,
+    ) -> ProgramData {
+        Self::execute_single_payout(env, program_id, recipient, amount, valid_until, None)
+    }
+
+    /// `single_payout` signed by an explicit `signer`: the program's
+    /// `authorized_payout_key` or its unexpired delegate. Lets the cold key
+    /// keep paying while a delegate is active.
+    pub fn single_payout_by(
+        env: Env,
+        signer: Address,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        valid_until: u64,
+    ) -> ProgramData {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let signer = Self::require_payout_auth(
+            &env,
+            &program_id,
+            &program_data.authorized_payout_key,
+            Some(signer),
+            recipient.into_val(&env),
+            amount.into_val(&env),
+        );
+        Self::execute_single_payout(env, program_id, recipient, amount, valid_until, Some(signer))
+    }
+
+    /// `single_payout` returning a compact `PayoutReceipt` instead of the
+    /// whole `ProgramData`. Authorization and checks are identical.
+    pub fn single_payout_v2(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        valid_until: u64,
+    ) -> PayoutReceipt {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let signer = Self::require_payout_auth(
+            &env,
+            &program_id,
+            &program_data.authorized_payout_key,
+            None,
+            recipient.into_val(&env),
+            amount.into_val(&env),
+        );
+        let program_data = Self::execute_single_payout(
+            env.clone(),
+            program_id.clone(),
+            recipient,
+            amount,
+            valid_until,
+            Some(signer.clone()),
+        );
+        let pruned = Self::pruned_payout_count(&env, &program_id) as u64;
+        PayoutReceipt {
+            payout_index: pruned + program_data.payout_history.len() as u64 - 1,
+            signer,
+            new_nonce: grainlify_core::nonce::get_nonce(&env, &program_data.authorized_payout_key),
+            remaining_balance: program_data.remaining_balance,
+            timestamp: env.ledger().timestamp(),
+        }
+    }
+
+    /// `single_payout` authorized by an off-chain ed25519 signature from the
+    /// program's `authorized_payout_key`, so any relayer can submit it.
+    ///
+    /// `signature` must cover the XDR of
+    /// `(program_id, recipient, amount, nonce, contract_id)` and `pubkey`
+    /// must be the ed25519 key of the (account) payout key. `nonce` must
+    /// equal the payout key's current nonce.
+    pub fn single_payout_signed(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        nonce: u64,
+        signature: BytesN<64>,
+        pubkey: BytesN<32>,
+    ) -> ProgramData {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let payout_key = program_data.authorized_payout_key;
+
+        let mut expected = Bytes::from_array(&env, &ACCOUNT_ADDRESS_XDR_PREFIX);
+        expected.append(&pubkey.clone().into());
+        if payout_key.clone().to_xdr(&env) != expected {
+            panic_with_error!(&env, EscrowError::Unauthorized);
+        }
+
+        let message = (
+            program_id.clone(),
+            recipient.clone(),
+            amount,
+            nonce,
+            env.current_contract_address(),
+        )
+            .to_xdr(&env);
+        env.crypto().ed25519_verify(&pubkey, &message, &signature);
+        Self::consume_nonce(&env, &payout_key, nonce);
+
+        Self::execute_single_payout(env, program_id, recipient, amount, u64::MAX, Some(payout_key))
+    }
+
+    /// Body of `single_payout`. `pre_authorized` is the signer when the
+    /// caller has already authenticated the payout key itself.
+    fn execute_single_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        valid_until: u64,
+        pre_authorized: Option<Address>,
+    ) -> ProgramData {
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::require_threshold_config_if_needed(&env);
+        // Reject stale signed intents submitted after their deadline
+        if env.ledger().timestamp() > valid_until {
+            panic_with_error!(&env, EscrowError::Expired);
+        }
+        // Get program data
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        // Reentrancy guard: Check and set
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+
+        Self::assert_dependencies_satisfied(&env, &program_id);
+        Self::require_min_funding(&env, &program_data);
+
+        let signer = match pre_authorized {
+            Some(signer) => signer,
+            None => Self::require_payout_auth(
+                &env,
+                &program_id,
+                &program_data.authorized_payout_key,
+                None,
+                recipient.into_val(&env),
+                amount.into_val(&env),
+            ),
+        };
+        Self::enforce_signer_interval(&env, &signer);
+        anti_abuse::check_rate_limit(&env, signer);
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            amount,
+        );
+
+        // Check circuit breaker with thresholds
+        if let Err(_) = error_recovery::check_and_allow_with_thresholds(&env) {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::CircuitBreakerOpen);
+        }
+        Self::enforce_token_thresholds(&env, &program_data);
+        if let Err(breach) = threshold_monitor::check_window_single_outflow(&env, amount) {
+            threshold_monitor::emit_program_threshold_breach_event(&env, &program_id, &breach);
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::SinglePayoutExceeded);
+        }
+        Self::enforce_safe_mode(&env, &recipient);
+        Self::enforce_recipient_cooldown(&env, &recipient);
+        Self::enforce_no_self_payout(&env, &program_data, &recipient);
+        // Validate amount
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        // Validate balance
+        if amount > program_data.remaining_balance {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+        let mut unearmarked = Self::unearmarked_balance(&env, &program_data);
+        Self::draw_earmark(&env, &program_id, &recipient, amount, &mut unearmarked);
+
+        // Enforce the calendar-day outflow cap
+        if threshold_monitor::check_daily_limit(&env, amount).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic_with_error!(&env, EscrowError::DailyLimitExceeded);
+        }
+
+        // Calculate and collect fee if enabled
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee_amount = Self::payout_fee(&env, &fee_config, amount);
+        let net_amount = amount - fee_amount;
+
+        // Transfer net amount to recipient
+        // Transfer tokens
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&contract_address, &recipient, &net_amount);
+
+        // Transfer fee to fee recipient if applicable
+        if fee_amount > 0 {
+            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+        }
+        if fee_amount > 0 && Self::emits_at(&env, EVENT_LEVEL_NORMAL) {
+            env.events().publish(
+                (symbol_short!("fee"),),
+                (
+                    symbol_short!("payout"),
+                    fee_amount,
+                    fee_config.payout_fee_rate,
+                    fee_config.fee_recipient.clone(),
+                ),
+            );
+        }
+
+        // Record payout (with net amount after fee)
+        let timestamp = env.ledger().timestamp();
+        let payout_record = PayoutRecord {
+            recipient: recipient.clone(),
+            amount: net_amount,
+            timestamp,
+        };
+
+        payout_digest::record(&env, &program_id, &payout_record);
+        Self::record_recipient_payout(&env, &recipient, net_amount);
+        let mut updated_history = program_data.payout_history.clone();
+        updated_history.push_back(payout_record);
+
+        // Record outflow for threshold monitoring
+        threshold_monitor::record_outflow(&env, amount);
+        threshold_monitor::record_token_outflow(&env, &program_data.token_address, amount);
+        threshold_monitor::record_operation_success(&env);
+
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= amount; // Total amount (includes fee)
+        updated_data.payout_history = updated_history;
+
+        // Store updated data
+        Self::save_program(&env, &updated_data);
+        Self::update_low_balance_alert(&env, &updated_data);
+        Self::notify_recipient(&env, &recipient, net_amount);
+
+        // Time-weighted average: settlement time from last lock to this payout
+        let last_lock = env
+            .storage()
+            .instance()
+            .get(&DataKey::TwaLastLock)
+            .unwrap_or(0u64);
+        let settlement_time = timestamp.saturating_sub(last_lock);
+        twa::record_settlement(&env, settlement_time);
+        let receipt_id = Self::increment_receipt_id(&env);
+
+        // Emit Payout event (with net amount after fee)
+        if Self::emits_at(&env, EVENT_LEVEL_NORMAL) {
+            env.events().publish(
+                (PAYOUT, program_id.clone()),
+                PayoutEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id,
+                    recipient,
+                    amount,
+                    remaining_balance: updated_data.remaining_balance,
+                    receipt_id,
+                },
+            );
+        }
+
+        reentrancy_guard::clear_entered(&env);
+        updated_data
+    }
+
+    // ========================================================================
+    // Release Schedule Functions
+    // ========================================================================
+
+    /// Creates a time-based release schedule for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to create schedule for
+    /// * `amount` - Amount to release (in token's smallest denomination)
+    /// * `release_timestamp` - Unix timestamp when funds become available
+    /// * `recipient` - Address that will receive the funds
+    ///
+    /// # Returns
+    /// * `ProgramData` - Updated program data
+    ///
+    /// # Panics
+    /// * If program is not initialized
+    /// * If caller is not authorized payout key
+    /// * If amount is invalid
+    /// * If timestamp is in the past
+    /// * If amount exceeds remaining balance
+    ///
+    /// # State Changes
+    /// - Creates ProgramReleaseSchedule record
+    /// - Updates next schedule ID
+    /// - Emits ScheduleCreated event
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// let now = env.ledger().timestamp();
+    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
+    /// escrow_client.create_program_release_schedule(
+    ///     &"Hackathon2024",
+    ///     &500_0000000, // 500 tokens
+    ///     &release_time,
+    ///     &winner_address
+    /// );
+    /// ```
+    pub fn create_program_release_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        release_timestamp: u64,
+        recipient: Address,
+    ) -> ProgramData {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::is_paused_internal(&env) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+
+        // Get program data
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        // Apply rate limiting to the authorized payout key
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+
+        // Verify authorization
+        program_data.authorized_payout_key.require_auth();
+
+        // Validate amount
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        // Validate timestamp
+        if release_timestamp <= env.ledger().timestamp() {
+            panic_with_error!(&env, EscrowError::InvalidTimestamp);
+        }
+
+        // Check sufficient remaining balance
+        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
+        if scheduled_total + amount > program_data.remaining_balance {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+
+        // Get next schedule ID
+        let schedule_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+
+        // Create release schedule
+        let schedule = ProgramReleaseSchedule {
+            schedule_id,
+            amount,
+            release_timestamp,
+            recipient: recipient.clone(),
+            released: false,
+            released_at: None,
+            released_by: None,
+        };
+    /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
+    pub fn create_program_release_schedule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+
+        program_data.authorized_payout_key.require_auth();
+
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        let mut schedules: Vec<ProgramReleaseSchedule> = env
+            .storage()
+            .instance()
+            .get(&SCHEDULES)
+            .unwrap_or_else(|| Vec::new(&env));
+        let schedule_id: u64 = env
+            .storage()
+            .instance()
+            .get(&NEXT_SCHEDULE_ID)
+            .unwrap_or(1_u64);
+
+        let schedule = ProgramReleaseSchedule {
+            schedule_id,
+            recipient,
+            amount,
+            release_timestamp,
+            released: false,
+            released_at: None,
+            released_by: None,
+        };
+        schedules.push_back(schedule.clone());
+
+        env.storage().instance().set(&SCHEDULES, &schedules);
+        env.storage()
+            .instance()
+            .set(&NEXT_SCHEDULE_ID, &(schedule_id + 1));
+
+        let receipt_id = Self::increment_receipt_id(&env);
+        env.events().publish(
+            (symbol_short!("sch_cred"),),
+            ScheduleCreatedEvent {
+                program_id: program_data.program_id.clone(),
+                schedule_id,
+                recipient: schedule.recipient.clone(),
+                amount,
+                release_timestamp,
+                receipt_id,
+            },
+        );
+        schedule
+    }
+
+        // Store schedule
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+
+        // Update next schedule ID
+        env.storage().persistent().set(
+            &DataKey::NextScheduleId(program_id.clone()),
+            &(schedule_id + 1),
+        );
+        Self::update_storage_footprint(&env, |footprint| {
+            footprint.schedule_count = footprint.schedule_count.saturating_add(1);
+        });
+
+        // Emit program schedule created event
+        env.events().publish(
+            (PROG_SCHEDULE_CREATED,),
+            ProgramScheduleCreated {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount,
+                release_timestamp,
+                recipient: recipient.clone(),
+                created_by: program_data.authorized_payout_key.clone(),
+            },
+        );
+
+        // Track successful operation
+        monitoring::track_operation(
+            &env,
+            symbol_short!("create_p"),
+            program_data.authorized_payout_key,
+            true,
+        );
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("create_p"), duration);
+
+        // Return updated program data
+        let updated_data: ProgramData = Self::load_program(&env, &program_id).unwrap();
+        updated_data
+    }
+
+    /// Automatically releases funds for program schedules that are due.
+    /// Can be called by anyone after the release timestamp has passed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to check for due schedules
+    /// * `schedule_id` - The specific schedule to release
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If schedule doesn't exist
+    /// * If schedule is already released
+    /// * If schedule is not yet due
+    ///
+    /// # State Changes
+    /// - Transfers tokens to recipient
+    /// - Updates schedule status to released
+    /// - Adds to release history
+    /// - Updates program remaining balance
+    /// - Emits ScheduleReleased event
+    ///
+    /// # Example
+    /// ```rust
+    /// // Anyone can call this after the timestamp
+    /// escrow_client.release_program_schedule_automatic(&"Hackathon2024", &1);
+    /// ```
+    pub fn release_prog_schedule_automatic(env: Env, program_id: String, schedule_id: u64) {
+        let start = env.ledger().timestamp();
+
+        // Check if contract is paused
+        if Self::check_paused(&env, symbol_short!("release")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+
+        // Get program data
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        Self::assert_dependencies_satisfied(&env, &program_id);
+        Self::require_min_funding(&env, &program_data);
+
+        // Get schedule
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+        {
+            panic_with_error!(&env, EscrowError::ScheduleNotFound);
+        }
+
+        let mut schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            .unwrap();
+
+        // Check if already released
+        if schedule.released {
+            panic_with_error!(&env, EscrowError::ScheduleAlreadyReleased);
+        }
+
+        let now = env.ledger().timestamp();
+        if now < schedule.release_timestamp {
+            panic_with_error!(&env, EscrowError::ScheduleNotDue);
+        }
+
+        // Get token client
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            schedule.amount,
+        );
+
+        // Transfer funds
+        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+
+        // Update schedule
+        schedule.released = true;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(env.current_contract_address());
+
+        // Update program data
+        program_data.remaining_balance -= schedule.amount;
+
+        // Add to release history
+        let history_entry = ProgramReleaseHistory {
+            schedule_id,
+            program_id: program_id.clone(),
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            released_at: now,
+            released_by: env.current_contract_address(),
+            release_type: ReleaseType::Automatic,
+        };
+
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(program_id.clone()))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(history_entry);
+
+        // Store updates
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+        Self::save_program(&env, &program_data);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+
+        let receipt_id = Self::increment_receipt_id(&env);
+
+        // Emit events
+        env.events().publish(
+            (PROG_SCHEDULE_RELEASED,),
+            ProgramScheduleReleased {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient.clone(),
+                released_at: now,
+                released_by: env.current_contract_address(),
+                release_type: ReleaseType::Automatic,
+            },
+        );
+
+        // Track successful operation
+        monitoring::track_operation(
+            &env,
+            symbol_short!("rel_manual"),
+            program_data.authorized_payout_key.clone(),
+            true,
+        env.events().publish(
+            (PAYOUT, program_data.program_id.clone()),
+            PayoutEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_data.program_id.clone(),
+                recipient: schedule.recipient.clone(),
+                amount: schedule.amount,
+                remaining_balance: program_data.remaining_balance,
+                receipt_id,
+            },
+        );
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
+    }
+
+    /// Manually releases funds for a program schedule (authorized payout key only).
+    /// Can be called before the release timestamp by authorized key.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule to release
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    /// * If caller is not authorized payout key
+    /// * If schedule doesn't exist
+    /// * If schedule is already released
+    ///
+    /// # State Changes
+    /// - Transfers tokens to recipient
+    /// - Updates schedule status to released
+    /// - Adds to release history
+    /// - Updates program remaining balance
+    /// - Emits ScheduleReleased event
+    ///
+    /// # Authorization
+    /// - Only authorized payout key can call this function
+    ///
+    /// # Example
+    /// ```rust
+    /// // Authorized key can release early
+    /// escrow_client.release_program_schedule_manual(&"Hackathon2024", &1);
+    /// ```
+    pub fn release_program_schedule_manual(env: Env, program_id: String, schedule_id: u64) {
+        let start = env.ledger().timestamp();
+
+        // Get program data
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        // Apply rate limiting to the authorized payout key
+        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+
+        // Verify authorization
+        program_data.authorized_payout_key.require_auth();
+
+        Self::assert_dependencies_satisfied(&env, &program_id);
+        Self::require_min_funding(&env, &program_data);
+
+        // Get schedule
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+        {
+            panic_with_error!(&env, EscrowError::ScheduleNotFound);
+        }
+
+        let mut schedule: ProgramReleaseSchedule = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            .unwrap();
+
+        // Check if already released
+        if schedule.released {
+            panic_with_error!(&env, EscrowError::ScheduleAlreadyReleased);
+        }
+
+        // Get token client
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+
+        // Enforce optional per-program spending limit for this window
+        Self::enforce_program_spending_limit_internal(
+            &env,
+            &program_id,
+            &program_data.token_address,
+            schedule.amount,
+        );
+
+        // Transfer funds
+        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+
+        // Update schedule
+        let now = env.ledger().timestamp();
+        schedule.released = true;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(program_data.authorized_payout_key.clone());
+
+        // Update program data
+        let mut updated_data = program_data.clone();
+        updated_data.remaining_balance -= schedule.amount;
+
+        // Add to release history
+        let history_entry = ProgramReleaseHistory {
+            schedule_id,
+            program_id: program_id.clone(),
+            amount: schedule.amount,
+            recipient: schedule.recipient.clone(),
+            released_at: now,
+            released_by: program_data.authorized_payout_key.clone(),
+            release_type: ReleaseType::Manual,
+        };
+
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(program_id.clone()))
+            .unwrap_or(vec![&env]);
+        history.push_back(history_entry);
+
+        // Store updates
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
+            &schedule,
+        );
+        Self::save_program(&env, &updated_data);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+
+        // Emit program schedule released event
+        env.events().publish(
+            (PROG_SCHEDULE_RELEASED,),
+            ProgramScheduleReleased {
+                program_id: program_id.clone(),
+                schedule_id,
+                amount: schedule.amount,
+                recipient: schedule.recipient.clone(),
+                released_at: now,
+                released_by: program_data.authorized_payout_key.clone(),
+                release_type: ReleaseType::Manual,
+            },
+        );
+
+        // Track successful operation
+        monitoring::track_operation(
+            &env,
+            symbol_short!("rel_man"),
+            program_data.authorized_payout_key,
+            true,
+        );
+
+        // Track performance
+        let duration = env.ledger().timestamp().saturating_sub(start);
+        monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
+    }
+
+    // ========================================================================
+    // View Functions (Read-only)
+    // ========================================================================
+
+
+
+    /// Retrieves the remaining balance for a specific program.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID to query
+    ///
+    /// # Returns
+    /// * `i128` - Remaining balance
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        program_data.remaining_balance
+    }
+
+    /// Update fee configuration (admin only - uses authorized_payout_key)
+    ///
+    /// # Arguments
+    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
+    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
+    /// * `fee_recipient` - Optional new fee recipient address
+    /// * `fee_enabled` - Optional fee enable/disable flag
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        payout_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) {
+        Self::require_not_finalized(&env);
+        let admin = anti_abuse::get_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+
+        if let Some(rate) = lock_fee_rate {
+            Self::validate_fee_rate(&env, rate);
+            fee_config.lock_fee_rate = rate;
+        }
+
+        if let Some(rate) = payout_fee_rate {
+            Self::validate_fee_rate(&env, rate);
+            fee_config.payout_fee_rate = rate;
+        }
+
+        if let Some(recipient) = fee_recipient {
+            fee_config.fee_recipient = recipient;
+        }
+
+        if let Some(enabled) = fee_enabled {
+            fee_config.fee_enabled = enabled;
+        }
+
+        env.storage().instance().set(&FEE_CONFIG, &fee_config);
+
+        // Emit fee config updated event
+        env.events().publish(
+            (symbol_short!("fee_cfg"),),
+            (
+                fee_config.lock_fee_rate,
+                fee_config.payout_fee_rate,
+                fee_config.fee_recipient,
+                fee_config.fee_enabled,
+            ),
+        );
+        Self::record_config_change(&env, symbol_short!("fee_cfg"));
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Previews the payout fee on `amount` as `(fee, net)`, using the stored
+    /// payout fee rate and rounding mode. Matches the split a subsequent
+    /// `single_payout` or `batch_payout` of `amount` would perform.
+    pub fn preview_fee(env: Env, amount: i128) -> (i128, i128) {
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        let fee_config = Self::get_fee_config_internal(&env);
+        let fee = Self::payout_fee(&env, &fee_config, amount);
+        (fee, amount - fee)
+    }
+
+    /// Gets the total number of programs registered.
+    ///
+    /// # Returns
+    /// * `u32` - Count of registered programs
+    pub fn get_program_count(env: Env) -> u32 {
+        let registry: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![&env]);
+
+        registry.len()
+    }
+
+    // ========================================================================
+    // Monitoring & Analytics Functions
+    // ========================================================================
+
+    /// Health check - returns contract health status
+    pub fn health_check(env: Env) -> monitoring::HealthStatus {
+        monitoring::health_check(&env)
+    }
+
+    /// Get analytics - returns usage analytics
+    pub fn get_analytics(env: Env) -> monitoring::Analytics {
+        monitoring::get_analytics(&env)
+    }
+
+    /// Get state snapshot - returns current state
+    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
+
+    /// Get performance stats for a function
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
+    }
+
+    // ========================================================================
+    // Program Spending Limit Helpers & Admin
+    // ========================================================================
+
+    /// Internal helper that enforces the optional per-program spending limit.
+    ///
+    /// If no limit is configured or the limit is disabled, this is a no-op.
+    /// Otherwise, it tracks the released amount in a fixed-size time window
+    /// and panics if the new total would exceed the configured maximum.
+    fn enforce_program_spending_limit_internal(
+        env: &Env,
+        program_id: &String,
+        token: &Address,
+        amount: i128,
+    ) {
+        // Zero or negative amounts are ignored by the limiter; other
+        // validation happens in the caller.
+        if amount <= 0 {
+            return;
+        }
+
+        let cfg_key = DataKey::ProgramSpendingConfig(program_id.clone(), token.clone());
+        let config: Option<ProgramSpendingConfig> = env.storage().instance().get(&cfg_key);
+        let config = match config {
+            Some(cfg)
+                if cfg.enabled && cfg.window_size > 0 && cfg.max_amount > 0 =>
+            {
+                cfg
+            }
+            _ => {
+                // No active limit configured for this program/token.
+                return;
+            }
+        };
+
+        let now = env.ledger().timestamp();
+        let state_key = DataKey::ProgramSpendingState(program_id.clone(), token.clone());
+        let mut state: ProgramSpendingState = env
+            .storage()
+            .instance()
+            .get(&state_key)
+            .unwrap_or(ProgramSpendingState {
+                window_start: now,
+                amount_released: 0,
+            });
+
+        // If we're outside the current window, start a new one.
+        if now
+            .saturating_sub(state.window_start)
+            >= config.window_size
+        {
+            state.window_start = now;
+            state.amount_released = 0;
+        }
+
+        let new_total = state
+            .amount_released
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::AmountOverflow));
+
+        if new_total > config.max_amount {
+            env.events().publish(
+                (symbol_short!("limit"), symbol_short!("prog_spend")),
+                (
+                    program_id.clone(),
+                    token.clone(),
+                    amount,
+                    new_total,
+                    config.max_amount,
+                    config.window_size,
+                ),
+            );
+            panic_with_error!(env, EscrowError::SpendingLimitExceeded);
+        }
+
+        state.amount_released = new_total;
+        env.storage().instance().set(&state_key, &state);
+    }
+
+    /// Amount the optional per-program spending limit still allows in the
+    /// current window; `i128::MAX` when no limit is active.
+    fn program_spending_remaining(env: &Env, program_id: &String, token: &Address) -> i128 {
+        let cfg_key = DataKey::ProgramSpendingConfig(program_id.clone(), token.clone());
+        let config: ProgramSpendingConfig = match env.storage().instance().get(&cfg_key) {
+            Some(cfg) if cfg.enabled && cfg.window_size > 0 && cfg.max_amount > 0 => cfg,
+            _ => return i128::MAX,
+        };
+
+        let state_key = DataKey::ProgramSpendingState(program_id.clone(), token.clone());
+        let state: Option<ProgramSpendingState> = env.storage().instance().get(&state_key);
+        match state {
+            Some(state)
+                if env.ledger().timestamp().saturating_sub(state.window_start)
+                    < config.window_size =>
+            {
+                config.max_amount.saturating_sub(state.amount_released)
+            }
+            _ => config.max_amount,
+        }
+    }
+
+    // ========================================================================
+    // Anti-Abuse Administrative Functions
+    // ========================================================================
+
+    /// Sets the administrative address for anti-abuse configuration.
+    /// Can only be called once or by the existing admin.
+    pub fn set_admin(env: Env, new_admin: Address) {
+        if let Some(current_admin) = anti_abuse::get_admin(&env) {
+            current_admin.require_auth();
+        }
+        anti_abuse::set_admin(&env, new_admin);
+    }
+
+    /// Updates the rate limit configuration.
+    /// Only the admin can call this.
+    pub fn update_rate_limit_config(
+        env: Env,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) {
+        Self::require_not_finalized(&env);
+        let admin = anti_abuse::get_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        anti_abuse::set_config(
+            &env,
+            anti_abuse::AntiAbuseConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+            },
+        );
+        Self::record_config_change(&env, symbol_short!("rate_cfg"));
+    }
+
+    /// Adds or removes an address from the whitelist.
+    /// Only the admin can call this.
+    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
+        let admin = anti_abuse::get_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        anti_abuse::set_whitelist(&env, address, whitelisted);
+    }
+
+    /// Checks if an address is whitelisted.
+    pub fn is_whitelisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_whitelisted(&env, address)
+    }
+
+    /// Gets the current rate limit configuration.
+    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
+        anti_abuse::get_config(&env)
+    }
+
+    // ========================================================================
+    // Program Spending Limit Admin & Views
+    // ========================================================================
+
+    /// Configure or update the optional per-program spending limit for the
+    /// current token.
+    ///
+    /// This function can only be called by the program owner.
+    /// Passing `enabled = false` stores the configuration but disables
+    /// enforcement until re-enabled.
+    pub fn set_program_spending_limit(
+        env: Env,
+        program_id: String,
+        window_size: u64,
+        max_amount: i128,
+        enabled: bool,
+    ) {
+        Self::require_not_finalized(&env);
+        if max_amount < 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        // Only the program owner may update limits.
+        Self::get_program_owner_internal(&env, &program_id).require_auth();
+
+        let cfg = ProgramSpendingConfig {
+            window_size,
+            max_amount,
+            enabled,
+        };
+
+        let token = program_data.token_address.clone();
+        let cfg_key = DataKey::ProgramSpendingConfig(program_id, token);
+        env.storage().instance().set(&cfg_key, &cfg);
+    }
+
+    /// Returns the current spending limit configuration for a program and its
+    /// configured token, if any.
+    pub fn get_program_spending_limit(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingConfig> {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let cfg_key =
+            DataKey::ProgramSpendingConfig(program_id, program_data.token_address.clone());
+        env.storage().instance().get(&cfg_key)
+    }
+
+    /// Returns the current spending state (window start and amount released)
+    /// for a program/token pair, if any state has been recorded yet.
+    pub fn get_program_spending_state(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingState> {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let state_key =
+            DataKey::ProgramSpendingState(program_id, program_data.token_address.clone());
+        env.storage().instance().get(&state_key)
+    }
+
+    /// Creates an on-chain snapshot of critical configuration (admin-only).
+    /// Returns the snapshot id.
+    pub fn create_config_snapshot(env: Env) -> u64 {
+        let admin = anti_abuse::get_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        let next_id: u64 = env
+            .storage()
+            .instance()
+            .get(&ConfigSnapshotKey::SnapshotCounter)
+            .unwrap_or(0)
+            + 1;
+
+        let snapshot = ConfigSnapshot {
+            id: next_id,
+            timestamp: env.ledger().timestamp(),
+            fee_config: Self::get_fee_config_internal(&env),
+            anti_abuse_config: anti_abuse::get_config(&env),
+            anti_abuse_admin: anti_abuse::get_admin(&env),
+            is_paused: Self::is_paused_internal(&env),
+        };
+
+        env.storage()
+            .instance()
+            .set(&ConfigSnapshotKey::Snapshot(next_id), &snapshot);
+
+        let mut index: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&ConfigSnapshotKey::SnapshotIndex)
+            .unwrap_or(vec![&env]);
+        index.push_back(next_id);
+
+        if index.len() > CONFIG_SNAPSHOT_LIMIT {
+            let oldest_snapshot_id = index.get(0).unwrap();
+            env.storage()
+                .instance()
+                .remove(&ConfigSnapshotKey::Snapshot(oldest_snapshot_id));
+
+            let mut trimmed = Vec::new(&env);
+            for i in 1..index.len() {
+                trimmed.push_back(index.get(i).unwrap());
+            }
+            index = trimmed;
+        }
+
+        env.storage()
+            .instance()
+            .set(&ConfigSnapshotKey::SnapshotIndex, &index);
+        env.storage()
+            .instance()
+            .set(&ConfigSnapshotKey::SnapshotCounter, &next_id);
+
+        env.events().publish(
+            (symbol_short!("cfg_snap"), symbol_short!("create")),
+            (next_id, snapshot.timestamp),
+        );
+
+        next_id
+    }
+
+    /// Lists retained configuration snapshots in chronological order.
+    pub fn list_config_snapshots(env: Env) -> Vec<ConfigSnapshot> {
+        let index: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&ConfigSnapshotKey::SnapshotIndex)
+            .unwrap_or(vec![&env]);
+
+        let mut snapshots = Vec::new(&env);
+        for snapshot_id in index.iter() {
+            if let Some(snapshot) = env
+                .storage()
+                .instance()
+                .get(&ConfigSnapshotKey::Snapshot(snapshot_id))
+            {
+                snapshots.push_back(snapshot);
+            }
+        }
+
+        snapshots
+    }
+
+    /// Restores contract configuration from a prior snapshot (admin-only).
+    pub fn restore_config_snapshot(env: Env, snapshot_id: u64) {
+        let admin = anti_abuse::get_admin(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+
+        let snapshot: ConfigSnapshot = env
+            .storage()
+            .instance()
+            .get(&ConfigSnapshotKey::Snapshot(snapshot_id))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotFound));
+
+        env.storage()
+            .instance()
+            .set(&FEE_CONFIG, &snapshot.fee_config);
+        anti_abuse::set_config(&env, snapshot.anti_abuse_config);
+
+        match snapshot.anti_abuse_admin {
+            Some(snapshot_admin) => anti_abuse::set_admin(&env, snapshot_admin),
+            None => anti_abuse::clear_admin(&env),
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::IsPaused, &snapshot.is_paused);
+
+        env.events().publish(
+            (symbol_short!("cfg_snap"), symbol_short!("restore")),
+            (snapshot_id, env.ledger().timestamp()),
+        );
+        Self::record_config_change(&env, symbol_short!("restore"));
+    }
+
+    // ========================================================================
+    // Schedule View Functions
+    // ========================================================================
+
+    /// Retrieves a specific program release schedule.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program containing the schedule
+    /// * `schedule_id` - The schedule ID to retrieve
+    ///
+    /// # Returns
+    /// * `ProgramReleaseSchedule` - The schedule details
+    ///
+    /// # Panics
+    /// * If schedule doesn't exist
+    pub fn get_program_release_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+    ) -> ProgramReleaseSchedule {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id, schedule_id))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ScheduleNotFound))
+    }
+
+    /// Get aggregate statistics for the program
+    pub fn get_program_aggregate_stats(env: Env) -> ProgramAggregateStats {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        let schedules: Vec<ProgramReleaseSchedule> = env
+            .storage()
+            .instance()
+            .get(&SCHEDULES)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut scheduled_count = 0u32;
+        let mut released_count = 0u32;
+
+        for i in 0..schedules.len() {
+            let schedule = schedules.get(i).unwrap();
+            if schedule.released {
+                released_count += 1;
+            } else {
+                scheduled_count += 1;
+            }
+        }
+
+        ProgramAggregateStats {
+            total_funds: program_data.total_funds,
+            remaining_balance: program_data.remaining_balance,
+            total_paid_out: program_data.total_funds - program_data.remaining_balance,
+            authorized_payout_key: program_data.authorized_payout_key.clone(),
+            payout_history: program_data.payout_history.clone(),
+            token_address: program_data.token_address.clone(),
+            payout_count: program_data.payout_history.len(),
+            scheduled_count,
+            released_count,
+        }
+    }
+
+    /// Returns time-weighted average metrics over the last 24 hours (sliding window).
+    ///
+    /// * **window_secs**: 86400 (24 × 1h periods).
+    /// * **avg_lock_size**: Sum of lock amounts in window / number of locks; 0 if no locks.
+    /// * **avg_settlement_time_secs**: Sum of (payout_timestamp − last_lock_timestamp) / number of payouts; 0 if none.
+    ///
+    /// Gas-efficient and bounded (24 buckets only).
+    pub fn get_time_weighted_metrics(env: Env) -> TimeWeightedMetrics {
+        twa::get_time_weighted_metrics(&env)
+    }
+
+    /// Retrieves all release schedules for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All schedules for the program
+    pub fn get_all_prog_release_schedules(
+        env: Env,
+        program_id: String,
+    ) -> Vec<ProgramReleaseSchedule> {
+        let mut schedules = Vec::new(&env);
+        let next_id: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+
+        for schedule_id in 1..next_id {
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+            {
+                let schedule: ProgramReleaseSchedule = env
+                    .storage()
+                    .persistent()
+                    .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+                    .unwrap();
+                schedules.push_back(schedule);
+            }
+        }
+
+        schedules
+    }
+
+    /// Retrieves pending (unreleased) schedules for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All pending schedules
+    pub fn get_pending_program_schedules(
+        env: Env,
+        program_id: String,
+    ) -> Vec<ProgramReleaseSchedule> {
+        let all_schedules = Self::get_all_prog_release_schedules(env.clone(), program_id.clone());
+        let mut pending = Vec::new(&env);
+
+        for schedule in all_schedules.iter() {
+            if !schedule.released {
+                pending.push_back(schedule.clone());
+            }
+        }
+
+        pending
+    }
+
+    /// Retrieves due schedules (timestamp passed but not released) for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseSchedule>` - All due but unreleased schedules
+    pub fn get_due_program_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
+        let pending = Self::get_pending_program_schedules(env.clone(), program_id.clone());
+        let mut due = Vec::new(&env);
+        let now = env.ledger().timestamp();
+
+        for schedule in pending.iter() {
+            if schedule.release_timestamp <= now {
+                due.push_back(schedule.clone());
+            }
+        }
+
+        due
+    }
+
+    /// Retrieves release history for a program.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `program_id` - The program to query
+    ///
+    /// # Returns
+    /// * `Vec<ProgramReleaseHistory>` - Complete release history
+    pub fn get_program_release_history(env: Env, program_id: String) -> Vec<ProgramReleaseHistory> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseHistory(program_id))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Compute reputation score from on-chain program state.
+    ///
+    /// Scores are in basis points (10000 = 100%).
+    /// - `completion_rate_bps`: completed_releases / total_scheduled
+    /// - `payout_fulfillment_rate_bps`: funds_distributed / funds_locked
+    /// - `overall_score_bps`: weighted average (60% completion, 40% fulfillment)
+    pub fn get_program_reputation(env: Env, program_id: String) -> ProgramReputationScore {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let schedules = Self::get_all_prog_release_schedules(env.clone(), program_id);
+
+        let now = env.ledger().timestamp();
+        let total_payouts = program_data.payout_history.len();
+        let total_scheduled = schedules.len();
+        let mut completed_releases = 0u32;
+        let mut pending_releases = 0u32;
+        let mut overdue_releases = 0u32;
+
+        for i in 0..schedules.len() {
+            let schedule = schedules.get(i).unwrap();
+            if schedule.released {
+                completed_releases += 1;
+            } else {
+                pending_releases += 1;
+                if schedule.release_timestamp <= now {
+                    overdue_releases += 1;
+                }
+            }
+        }
+
+        let total_funds_locked = program_data.total_funds;
+        let total_funds_distributed = program_data.total_funds - program_data.remaining_balance;
+
+        let completion_rate_bps: u32 = if total_scheduled > 0 {
+            ((completed_releases as u64 * BASIS_POINTS as u64) / total_scheduled as u64) as u32
+        } else {
+            10_000
+        };
+
+        let payout_fulfillment_rate_bps: u32 = if total_funds_locked > 0 {
+            ((total_funds_distributed as u64 * BASIS_POINTS as u64) / total_funds_locked as u64)
+                as u32
+        } else {
+            10_000
+        };
+
+        let overall_score_bps: u32 =
+            (completion_rate_bps as u64 * 60 + payout_fulfillment_rate_bps as u64 * 40) as u32
+                / 100;
+
+        ProgramReputationScore {
+            total_payouts,
+            total_scheduled,
+            completed_releases,
+            pending_releases,
+            overdue_releases,
+            dispute_count: 0,
+            refund_count: 0,
+            total_funds_locked,
+            total_funds_distributed,
+            completion_rate_bps,
+            payout_fulfillment_rate_bps,
+            overall_score_bps,
+        }
+    }
+
+    // ========================================================================
+    // Daily Outflow Limit
+    // ========================================================================
+
+    /// Set the calendar-day outflow cap enforced by `single_payout` and
+    /// `batch_payout` (admin only). Days are UTC, derived from
+    /// `timestamp / 86400`. A limit of `0` disables the cap.
+    pub fn set_daily_outflow_limit(env: Env, limit: i128, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if threshold_monitor::set_daily_outflow_limit(&env, limit).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        Self::record_config_change(&env, symbol_short!("daily_lim"));
+    }
+
+    /// Returns the configured calendar-day outflow cap (`0` means unlimited).
+    pub fn get_daily_outflow_limit(env: Env) -> i128 {
+        threshold_monitor::get_daily_outflow_limit(&env)
+    }
+
+    /// Returns how much can still be paid out today before the daily cap is
+    /// reached (`i128::MAX` when no cap is configured).
+    pub fn get_daily_remaining(env: Env) -> i128 {
+        threshold_monitor::get_daily_remaining(&env)
+    }
+
+    /// Replaces the threshold-monitor configuration (admin only).
+    ///
+    /// The current window's accumulated metrics are kept, so reconfiguring
+    /// cannot reset the breaker's view of recent outflow. The window is
+    /// re-evaluated against the new limits straight away and the breaker
+    /// opens if they are already exceeded.
+    pub fn set_threshold_config(env: Env, config: threshold_monitor::ThresholdConfig, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if threshold_monitor::set_threshold_config(&env, config).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if error_recovery::get_state(&env) != error_recovery::CircuitState::Open {
+            let _ = error_recovery::check_and_allow_with_thresholds(&env);
+        }
+        Self::record_config_change(&env, symbol_short!("threshold"));
+    }
+
+    /// Returns the active threshold-monitor configuration.
+    pub fn get_threshold_config(env: Env) -> threshold_monitor::ThresholdConfig {
+        threshold_monitor::get_threshold_config(&env)
+    }
+
+    /// Seconds until the threshold cooldown ends and payouts may resume;
+    /// `0` when no cooldown is active.
+    pub fn cooldown_remaining(env: Env) -> u64 {
+        threshold_monitor::get_cooldown_remaining(&env)
+    }
+
+    /// Largest single payout `program_id` could make right now to a
+    /// recipient with no earmark: the minimum of its unearmarked balance,
+    /// the per-program spending limit, the daily remaining allowance, the
+    /// global and per-token window outflow allowance, `max_single_payout`
+    /// and `window_max_single_outflow`.
+    ///
+    /// Returns 0 while the contract is paused, the circuit breaker is open,
+    /// a threshold cooldown is active or the program is below its
+    /// `min_funding_before_payout` floor.
+    pub fn max_payable_now(env: Env, program_id: String) -> i128 {
+        Self::max_payable_internal(&env, &program_id, 0)
+    }
+
+    /// `max_payable_now` for a recipient holding `earmark` of the program's
+    /// balance, which they may draw on top of the unearmarked part.
+    fn max_payable_internal(env: &Env, program_id: &String, earmark: i128) -> i128 {
+        let program_data: ProgramData = Self::load_program(env, program_id)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::ProgramNotFound));
+
+        let min_funding: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::MinFundingBeforePayout(program_id.clone()))
+            .unwrap_or(0);
+        if Self::is_paused_internal(env)
+            || Self::check_paused(env, symbol_short!("release"))
+            || error_recovery::get_state(env) == error_recovery::CircuitState::Open
+            || threshold_monitor::is_cooldown_active(env)
+            || program_data.total_funds < min_funding
+        {
+            return 0;
+        }
+
+        // `check_single_payout_threshold` rejects `amount >= max_single_payout`,
+        // `check_window_single_outflow` only `amount > window_max_single_outflow`
+        let config = threshold_monitor::get_threshold_config(env);
+        let mut single_cap = config.max_single_payout - 1;
+        if config.window_max_single_outflow > 0 {
+            single_cap = single_cap.min(config.window_max_single_outflow);
+        }
+
+        (Self::unearmarked_balance(env, &program_data) + earmark)
+            .min(Self::program_spending_remaining(
+                env,
+                program_id,
+                &program_data.token_address,
+            ))
+            .min(threshold_monitor::get_daily_remaining(env))
+            .min(threshold_monitor::get_window_outflow_remaining(env))
+            .min(threshold_monitor::get_token_outflow_remaining(
+                env,
+                &program_data.token_address,
+            ))
+            .min(single_cap)
+            .max(0)
+    }
+
+    /// Sets thresholds for payouts in `token` alone, tracked in their own
+    /// window so heavy outflow in one token does not halt the others. The
+    /// global configuration still applies on top.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_threshold_config_for(
+        env: Env,
+        token: Address,
+        config: threshold_monitor::ThresholdConfig,
+        nonce: u64,
+    ) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if threshold_monitor::set_threshold_config_for(&env, &token, config).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        Self::record_config_change(&env, symbol_short!("thresh_tk"));
+    }
+
+    /// Returns the per-token threshold configuration for `token`, if set.
+    pub fn get_threshold_config_for(
+        env: Env,
+        token: Address,
+    ) -> Option<threshold_monitor::ThresholdConfig> {
+        threshold_monitor::get_threshold_config_for(&env, &token)
+    }
+
+    /// Returns the current per-token window metrics for `token`.
+    pub fn get_window_metrics_for(env: Env, token: Address) -> threshold_monitor::WindowMetrics {
+        threshold_monitor::get_window_metrics_for(&env, &token)
+    }
+
+    /// Ends an active threshold cooldown immediately and resets its backoff
+    /// multiplier to 1, for use after a verified false-positive breach.
+    ///
+    /// Unlike closing the circuit breaker, this also forgets prior breaches,
+    /// so the next breach starts again from the base cooldown period.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn clear_cooldown(env: Env, nonce: u64) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        threshold_monitor::clear_cooldown(&env, &admin);
+    }
+
+    /// Returns `(failures, total)` operations recorded in the current
+    /// threshold-monitor window, for off-chain failure-rate monitoring.
+    pub fn get_failure_rate(env: Env) -> (u32, u32) {
+        threshold_monitor::get_failure_rate(&env)
+    }
+
+    // ========================================================================
+    // Metrics History
+    // ========================================================================
+
+    /// Sets how many closed threshold windows `get_metrics_history` keeps
+    /// (admin only, at most `MAX_METRICS_HISTORY_LEN`; default 24).
+    pub fn set_metrics_history_len(env: Env, len: u32, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if threshold_monitor::set_metrics_history_len(&env, len).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        Self::record_config_change(&env, symbol_short!("hist_len"));
+    }
+
+    /// Snapshots of the most recent closed threshold windows, oldest first.
+    pub fn get_metrics_history(env: Env) -> Vec<threshold_monitor::WindowMetrics> {
+        threshold_monitor::get_metrics_history(&env)
+    }
+
+    // ========================================================================
+    // Window Alignment
+    // ========================================================================
+
+    /// Chooses rolling or calendar-aligned outflow windows (admin only).
+    /// `Calendar` starts each window on a multiple of `time_window_secs`;
+    /// the current window keeps its start until it next rotates.
+    pub fn set_window_alignment(
+        env: Env,
+        window_alignment: threshold_monitor::WindowAlignment,
+        nonce: u64,
+    ) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        threshold_monitor::set_window_alignment(&env, window_alignment);
+        Self::record_config_change(&env, symbol_short!("win_align"));
+    }
+
+    /// Returns the outflow window alignment (`Rolling` by default).
+    pub fn get_window_alignment(env: Env) -> threshold_monitor::WindowAlignment {
+        threshold_monitor::get_window_alignment(&env)
+    }
+
+    // ========================================================================
+    // Health Check
+    // ========================================================================
+
+    /// Returns pause, circuit breaker, cooldown, balance, payout and admin
+    /// nonce state for `program_id` in a single call.
+    ///
+    /// Named `program_health_check` rather than `health_check` because the
+    /// monitoring entrypoint `health_check() -> monitoring::HealthStatus`
+    /// already exists; it takes a `program_id` since each program has its
+    /// own balance and payout history.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn program_health_check(env: Env, program_id: String) -> ProgramHealthStatus {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let nonce_admin = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Admin)
+            .map(|admin| grainlify_core::nonce::get_nonce(&env, &admin))
+            .unwrap_or(0);
+
+        ProgramHealthStatus {
+            paused: Self::is_paused_internal(&env),
+            breaker_open: error_recovery::get_state(&env) == error_recovery::CircuitState::Open,
+            cooldown_ends: threshold_monitor::get_cooldown_end(&env),
+            remaining_balance: program_data.remaining_balance,
+            payout_count: (program_data.payout_history.len()
+                + Self::pruned_payout_count(&env, &program_id)) as u64,
+            nonce_admin,
+        }
+    }
+
+    /// Returns a compact summary of `program_id` without its payout history.
+    ///
+    /// `total_disbursed` is `total_funds - remaining_balance`, matching
+    /// `ProgramAggregateStats::total_paid_out`.
+    ///
+    /// # Panics
+    /// * If program doesn't exist
+    pub fn get_program_summary(env: Env, program_id: String) -> ProgramSummary {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        Self::summarize_program(&env, program_data)
+    }
+
+    /// Returns `get_program_summary` for each id in `program_ids`, in order.
+    /// Ids that don't exist are skipped, so the result may be shorter than
+    /// the input.
+    ///
+    /// # Panics
+    /// * `BatchTooLarge` if more ids than the max batch size are requested
+    pub fn get_program_infos(env: Env, program_ids: Vec<String>) -> Vec<ProgramSummary> {
+        if program_ids.len() > Self::get_max_batch_size_internal(&env) {
+            panic_with_error!(&env, EscrowError::BatchTooLarge);
+        }
+
+        let mut summaries = Vec::new(&env);
+        for program_id in program_ids.iter() {
+            if let Some(program_data) = Self::load_program(&env, &program_id)
+            {
+                summaries.push_back(Self::summarize_program(&env, program_data));
+            }
+        }
+        summaries
+    }
+
+    /// Builds the `ProgramSummary` for an already loaded program.
+    fn summarize_program(env: &Env, program_data: ProgramData) -> ProgramSummary {
+        let payout_count = program_data.payout_history.len()
+            + Self::pruned_payout_count(env, &program_data.program_id);
+        ProgramSummary {
+            program_id: program_data.program_id,
+            total_funds: program_data.total_funds,
+            remaining_balance: program_data.remaining_balance,
+            total_disbursed: program_data.total_funds - program_data.remaining_balance,
+            payout_count,
+            paused: Self::is_paused_internal(env),
+            breaker_open: error_recovery::get_state(env) == error_recovery::CircuitState::Open,
+        }
+    }
+
+    /// Returns running counts of payouts, distinct recipients and release
+    /// schedules across all programs, to help decide when to archive or
+    /// rotate to a new deployment.
+    pub fn get_storage_footprint(env: Env) -> StorageFootprint {
+        env.storage()
+            .instance()
+            .get(&DataKey::StorageFootprint)
+            .unwrap_or_default()
+    }
+
+    // ========================================================================
+    // Program Ownership
+    // ========================================================================
+
+    /// Returns the current replay-protection nonce for `signer`.
+    pub fn get_nonce(env: Env, signer: Address) -> u64 {
+        grainlify_core::nonce::get_nonce(&env, &signer)
+    }
+
+    /// Returns each of `program_id`'s configured multisig signers paired
+    /// with its current nonce, in configuration order. Empty when no
+    /// signers are configured.
+    pub fn get_signer_set(env: Env, program_id: String) -> Vec<(Address, u64)> {
+        if !Self::has_program(&env, &program_id) {
+            panic_with_error!(&env, EscrowError::ProgramNotFound);
+        }
+        let signers = env
+            .storage()
+            .persistent()
+            .get::<_, MultisigConfig>(&DataKey::MultisigConfig(program_id))
+            .map(|config| config.signers)
+            .unwrap_or(Vec::new(&env));
+
+        let mut signer_set = Vec::new(&env);
+        for signer in signers.iter() {
+            let nonce = grainlify_core::nonce::get_nonce(&env, &signer);
+            signer_set.push_back((signer, nonce));
+        }
+        signer_set
+    }
+
+    /// Returns `(current_nonce, last_used_timestamp)` for `signer` so a
+    /// client that lost sync can resume the sequence and see how recently
+    /// it advanced. The timestamp is `0` if no nonce was ever consumed.
+    pub fn get_nonce_status(env: Env, signer: Address) -> (u64, u64) {
+        let last_used: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NonceLastUsed(signer.clone()))
+            .unwrap_or(0);
+        (grainlify_core::nonce::get_nonce(&env, &signer), last_used)
+    }
+
+    /// Returns the governance owner of a program.
+    ///
+    /// The owner alone may rotate the payout key and change program limits;
+    /// the `authorized_payout_key` only signs payouts.
+    pub fn get_program_owner(env: Env, program_id: String) -> Address {
+        Self::get_program_owner_internal(&env, &program_id)
+    }
+
+    /// Returns the owner nominated by `transfer_ownership`, if any.
+    pub fn get_pending_owner(env: Env, program_id: String) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PendingProgramOwner(program_id))
+    }
 
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
+    /// Nominates `new_owner` as the program owner (step one of two).
+    ///
+    /// Ownership only moves once `new_owner` calls `accept_ownership`, so a
+    /// mistyped address cannot lock governance. Requires the current owner's
+    /// auth and nonce.
+    pub fn transfer_ownership(env: Env, program_id: String, new_owner: Address, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let owner = Self::get_program_owner_internal(&env, &program_id);
+        owner.require_auth();
+        Self::consume_nonce(&env, &owner, nonce);
 
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            panic!("Funds Paused");
-        }
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingProgramOwner(program_id.clone()), &new_owner);
 
-        // Validate amount
-        if amount <= 0 {
-            // `caller` is not defined here, assuming it should be the authorized_payout_key or similar
-            // For now, removing the monitoring call as it would cause a compile error.
-            // monitoring::track_operation(&env, symbol_short!("lock"), caller.clone(), false);
-            panic!("Amount must be greater than zero");
-        }
+        env.events().publish(
+            (symbol_short!("own_prop"), program_id),
+            (owner, new_owner, env.ledger().timestamp()),
+        );
+    }
 
-        let program_key = DataKey::Program(program_id.clone());
-        let mut program_data: ProgramData = env
+    /// Accepts a pending ownership transfer (step two of two). Must be signed
+    /// by the nominated owner.
+    pub fn accept_ownership(env: Env, program_id: String) {
+        let pending_key = DataKey::PendingProgramOwner(program_id.clone());
+        let new_owner: Address = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not initialized"));
+            .get(&pending_key)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotFound));
+        new_owner.require_auth();
 
-        // Require the authorized payout key or creator
-        program_data.authorized_payout_key.require_auth();
-
-        // Calculate fee
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled {
-            Self::calculate_fee(amount, fee_config.lock_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+        let previous_owner = Self::get_program_owner_internal(&env, &program_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::ProgramOwner(program_id.clone()), &new_owner);
+        env.storage().instance().remove(&pending_key);
 
-        // Update balances with net amount
-        program_data.total_funds += net_amount;
-        program_data.remaining_balance += net_amount;
+        env.events().publish(
+            (symbol_short!("own_acc"), program_id),
+            (previous_owner, new_owner, env.ledger().timestamp()),
+        );
+    }
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &program_data);
+    /// Replaces the program's `authorized_payout_key` (owner only).
+    pub fn rotate_payout_key(
+        env: Env,
+        program_id: String,
+        new_payout_key: Address,
+        nonce: u64,
+    ) -> ProgramData {
+        Self::require_not_finalized(&env);
+        let owner = Self::get_program_owner_internal(&env, &program_id);
+        owner.require_auth();
+        Self::consume_nonce(&env, &owner, nonce);
 
-        let receipt_id = Self::increment_receipt_id(&env);
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
 
-        // Emit fee collected event if applicable
-        if fee_amount > 0 {
-            env.events().publish(
-                (FEE_COLLECTED,),
-                FeeCollectedEvent {
-                    version: 2, // Changed from EVENT_VERSION_V2
-                    program_id: program_data.program_id.clone(),
-                    fee_type: symbol_short!("lock"),
-                    amount: fee_amount,
-                    recipient: fee_config.fee_recipient.clone(),
-                    receipt_id,
-                },
-            );
-        }
+        let previous_key = program_data.authorized_payout_key.clone();
+        program_data.authorized_payout_key = new_payout_key.clone();
+        Self::save_program(&env, &program_data);
 
-        // Emit FundsLocked event (with net amount after fee)
         env.events().publish(
-            (FUNDS_LOCKED,),
-            FundsLockedEvent {
-                version: 2, // Changed from EVENT_VERSION_V2
-                program_id: program_data.program_id.clone(),
-                amount: net_amount, // Use net_amount here
-                remaining_balance: program_data.remaining_balance,
-                receipt_id,
-            },
+            (symbol_short!("key_rot"), program_id),
+            (previous_key, new_payout_key, env.ledger().timestamp()),
         );
 
         program_data
     }
 
     // ========================================================================
-    // Initialization & Admin
+    // Batch Size Limit
     // ========================================================================
 
-    /// Initialize the contract with an admin.
-    /// This must be called before any admin protected functions (like pause) can be used.
-    pub fn initialize_contract(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
+    /// Sets the maximum number of recipients `batch_payout` accepts (admin
+    /// only). Bounding the batch keeps a single call within resource limits.
+    pub fn set_max_batch_size(env: Env, max_batch_size: u32, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if max_batch_size == 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
         }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &max_batch_size);
+
+        env.events().publish(
+            (symbol_short!("batch_max"),),
+            (max_batch_size, env.ledger().timestamp()),
+        );
+        Self::record_config_change(&env, symbol_short!("max_batch"));
     }
 
-    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
-    pub fn set_admin(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-            current.require_auth();
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+    /// Returns the maximum number of recipients `batch_payout` accepts.
+    pub fn get_max_batch_size(env: Env) -> u32 {
+        Self::get_max_batch_size_internal(&env)
     }
 
-    /// Returns the current admin address, if set.
-    pub fn get_admin(env: Env) -> Option<Address> {
-        env.storage().instance().get(&DataKey::Admin)
+    // ========================================================================
+    // Per-Signer Payout Interval
+    // ========================================================================
+
+    /// Sets the minimum number of seconds between consecutive payouts
+    /// authorized by `signer`. `0` removes the spacing requirement.
+    ///
+    /// # Authorization
+    /// - Admin only; `admin_nonce` must equal the admin's current nonce
+    pub fn set_rate_limit(env: Env, signer: Address, secs: u64, admin_nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, admin_nonce);
+
+        let key = DataKey::SignerRateLimit(signer.clone());
+        let last_payout_ts = env
+            .storage()
+            .persistent()
+            .get::<_, SignerRateLimit>(&key)
+            .and_then(|limit| limit.last_payout_ts);
+        env.storage().persistent().set(
+            &key,
+            &SignerRateLimit {
+                min_interval_secs: secs,
+                last_payout_ts,
+            },
+        );
+        Self::index_rate_limited_signer(&env, &signer);
+
+        env.events()
+            .publish((symbol_short!("rate_lim"), signer), secs);
+        Self::record_config_change(&env, symbol_short!("sig_rate"));
     }
 
-    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+    /// Returns the payout interval configured for `signer`, if any.
+    pub fn get_rate_limit(env: Env, signer: Address) -> Option<SignerRateLimit> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerRateLimit(signer))
+    }
+
+    fn rate_limited_signers(env: &Env) -> Vec<Address> {
         env.storage()
             .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env))
+            .get(&DataKey::RateLimitedSigners)
+            .unwrap_or_else(|| Vec::new(env))
     }
 
-    /// Update pause flags (admin only)
-    pub fn set_paused(
-        env: Env,
-        lock: Option<bool>,
-        release: Option<bool>,
-        refund: Option<bool>,
-        reason: Option<String>,
-    ) {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            panic!("Not initialized");
+    fn index_rate_limited_signer(env: &Env, signer: &Address) {
+        let mut signers = Self::rate_limited_signers(env);
+        if !signers.contains(signer) {
+            signers.push_back(signer.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::RateLimitedSigners, &signers);
         }
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    // ========================================================================
+    // Per-Recipient Payout Cooldown
+    // ========================================================================
+
+    /// Sets the minimum number of seconds between two payouts to the same
+    /// recipient, whoever signs them. `0` disables the cooldown.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_recipient_cooldown(env: Env, recipient_cooldown_secs: u64, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
         admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        let mut flags = Self::get_pause_flags(&env);
-        let timestamp = env.ledger().timestamp();
+        env.storage()
+            .instance()
+            .set(&DataKey::RecipientCooldown, &recipient_cooldown_secs);
+        Self::record_config_change(&env, symbol_short!("rcpt_cd"));
+    }
 
-        if reason.is_some() {
-            flags.pause_reason = reason.clone();
-        }
+    /// Returns the per-recipient payout cooldown in seconds (`0` when unset).
+    pub fn get_recipient_cooldown(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RecipientCooldown)
+            .unwrap_or(0)
+    }
 
-        if let Some(paused) = lock {
-            flags.lock_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                PauseStateChanged {
-                    operation: symbol_short!("lock"),
-                    paused,
-                    admin: admin.clone(),
-                    reason: reason.clone(),
-                    timestamp,
-                    receipt_id,
-                },
-            );
-        }
+    /// Timestamp of the last cooldown-tracked payout to `recipient`, if any.
+    pub fn get_recipient_last_payout(env: Env, recipient: Address) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientLastPayout(recipient))
+    }
 
-        if let Some(paused) = release {
-            flags.release_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                PauseStateChanged {
-                    operation: symbol_short!("release"),
-                    paused,
-                    admin: admin.clone(),
-                    reason: reason.clone(),
-                    timestamp,
-                    receipt_id,
-                },
-            );
+    // ========================================================================
+    // Self-Payout Prevention
+    // ========================================================================
+
+    /// Toggles rejection of payouts to a program's own payout key, owner
+    /// or the escrow contract (admin only). On by default.
+    pub fn set_forbid_self_payout(env: Env, forbid_self_payout: bool, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ForbidSelfPayout, &forbid_self_payout);
+        Self::record_config_change(&env, symbol_short!("self_pay"));
+    }
+
+    /// Whether self-payouts are rejected (`true` unless disabled).
+    pub fn get_forbid_self_payout(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::ForbidSelfPayout)
+            .unwrap_or(true)
+    }
+
+    // ========================================================================
+    // Funding Freeze On Breach
+    // ========================================================================
+
+    /// When `freeze_funding_on_breach` is set (admin only), every lock path
+    /// fails with `CircuitBreakerOpen` while the breaker is open, freezing
+    /// inflow as well as outflow during an incident.
+    pub fn set_freeze_funding_on_breach(env: Env, freeze_funding_on_breach: bool, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::FreezeFundingOnBreach, &freeze_funding_on_breach);
+        Self::record_config_change(&env, symbol_short!("frz_fund"));
+    }
+
+    /// Whether locks are refused while the breaker is open (off by default).
+    pub fn get_freeze_funding_on_breach(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::FreezeFundingOnBreach)
+            .unwrap_or(false)
+    }
+
+    // ========================================================================
+    // Token Allow-List
+    // ========================================================================
+
+    /// Adds `token` to the set of assets programs may be funded with (admin
+    /// only). While the list is empty every token is accepted.
+    pub fn add_allowed_token(env: Env, token: Address, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut tokens = Self::get_allowed_tokens(env.clone());
+        if !tokens.contains(&token) {
+            tokens.push_back(token);
+            env.storage()
+                .instance()
+                .set(&DataKey::AllowedTokens, &tokens);
         }
+        Self::record_config_change(&env, symbol_short!("tok_add"));
+    }
 
-        if let Some(paused) = refund {
-            flags.refund_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                PauseStateChanged {
-                    operation: symbol_short!("refund"),
-                    paused,
-                    admin: admin.clone(),
-                    reason: reason.clone(),
-                    timestamp,
-                    receipt_id,
-                },
-            );
+    /// Removes `token` from the allow-list (admin only). Removing the last
+    /// entry re-opens funding to every token.
+    pub fn remove_allowed_token(env: Env, token: Address, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut tokens = Self::get_allowed_tokens(env.clone());
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+            env.storage()
+                .instance()
+                .set(&DataKey::AllowedTokens, &tokens);
         }
+        Self::record_config_change(&env, symbol_short!("tok_rm"));
+    }
 
-        let any_paused = flags.lock_paused || flags.release_paused || flags.refund_paused;
+    /// Tokens on the allow-list; empty means no restriction.
+    pub fn get_allowed_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowedTokens)
+            .unwrap_or(Vec::new(&env))
+    }
 
-        if any_paused {
-            if flags.paused_at == 0 {
-                flags.paused_at = timestamp;
-            }
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
+    /// Whether programs holding `token` may currently be funded.
+    pub fn is_token_allowed(env: Env, token: Address) -> bool {
+        let tokens = Self::get_allowed_tokens(env);
+        tokens.is_empty() || tokens.contains(&token)
+    }
 
-        // Update balances with net amount
-        program_data.total_funds += net_amount;
-        program_data.remaining_balance += net_amount;
+    // ========================================================================
+    // Recipient Notification
+    // ========================================================================
 
-        // Emit fee collected event if applicable
-        if fee_amount > 0 {
-        let program_data: ProgramData = env
+    /// Toggles the post-payout `on_payout(from: Address, amount: i128)`
+    /// callback on recipient contracts (admin only). Off by default; plain
+    /// accounts are never called and `amount` is the net received.
+    pub fn set_notify_recipient(env: Env, notify_recipient: bool, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
-        let token_client = token::TokenClient::new(&env, &program_data.token_address);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
+        env.storage()
+            .instance()
+            .set(&DataKey::NotifyRecipient, &notify_recipient);
+        Self::record_config_change(&env, symbol_short!("notify"));
+    }
 
-        if balance > 0 {
-            token_client.transfer(&contract_address, &target, &balance);
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (symbol_short!("em_wtd"),),
-                EmergencyWithdrawEvent {
-                    admin,
-                    target: target.clone(),
-                    amount: balance,
-                    timestamp: env.ledger().timestamp(),
-                    receipt_id,
-                },
-            );
+    /// Whether recipient contracts are notified after payouts.
+    pub fn get_notify_recipient(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::NotifyRecipient)
+            .unwrap_or(false)
+    }
+
+    /// Caps the amount a single `lock_program_funds`, `batch_lock` entry or
+    /// `fund_and_pay` call may deposit (admin only), to catch fat-finger
+    /// over-funding. Independent of each program's lifetime
+    /// `max_total_funds`; `0` disables the cap.
+    pub fn set_max_single_lock(env: Env, max_single_lock: i128, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if max_single_lock < 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
         }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxSingleLock, &max_single_lock);
+        Self::record_config_change(&env, symbol_short!("max_lock"));
+    }
 
-        // Time-weighted average metrics: record this lock for sliding-window analytics
-        twa::record_lock(&env, amount);
+    /// Per-call deposit cap; `0` means unlimited.
+    pub fn get_max_single_lock(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxSingleLock)
+            .unwrap_or(0)
+    }
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &program_data);
+    // ========================================================================
+    // Config Change Log
+    // ========================================================================
 
-        // Emit FundsLocked event (with net amount after fee)
-        env.events().publish(
-            (FUNDS_LOCKED,),
-            (
-                program_data.program_id.clone(),
-                net_amount,
-                program_data.remaining_balance,
-            ),
-        );
+    /// Number of contract-level config changes made so far (`0` before the
+    /// first setter runs).
+    pub fn get_config_version(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ConfigVersion)
+            .unwrap_or(0)
+    }
 
-    pub fn get_analytics(_env: Env) -> monitoring::Analytics {
-        monitoring::Analytics {
-            operation_count: 0,
-            unique_users: 0,
-            error_count: 0,
-            error_rate: 0,
+    /// Returns up to `limit` retained config changes, oldest first,
+    /// starting at position `start` of the log. Only the latest
+    /// `MAX_CONFIG_CHANGES` entries are kept.
+    pub fn get_config_changes(env: Env, start: u32, limit: u32) -> Vec<ConfigChange> {
+        let changes: Vec<ConfigChange> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ConfigChanges)
+            .unwrap_or(Vec::new(&env));
+        let end = start.saturating_add(limit).min(changes.len());
+        if start >= end {
+            return Vec::new(&env);
         }
+        changes.slice(start..end)
     }
 
     // ========================================================================
-    // Initialization & Admin
+    // Excess Withdrawal
     // ========================================================================
 
-    /// Executes batch payouts to multiple recipients simultaneously.
+    /// Returns over-locked funds from a program to `destination`.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `recipients` - Vector of recipient addresses
-    /// * `amounts` - Vector of amounts (must match recipients length)
+    /// Reduces both `total_funds` and `remaining_balance` by `amount`. The
+    /// withdrawal may not dip into funds already committed to unreleased
+    /// release schedules or earmarked for a recipient. `reason` is included
+    /// in the emitted event.
     ///
-    /// # Returns
-    /// Updated ProgramData after payouts
-    pub fn batch_payout(
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    ///
+    /// # Panics
+    /// * If `amount` is not positive
+    /// * If `amount` exceeds the unearmarked balance minus scheduled reservations
+    /// * If `reason` is longer than `MAX_REASON_LEN`
+    /// * If `reclaim_delay_secs` has not elapsed since the last lock
+    pub fn withdraw_excess(
         env: Env,
         program_id: String,
-        recipients: Vec<Address>,
-        amounts: Vec<i128>,
+        amount: i128,
+        destination: Address,
+        reason: String,
+        nonce: u64,
     ) -> ProgramData {
-        // Reentrancy guard: Check and set
+        Self::validate_reason(&env, &reason);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        if !Self::reclaim_delay_elapsed(&env, &program_id) {
+            panic_with_error!(&env, EscrowError::ReclaimTooSoon);
+        }
+
+        let reserved = get_program_total_scheduled_amount(&env, &program_id);
+        let available = Self::unearmarked_balance(&env, &program_data) - reserved;
+        if amount > available {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+
         reentrancy_guard::check_not_entered(&env);
         reentrancy_guard::set_entered(&env);
 
-        if Self::check_paused(&env, symbol_short!("release")) {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Funds Paused");
-        }
+        program_data.total_funds -= amount;
+        program_data.remaining_balance -= amount;
+        Self::save_program(&env, &program_data);
 
-        // Verify authorization
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData =
-            env.storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| {
-                    reentrancy_guard::clear_entered(&env);
-                    panic!("Program not found")
-                });
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &destination, &amount);
 
-        Self::assert_dependencies_satisfied(&env, &program_data.program_id);
+        reentrancy_guard::clear_entered(&env);
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        env.events().publish(
+            (symbol_short!("exc_wdraw"), program_id),
+            (amount, destination, program_data.remaining_balance, reason),
+        );
 
-        program_data.authorized_payout_key.require_auth();
+        program_data
+    }
 
-        // Validate inputs
-        if recipients.len() != amounts.len() {
-            panic!("Recipients and amounts vectors must have the same length");
-        }
+    // ========================================================================
+    // Payout History Digest
+    // ========================================================================
 
-        if recipients.is_empty() {
-            panic!("Cannot process empty batch");
-        }
+    /// Returns the running SHA-256 digest of the program's payout history.
+    ///
+    /// Updated on every appended `PayoutRecord`; see `payout_digest` for the
+    /// folding rule used to recompute it from `Payout` events off-chain.
+    pub fn payout_history_digest(env: Env, program_id: String) -> BytesN<32> {
+        payout_digest::get(&env, &program_id)
+    }
 
-        // Calculate total with overflow protection
-        let mut total_payout: i128 = 0;
-        for i in 0..amounts.len() {
-            let amount = amounts.get(i).unwrap();
-            if amount <= 0 {
-                panic!("All amounts must be greater than zero");
-            }
-            total_payout = total_payout
-                .checked_add(amount)
-                .unwrap_or_else(|| panic!("Payout amount overflow"));
+    // ========================================================================
+    // Event Verbosity
+    // ========================================================================
+
+    /// Sets which events are published (`EVENT_LEVEL_MINIMAL`,
+    /// `EVENT_LEVEL_NORMAL` or `EVENT_LEVEL_VERBOSE`).
+    ///
+    /// Breach, pause and configuration events are emitted at every level;
+    /// only payout-path events are gated.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_event_level(env: Env, level: u32, nonce: u64) {
+        Self::require_not_finalized(&env);
+        if level > EVENT_LEVEL_VERBOSE {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
         }
 
-        // Validate balance
-        if total_payout > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                total_payout, program_data.remaining_balance
-            );
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        env.storage().instance().set(&DataKey::EventLevel, &level);
+        env.events().publish((symbol_short!("evt_lvl"),), level);
+        Self::record_config_change(&env, symbol_short!("evt_level"));
+    }
+
+    /// Current event verbosity; `EVENT_LEVEL_NORMAL` unless configured.
+    pub fn get_event_level(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::EventLevel)
+            .unwrap_or(EVENT_LEVEL_NORMAL)
+    }
+
+    // ========================================================================
+    // Two-Phase Funding
+    // ========================================================================
+
+    /// Phase one of a two-phase lock: pulls `amount` from `depositor` into a
+    /// pending bucket without crediting the program. `amount` is capped by
+    /// `max_single_lock` like a direct lock.
+    ///
+    /// Returns the prepared-lock id to pass to `commit_lock` or `abort_lock`.
+    pub fn prepare_lock(env: Env, program_id: String, depositor: Address, amount: i128) -> u64 {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::enforce_funding_not_frozen(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
         }
+        Self::enforce_max_single_lock(&env, amount);
+        depositor.require_auth();
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            total_payout,
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        Self::require_token_allowed(&env, &program_data.token_address);
+        Self::require_program_open(&env, &program_data.program_id);
+
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextPreparedLockId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextPreparedLockId, &(id + 1));
+
+        let prepared = PreparedLock {
+            id,
+            program_id: program_id.clone(),
+            depositor: depositor.clone(),
+            amount,
+            created_at: env.ledger().timestamp(),
+            status: PreparedLockStatus::Pending,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::PreparedLock(id), &prepared);
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, amount);
+
+        env.events().publish(
+            (symbol_short!("lock_prep"), program_id),
+            (id, depositor, amount),
         );
 
-        // Calculate fees if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let mut total_fees: i128 = 0;
+        id
+    }
 
-        // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
-        let timestamp = env.ledger().timestamp();
-        let contract_address = env.current_contract_address();
+    /// Phase two: credits a pending prepared lock to its program's
+    /// `total_funds` and `remaining_balance`.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn commit_lock(env: Env, id: u64, nonce: u64) -> ProgramData {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut prepared = Self::load_pending_lock(&env, id);
+
+        let mut program_data: ProgramData = Self::load_program(&env, &prepared.program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let total_funds = program_data
+            .total_funds
+            .checked_add(prepared.amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        let remaining_balance = program_data
+            .remaining_balance
+            .checked_add(prepared.amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+
+        Self::enforce_max_total_funds(&env, &prepared.program_id, total_funds);
+        program_data.total_funds = total_funds;
+        program_data.remaining_balance = remaining_balance;
+        Self::save_program(&env, &program_data);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, -prepared.amount);
+        Self::update_low_balance_alert(&env, &program_data);
+        env.storage().instance().set(
+            &DataKey::LastLockAt(prepared.program_id.clone()),
+            &env.ledger().timestamp(),
+        );
+
+        prepared.status = PreparedLockStatus::Committed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PreparedLock(id), &prepared);
+
+        let receipt_id = Self::increment_receipt_id(&env);
+        env.events().publish(
+            (FUNDS_LOCKED, program_data.program_id.clone()),
+            FundsLockedEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_data.program_id.clone(),
+                amount: prepared.amount,
+                remaining_balance: program_data.remaining_balance,
+                receipt_id,
+                percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+            },
+        );
+
+        program_data
+    }
+
+    /// Cancels a pending prepared lock and refunds its depositor.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn abort_lock(env: Env, id: u64, nonce: u64) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut prepared = Self::load_pending_lock(&env, id);
+        prepared.status = PreparedLockStatus::Aborted;
+        env.storage()
+            .persistent()
+            .set(&DataKey::PreparedLock(id), &prepared);
+
+        let program_data: ProgramData = Self::load_program(&env, &prepared.program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
         let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &prepared.depositor,
+            &prepared.amount,
+        );
+        Self::adjust_tracked_balance(&env, &program_data.token_address, -prepared.amount);
 
-        for i in 0..recipients.len() {
-            let recipient = recipients.get(i).unwrap();
-            let amount = amounts.get(i).unwrap();
+        env.events().publish(
+            (symbol_short!("lock_abrt"), prepared.program_id),
+            (id, prepared.depositor, prepared.amount),
+        );
+    }
 
-            // Calculate fee for this payout
-            let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
-                Self::calculate_fee(amount, fee_config.payout_fee_rate)
-            } else {
-                0
-            };
-            let net_amount = amount - fee_amount;
-            total_fees += fee_amount;
+    /// Returns a prepared lock by id.
+    pub fn get_prepared_lock(env: Env, id: u64) -> Option<PreparedLock> {
+        env.storage().persistent().get(&DataKey::PreparedLock(id))
+    }
 
-            // Transfer net amount to recipient
-            token_client.transfer(&contract_address, &recipient.clone(), &net_amount);
+    fn load_pending_lock(env: &Env, id: u64) -> PreparedLock {
+        let prepared: PreparedLock = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PreparedLock(id))
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
+        if prepared.status != PreparedLockStatus::Pending {
+            panic_with_error!(env, EscrowError::AlreadyProcessed);
+        }
+        prepared
+    }
 
-            // Transfer fee to fee recipient if applicable
-            if fee_amount > 0 {
-                token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
+    // ========================================================================
+    // Pooled Funding
+    // ========================================================================
+
+    /// Funds `program_id` from several depositors in one call: each
+    /// depositor signs and transfers its amount into the escrow, and the
+    /// sum is credited to `total_funds` and `remaining_balance`. Emits one
+    /// `FundsLocked` event per depositor.
+    ///
+    /// # Panics
+    /// * `EmptyBatch` / `BatchLengthMismatch` on empty or mismatched vectors
+    /// * `InvalidAmount` if any amount is not positive
+    /// * `MaxTotalFundsExceeded` if the pooled total passes the program cap
+    pub fn batch_lock(
+        env: Env,
+        program_id: String,
+        depositors: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::enforce_funding_not_frozen(&env);
+        if depositors.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
+        if depositors.len() != amounts.len() {
+            panic_with_error!(&env, EscrowError::BatchLengthMismatch);
+        }
+
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        Self::require_token_allowed(&env, &program_data.token_address);
+        Self::require_program_open(&env, &program_data.program_id);
+
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        for (i, depositor) in depositors.iter().enumerate() {
+            let amount = amounts.get(i as u32).unwrap();
+            if amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidAmount);
             }
+            Self::enforce_max_single_lock(&env, amount);
+            depositor.require_auth();
+            token_client.transfer(&depositor, &contract_address, &amount);
 
-            // Record payout (with net amount)
-            let payout_record = PayoutRecord {
-                recipient: recipient.clone(),
-                amount: net_amount,
-                timestamp,
-            };
-            updated_history.push_back(payout_record);
-            
-            // Record outflow for threshold monitoring
-            threshold_monitor::record_outflow(&env, amount);
-        }
+            program_data.total_funds = program_data
+                .total_funds
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+            program_data.remaining_balance = program_data
+                .remaining_balance
+                .checked_add(amount)
+                .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+            Self::enforce_max_total_funds(&env, &program_id, program_data.total_funds);
 
-        // Emit fee collected event if applicable
-        if total_fees > 0 {
+            let receipt_id = Self::increment_receipt_id(&env);
             env.events().publish(
-                (symbol_short!("fee"),),
-                (
-                    symbol_short!("payout"),
-                    total_fees,
-                    fee_config.payout_fee_rate,
-                    fee_config.fee_recipient.clone(),
-                ),
+                (FUNDS_LOCKED, program_id.clone()),
+                FundsLockedEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: program_id.clone(),
+                    amount,
+                    remaining_balance: program_data.remaining_balance,
+                    receipt_id,
+                    percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+                },
             );
         }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout; // Total includes fees
-        updated_data.payout_history = updated_history;
+        Self::save_program(&env, &program_data);
+        Self::update_low_balance_alert(&env, &program_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::LastLockAt(program_id), &env.ledger().timestamp());
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+        program_data
+    }
 
-        // Time-weighted average: settlement time from last lock to this batch
-        let last_lock = env
-            .storage()
-            .instance()
-            .get(&DataKey::TwaLastLock)
-            .unwrap_or(0u64);
-        let settlement_time = timestamp.saturating_sub(last_lock);
-        twa::record_settlement_n(&env, settlement_time, recipients.len() as u64);
-        let receipt_id = Self::increment_receipt_id(&env);
+    // ========================================================================
+    // Payout Key Delegation
+    // ========================================================================
 
-        // Emit event
-        env.events().publish(
-            (BATCH_PAYOUT,),
-            BatchPayoutEvent {
-                version: EVENT_VERSION_V2,
-                program_id: updated_data.program_id.clone(),
-                recipient_count: recipients.len() as u32,
-                total_amount: total_payout,
-                remaining_balance: updated_data.remaining_balance,
-                receipt_id,
+    /// Lets a hot `delegate` key authorize `single_payout` and `batch_payout`
+    /// for this program until `expiry`. While the delegate is active it is
+    /// the only accepted payout signer; the authorized key regains direct
+    /// control once the delegate expires or is revoked.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn set_delegate(env: Env, program_id: String, delegate: Address, expiry: u64, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
+
+        if expiry <= env.ledger().timestamp() {
+            panic_with_error!(&env, EscrowError::InvalidTimestamp);
+        }
+
+        env.storage().instance().set(
+            &DataKey::PayoutDelegate(program_id.clone()),
+            &PayoutDelegate {
+                delegate: delegate.clone(),
+                expiry,
             },
         );
 
-        updated_data
+        env.events()
+            .publish((symbol_short!("dlg_set"), program_id), (delegate, expiry));
     }
 
-    /// Executes a single payout to one recipient.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `recipient` - Address of the prize recipient
-    /// * `amount` - Amount to transfer (in token's smallest denomination)
-    ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data after payout
-    ///
-    /// # Panics
-    /// * If caller is not the authorized payout key
-    /// * If program is not initialized
-    /// * If amount is zero or negative
-    /// * If amount exceeds remaining balance
+    /// Removes the program's payout delegate with immediate effect.
     ///
     /// # Authorization
-    /// - Only authorized payout key can call this function
-    ///
-    /// # State Changes
-    /// - Transfers tokens from contract to recipient
-    /// - Adds PayoutRecord to history
-    /// - Decreases `remaining_balance` by amount
-    /// - Emits Payout event
-    ///
-    /// # Security Considerations
-    /// - Verify recipient address before calling
-    /// - Amount must be positive
-    /// - Balance check prevents overdraft
-    /// - Transfer is logged in payout history
-    ///
-    /// # Events
-    /// Emits: `Payout(program_id, recipient, amount, new_balance)`
-    ///
-    /// # Example
-    /// ```rust
-    /// use soroban_sdk::Address;
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn revoke_delegate(env: Env, program_id: String, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
+
+        env.storage()
+            .instance()
+            .remove(&DataKey::PayoutDelegate(program_id.clone()));
+
+        env.events()
+            .publish((symbol_short!("dlg_rev"), program_id), ());
+    }
+
+    /// Returns the program's payout delegate, including expired ones.
+    pub fn get_delegate(env: Env, program_id: String) -> Option<PayoutDelegate> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PayoutDelegate(program_id))
+    }
+
+    // ========================================================================
+    // Lifetime Funding Cap
+    // ========================================================================
+
+    /// Returns the program's lifetime funding cap, or `0` when unlimited.
+    pub fn get_max_total_funds(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxTotalFunds(program_id))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Payout Clawback
+    // ========================================================================
+
+    /// Sets how long after a payout it may be clawed back. `0` disables
+    /// clawback entirely.
     ///
-    /// let winner = Address::from_string("GWINNER...");
-    /// let prize = 1_000_0000000; // $1,000 USDC
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_clawback_window(env: Env, window_secs: u64, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ClawbackWindow, &window_secs);
+        Self::record_config_change(&env, symbol_short!("clawback"));
+    }
+
+    /// Returns the clawback window in seconds (`0` when disabled).
+    pub fn get_clawback_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClawbackWindow)
+            .unwrap_or(0)
+    }
+
+    /// Reclaims the recorded payout at `payout_index` from its recipient and
+    /// returns the amount to the program's `remaining_balance`.
     ///
-    /// // Execute single payout
-    /// let result = escrow_client.single_payout(&winner, &prize);
-    /// println!("Paid {} to winner", prize);
-    /// ```
+    /// Only works for Stellar assets with clawback enabled, and the escrow
+    /// contract must be the asset admin so it can authorize the clawback
+    /// and mint the burned amount back into its own balance.
     ///
-    /// # Gas Cost
-    /// Medium - Single token transfer + storage update
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
     ///
-    /// # Use Cases
-    /// - Individual prize awards
-    /// - Bonus payments
-    /// - Late additions to prize pool distribution
-    pub fn single_payout(
+    /// # Panics
+    /// * If clawback is disabled or the window has elapsed
+    /// * If `payout_index` does not exist or was already clawed back
+    pub fn clawback_payout(
         env: Env,
         program_id: String,
-        recipient: Address,
-        amount: i128,
+        payout_index: u32,
+        nonce: u64,
     ) -> ProgramData {
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
-        }
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        // Reentrancy guard: Check and set
-        reentrancy_guard::check_not_entered(&env);
-        reentrancy_guard::set_entered(&env);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        if Self::check_paused(&env, symbol_short!("release")) {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Funds Paused");
+        let window = Self::get_clawback_window(env.clone());
+        if window == 0 {
+            panic_with_error!(&env, EscrowError::ClawbackDisabled);
         }
 
-        Self::assert_dependencies_satisfied(&env, &program_id);
-
-        program_data.authorized_payout_key.require_auth();
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            amount,
-        );
+        // `payout_index` counts pruned records too; map it onto the kept tail
+        let history_index = payout_index
+            .checked_sub(Self::pruned_payout_count(&env, &program_id))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotFound));
+        let record = program_data
+            .payout_history
+            .get(history_index)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotFound));
 
-        // Check circuit breaker with thresholds
-        if let Err(_) = error_recovery::check_and_allow_with_thresholds(&env) {
-            reentrancy_guard::clear_entered(&env);
-            panic!("Circuit breaker open or threshold breached");
-        }
-        // Validate amount
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
+        if env.ledger().timestamp() > record.timestamp.saturating_add(window) {
+            panic_with_error!(&env, EscrowError::Expired);
         }
 
-        // Validate balance
-        if amount > program_data.remaining_balance {
-            panic!(
-                "Insufficient balance: requested {}, available {}",
-                amount, program_data.remaining_balance
-            );
+        let clawed_key = DataKey::ClawedBack(program_id.clone(), payout_index);
+        if env.storage().persistent().has(&clawed_key) {
+            panic_with_error!(&env, EscrowError::AlreadyProcessed);
         }
+        env.storage().persistent().set(&clawed_key, &true);
 
-        // Calculate and collect fee if enabled
-        let fee_config = Self::get_fee_config_internal(&env);
-        let fee_amount = if fee_config.fee_enabled && fee_config.payout_fee_rate > 0 {
-            Self::calculate_fee(amount, fee_config.payout_fee_rate)
-        } else {
-            0
-        };
-        let net_amount = amount - fee_amount;
-
-        // Transfer net amount to recipient
-        // Transfer tokens
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &net_amount);
-
-        // Transfer fee to fee recipient if applicable
-        if fee_amount > 0 {
-            token_client.transfer(&contract_address, &fee_config.fee_recipient, &fee_amount);
-            env.events().publish(
-                (symbol_short!("fee"),),
-                (
-                    symbol_short!("payout"),
-                    fee_amount,
-                    fee_config.payout_fee_rate,
-                    fee_config.fee_recipient.clone(),
-                ),
-            );
-        }
+        // Clawback burns the tokens; as asset admin the escrow re-mints them
+        // to itself so the credited balance is actually held
+        let sac = token::StellarAssetClient::new(&env, &program_data.token_address);
+        sac.clawback(&record.recipient, &record.amount);
+        sac.mint(&env.current_contract_address(), &record.amount);
 
-        // Record payout (with net amount after fee)
-        let timestamp = env.ledger().timestamp();
-        let payout_record = PayoutRecord {
-            recipient: recipient.clone(),
-            amount: net_amount,
-            timestamp,
-        };
+        program_data.remaining_balance += record.amount;
+        Self::save_program(&env, &program_data);
+        Self::unrecord_recipient_payout(&env, &record.recipient, record.amount);
 
-        let mut updated_history = program_data.payout_history.clone();
-        updated_history.push_back(payout_record);
+        env.events().publish(
+            (symbol_short!("clawback"), program_id),
+            (payout_index, record.recipient, record.amount),
+        );
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= amount; // Total amount (includes fee)
-        updated_data.payout_history = updated_history;
+        program_data
+    }
 
-        // Store updated data
-        env.storage().instance().set(&program_key, &updated_data);
+    // ========================================================================
+    // Rounding Policy
+    // ========================================================================
 
-        // Time-weighted average: settlement time from last lock to this payout
-        let last_lock = env
+    /// Sets the rounding mode used for fee and share-split calculations.
+    /// Split payouts clamp rounded shares so the total never exceeds the
+    /// source amount.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_rounding_mode(env: Env, mode: RoundingMode, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&DataKey::TwaLastLock)
-            .unwrap_or(0u64);
-        let settlement_time = timestamp.saturating_sub(last_lock);
-        twa::record_settlement(&env, settlement_time);
-        let receipt_id = Self::increment_receipt_id(&env);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        // Emit Payout event (with net amount after fee)
-        // Emit event
-            env.events().publish(
-                (PAYOUT,),
-                PayoutEvent {
-                    version: EVENT_VERSION_V2,
-                    program_id,
-                    recipient,
-                    amount,
-                    remaining_balance: updated_data.remaining_balance,
-                    receipt_id,
-                },
-            );
+        env.storage().instance().set(&DataKey::RoundingMode, &mode);
+        Self::record_config_change(&env, symbol_short!("rounding"));
+    }
+
+    /// Returns the configured rounding mode (`Floor` by default).
+    pub fn get_rounding_mode(env: Env) -> RoundingMode {
+        Self::rounding_mode(&env)
+    }
+
+    // ========================================================================
+    // Recipient Stats
+    // ========================================================================
 
-        updated_data
+    /// Returns `(payout_count, total_received)` for `recipient` across all
+    /// programs, counting net amounts from `single_payout` and
+    /// `batch_payout`.
+    pub fn get_recipient_stats(env: Env, recipient: Address) -> (u32, i128) {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecipientStats(recipient))
+            .unwrap_or((0, 0))
     }
 
     // ========================================================================
-    // Release Schedule Functions
+    // Payout Authorization Mode
     // ========================================================================
 
-    /// Creates a time-based release schedule for a program.
+    /// Selects whether payout signers authorize the whole invocation or
+    /// only the exact `(recipient, amount, nonce)` they signed.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to create schedule for
-    /// * `amount` - Amount to release (in token's smallest denomination)
-    /// * `release_timestamp` - Unix timestamp when funds become available
-    /// * `recipient` - Address that will receive the funds
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_auth_mode(env: Env, mode: PayoutAuthMode, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        env.storage().instance().set(&DataKey::AuthMode, &mode);
+        Self::record_config_change(&env, symbol_short!("auth_mode"));
+    }
+
+    /// Returns the payout authorization mode (`Invocation` by default).
+    pub fn get_auth_mode(env: Env) -> PayoutAuthMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::AuthMode)
+            .unwrap_or(PayoutAuthMode::Invocation)
+    }
+
+    // ========================================================================
+    // Decimals-Aware Funding
+    // ========================================================================
+
+    /// Returns the token decimals recorded for `program_id` at init, or
+    /// `None` if the token did not report them.
+    pub fn get_token_decimals(env: Env, program_id: String) -> Option<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::TokenDecimals(program_id))
+    }
+
+    /// Transfers `whole.fractional` human units of the program's token from
+    /// `depositor` and locks them, composing the raw amount from the
+    /// decimals recorded at init.
     ///
-    /// # Returns
-    /// * `ProgramData` - Updated program data
+    /// E.g. with 7 decimals, `(3, 5_000_000)` locks `35_000_000` (3.5 units).
     ///
     /// # Panics
-    /// * If program is not initialized
-    /// * If caller is not authorized payout key
-    /// * If amount is invalid
-    /// * If timestamp is in the past
-    /// * If amount exceeds remaining balance
-    ///
-    /// # State Changes
-    /// - Creates ProgramReleaseSchedule record
-    /// - Updates next schedule ID
-    /// - Emits ScheduleCreated event
-    ///
-    /// # Authorization
-    /// - Only authorized payout key can call this function
-    ///
-    /// # Example
-    /// ```rust
-    /// let now = env.ledger().timestamp();
-    /// let release_time = now + (30 * 24 * 60 * 60); // 30 days from now
-    /// escrow_client.create_program_release_schedule(
-    ///     &"Hackathon2024",
-    ///     &500_0000000, // 500 tokens
-    ///     &release_time,
-    ///     &winner_address
-    /// );
-    /// ```
-    pub fn create_program_release_schedule(
+    /// * If the program's token decimals are unknown
+    /// * If `fractional` is negative or needs more than `decimals` digits
+    pub fn lock_program_funds_decimal(
         env: Env,
         program_id: String,
-        amount: i128,
-        release_timestamp: u64,
-        recipient: Address,
+        whole: i128,
+        fractional: i128,
+        depositor: Address,
     ) -> ProgramData {
-        let start = env.ledger().timestamp();
+        let decimals = Self::get_token_decimals(env.clone(), program_id.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::InvalidConfig));
 
-        // Check if contract is paused
-        if Self::is_paused_internal(&env) {
-            panic!("Contract is paused");
+        let unit = token_math::to_base_units(1, decimals)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        if whole < 0 || fractional < 0 || fractional >= unit {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
         }
+        let amount = token_math::to_base_units(whole, decimals)
+            .and_then(|raw| raw.checked_add(fractional))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        depositor.require_auth();
+        token::Client::new(&env, &program_data.token_address).transfer(
+            &depositor,
+            &env.current_contract_address(),
+            &amount,
+        );
+
+        Self::lock_program_funds(env, program_id, amount)
+    }
+
+    // ========================================================================
+    // Finalization
+    // ========================================================================
+
+    /// Permanently freezes governance configuration: fee, threshold, limit,
+    /// rounding, auth-mode, event-level and delegation setters, ownership
+    /// transfer and payout key rotation all panic afterwards. Payouts, locks
+    /// and pause keep working.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn finalize_program(env: Env, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        env.storage().instance().set(&DataKey::Finalized, &true);
+        env.events()
+            .publish((FINALIZED,), (admin, env.ledger().timestamp()));
+    }
 
-        // Verify authorization
-        program_data.authorized_payout_key.require_auth();
+    /// Whether `finalize_program` has been called.
+    pub fn is_finalized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::Finalized)
+    }
 
-        // Validate amount
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
-        }
+    // ========================================================================
+    // Batch Reclaim
+    // ========================================================================
 
-        // Validate timestamp
-        if release_timestamp <= env.ledger().timestamp() {
-            panic!("Release timestamp must be in the future");
-        }
+    /// Sweeps the unused `remaining_balance` of several programs, less
+    /// earmarks and unreleased schedules, to `destination` in one call and
+    /// returns the total reclaimed.
+    ///
+    /// Programs that do not exist, are not owned by `caller`, or were locked
+    /// less than `reclaim_delay_secs` ago are skipped rather than failing the
+    /// batch; each skip emits a `rcl_skip` event.
+    /// `reason` is included in every `reclaim` event.
+    ///
+    /// # Authorization
+    /// - `caller` must sign; `nonce` must equal the caller's current nonce
+    pub fn batch_reclaim(
+        env: Env,
+        caller: Address,
+        program_ids: Vec<String>,
+        destination: Address,
+        reason: String,
+        nonce: u64,
+    ) -> i128 {
+        Self::validate_reason(&env, &reason);
+        caller.require_auth();
+        Self::consume_nonce(&env, &caller, nonce);
+
+        let mut total: i128 = 0;
+        for program_id in program_ids.iter() {
+            let program_data: Option<ProgramData> = Self::load_program(&env, &program_id);
+            let mut program_data = match program_data {
+                Some(data)
+                    if Self::get_program_owner_internal(&env, &program_id) == caller
+                        && Self::reclaim_delay_elapsed(&env, &program_id) =>
+                {
+                    data
+                }
+                _ => {
+                    env.events().publish(
+                        (RECLAIM_SKIPPED, program_id),
+                        (caller.clone(), env.ledger().timestamp()),
+                    );
+                    continue;
+                }
+            };
 
-        // Check sufficient remaining balance
-        let scheduled_total = get_program_total_scheduled_amount(&env, &program_id);
-        if scheduled_total + amount > program_data.remaining_balance {
-            panic!("Insufficient balance for scheduled amount");
+            let amount = Self::take_unearmarked(&env, &mut program_data);
+            Self::save_program(&env, &program_data);
+
+            if amount > 0 {
+                let client = token::Client::new(&env, &program_data.token_address);
+                client.transfer(&env.current_contract_address(), &destination, &amount);
+                total = total
+                    .checked_add(amount)
+                    .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+            }
+
+            env.events().publish(
+                (RECLAIMED, program_id),
+                (amount, destination.clone(), reason.clone(), env.ledger().timestamp()),
+            );
         }
 
-        // Get next schedule ID
-        let schedule_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
+        total
+    }
 
-        // Create release schedule
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
-            amount,
-            release_timestamp,
-            recipient: recipient.clone(),
-            released: false,
-            released_at: None,
-            released_by: None,
-        };
-    /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
-    pub fn create_program_release_schedule(
+    /// Sweeps `program_id`'s `remaining_balance`, less earmarks and
+    /// unreleased schedules, to `destinations`,
+    /// splitting it by `shares_bps` (which must be non-zero and sum to
+    /// 10 000). Rounding dust goes to the last destination. Returns the
+    /// total reclaimed.
+    ///
+    /// # Authorization
+    /// - Program owner; `nonce` must equal the owner's current nonce
+    ///
+    /// # Errors
+    /// - `BatchLengthMismatch` if the two vectors differ in length
+    /// - `EmptyBatch` if no destinations are given
+    /// - `InvalidConfig` if a share is zero or the shares do not sum to 10 000
+    /// - `ReclaimTooSoon` if `reclaim_delay_secs` has not passed since the last lock
+    pub fn reclaim_split(
         env: Env,
-        recipient: Address,
-        amount: i128,
-        release_timestamp: u64,
-    ) -> ProgramReleaseSchedule {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
+        program_id: String,
+        destinations: Vec<Address>,
+        shares_bps: Vec<u32>,
+        nonce: u64,
+    ) -> i128 {
+        Self::require_program_owner_auth(&env, &program_id, nonce);
+
+        if destinations.len() != shares_bps.len() {
+            panic_with_error!(&env, EscrowError::BatchLengthMismatch);
+        }
+        if destinations.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
+        let mut total_bps: i128 = 0;
+        for share in shares_bps.iter() {
+            if share == 0 {
+                panic_with_error!(&env, EscrowError::InvalidConfig);
+            }
+            total_bps += share as i128;
+        }
+        if total_bps != BASIS_POINTS {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if !Self::reclaim_delay_elapsed(&env, &program_id) {
+            panic_with_error!(&env, EscrowError::ReclaimTooSoon);
+        }
 
-        program_data.authorized_payout_key.require_auth();
+        let mut program_data = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let total = Self::take_unearmarked(&env, &mut program_data);
+        Self::save_program(&env, &program_data);
 
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
+        let client = token::Client::new(&env, &program_data.token_address);
+        let last = destinations.len() - 1;
+        let mut allocated: i128 = 0;
+        for (i, destination) in destinations.iter().enumerate() {
+            let amount = if i as u32 == last {
+                total - allocated
+            } else {
+                total
+                    .checked_mul(shares_bps.get(i as u32).unwrap() as i128)
+                    .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow))
+                    / BASIS_POINTS
+            };
+            allocated += amount;
+
+            if amount > 0 {
+                client.transfer(&env.current_contract_address(), &destination, &amount);
+            }
+            env.events().publish(
+                (RECLAIMED, program_id.clone()),
+                (amount, destination, String::from_str(&env, "split"), env.ledger().timestamp()),
+            );
         }
 
-        let mut schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
-        let schedule_id: u64 = env
+        total
+    }
+
+    /// Sets the cool-off, in seconds, that must pass after a program's last
+    /// lock before `withdraw_excess` or `batch_reclaim` may take its funds
+    /// (admin only). `0` disables the delay.
+    pub fn set_reclaim_delay(env: Env, reclaim_delay_secs: u64, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&NEXT_SCHEDULE_ID)
-            .unwrap_or(1_u64);
-
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
-            recipient,
-            amount,
-            release_timestamp,
-            released: false,
-            released_at: None,
-            released_by: None,
-        };
-        schedules.push_back(schedule.clone());
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        env.storage().instance().set(&SCHEDULES, &schedules);
         env.storage()
             .instance()
-            .set(&NEXT_SCHEDULE_ID, &(schedule_id + 1));
-
-        let receipt_id = Self::increment_receipt_id(&env);
-        env.events().publish(
-            (symbol_short!("sch_cred"),),
-            ScheduleCreatedEvent {
-                program_id: program_data.program_id.clone(),
-                schedule_id,
-                recipient: schedule.recipient.clone(),
-                amount,
-                release_timestamp,
-                receipt_id,
-            },
-        );
-        schedule
+            .set(&DataKey::ReclaimDelay, &reclaim_delay_secs);
+        Self::record_config_change(&env, symbol_short!("rcl_delay"));
     }
 
-        // Store schedule
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
-        );
+    /// Returns the reclaim cool-off in seconds (`0` when unset).
+    pub fn get_reclaim_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReclaimDelay)
+            .unwrap_or(0)
+    }
 
-        // Update next schedule ID
-        env.storage().persistent().set(
-            &DataKey::NextScheduleId(program_id.clone()),
-            &(schedule_id + 1),
-        );
+    // ========================================================================
+    // Program Expiry
+    // ========================================================================
 
-        // Emit program schedule created event
+    /// Sets the timestamp after which anyone may call `execute_expiry` on
+    /// `program_id`; `0` clears it.
+    ///
+    /// # Authorization
+    /// - Program owner; `nonce` must equal the owner's current nonce
+    pub fn set_program_expiry(env: Env, program_id: String, program_expiry: u64, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let owner = Self::require_program_owner_auth(&env, &program_id, nonce);
+        let key = DataKey::ProgramExpiry(program_id.clone());
+        if program_expiry == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage().instance().set(&key, &program_expiry);
+        }
         env.events().publish(
-            (PROG_SCHEDULE_CREATED,),
-            ProgramScheduleCreated {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount,
-                release_timestamp,
-                recipient: recipient.clone(),
-                created_by: program_data.authorized_payout_key.clone(),
-            },
-        );
-
-        // Track successful operation
-        monitoring::track_operation(
-            &env,
-            symbol_short!("create_p"),
-            program_data.authorized_payout_key,
-            true,
+            (symbol_short!("exp_set"), program_id),
+            (program_expiry, owner),
         );
+    }
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("create_p"), duration);
-
-        // Return updated program data
-        let updated_data: ProgramData = env.storage().instance().get(&program_key).unwrap();
-        updated_data
+    /// Expiry timestamp of `program_id`, if one is set.
+    pub fn get_program_expiry(env: Env, program_id: String) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ProgramExpiry(program_id))
     }
 
-    /// Automatically releases funds for program schedules that are due.
-    /// Can be called by anyone after the release timestamp has passed.
+    /// Sets where `execute_expiry` sends `program_id`'s unused funds
+    /// (defaults to the program owner).
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to check for due schedules
-    /// * `schedule_id` - The specific schedule to release
+    /// # Authorization
+    /// - Program owner; `nonce` must equal the owner's current nonce
+    pub fn set_auto_reclaim_destination(
+        env: Env,
+        program_id: String,
+        destination: Address,
+        nonce: u64,
+    ) {
+        Self::require_not_finalized(&env);
+        Self::require_program_owner_auth(&env, &program_id, nonce);
+        env.storage().instance().set(
+            &DataKey::AutoReclaimDestination(program_id.clone()),
+            &destination,
+        );
+        env.events()
+            .publish((symbol_short!("exp_dest"), program_id), destination);
+    }
+
+    /// Destination `execute_expiry` would pay `program_id`'s funds to.
+    pub fn get_auto_reclaim_destination(env: Env, program_id: String) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::AutoReclaimDestination(program_id.clone()))
+            .unwrap_or_else(|| Self::get_program_owner_internal(&env, &program_id))
+    }
+
+    /// Once `program_id`'s expiry has passed, transfers its
+    /// `remaining_balance`, less earmarks and unreleased schedules, to the
+    /// auto-reclaim destination and closes the program so it accepts no
+    /// further funding. Callable by anyone; returns the amount reclaimed.
     ///
     /// # Panics
-    /// * If program doesn't exist
-    /// * If schedule doesn't exist
-    /// * If schedule is already released
-    /// * If schedule is not yet due
-    ///
-    /// # State Changes
-    /// - Transfers tokens to recipient
-    /// - Updates schedule status to released
-    /// - Adds to release history
-    /// - Updates program remaining balance
-    /// - Emits ScheduleReleased event
-    ///
-    /// # Example
-    /// ```rust
-    /// // Anyone can call this after the timestamp
-    /// escrow_client.release_program_schedule_automatic(&"Hackathon2024", &1);
-    /// ```
-    pub fn release_prog_schedule_automatic(env: Env, program_id: String, schedule_id: u64) {
-        let start = env.ledger().timestamp();
+    /// * `ProgramNotExpired` if no expiry is set or it has not passed yet
+    /// * `AlreadyProcessed` if the program was already expired
+    pub fn execute_expiry(env: Env, program_id: String) -> i128 {
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let closed_key = DataKey::ProgramClosed(program_id.clone());
+        if env.storage().instance().has(&closed_key) {
+            panic_with_error!(&env, EscrowError::AlreadyProcessed);
+        }
+        match Self::get_program_expiry(env.clone(), program_id.clone()) {
+            Some(expiry) if env.ledger().timestamp() >= expiry => {}
+            _ => panic_with_error!(&env, EscrowError::ProgramNotExpired),
+        }
 
-        // Check if contract is paused
-        if Self::check_paused(&env, symbol_short!("release")) {
-            panic!("Funds Paused");
+        let amount = Self::take_unearmarked(&env, &mut program_data);
+        let destination = Self::get_auto_reclaim_destination(env.clone(), program_id.clone());
+        Self::save_program(&env, &program_data);
+        env.storage().instance().set(&closed_key, &true);
+
+        if amount > 0 {
+            let client = token::Client::new(&env, &program_data.token_address);
+            client.transfer(&env.current_contract_address(), &destination, &amount);
         }
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let mut program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        env.events().publish(
+            (PROGRAM_EXPIRED, program_id),
+            (amount, destination, env.ledger().timestamp()),
+        );
 
-        Self::assert_dependencies_satisfied(&env, &program_id);
+        amount
+    }
 
-        // Get schedule
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+    /// Authenticates `program_id`'s owner and consumes `nonce`, returning
+    /// the owner.
+    fn require_program_owner_auth(env: &Env, program_id: &String, nonce: u64) -> Address {
+        if !Self::has_program(&env, &program_id)
         {
-            panic!("Schedule not found");
+            panic_with_error!(env, EscrowError::ProgramNotFound);
         }
+        let owner = Self::get_program_owner_internal(env, program_id);
+        owner.require_auth();
+        Self::consume_nonce(env, &owner, nonce);
+        owner
+    }
 
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
+    // ========================================================================
+    // Batch Pre-flight
+    // ========================================================================
 
-        // Check if already released
-        if schedule.released {
-            panic!("Schedule already released");
+    /// Dry-runs `batch_payout` checks so an orchestrator can vet a batch
+    /// before signing it. Read-only: no auth, no nonces, no state changes.
+    ///
+    /// Returns one flag per recipient, using the same rules as
+    /// `try_batch_payout` (positive amount below `max_single_payout`, not
+    /// the escrow itself, self-payout rule, safe-mode whitelist, recipient
+    /// cooldown including repeats within the batch). The aggregate flag is
+    /// `true` only if every entry is valid and the batch as a whole fits the
+    /// size limit, the daily outflow cap and the program balance, where only
+    /// a recipient's own earmark may cover their entries, and payouts are
+    /// not paused.
+    pub fn validate_batch(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> (Vec<bool>, bool) {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let contract = env.current_contract_address();
+        let cooldown_active = Self::get_recipient_cooldown(env.clone()) > 0;
+        let mut earmarks: Map<Address, i128> = Map::new(&env);
+        let mut unearmarked = Self::unearmarked_balance(&env, &program_data);
+        let mut entries: Vec<bool> = Vec::new(&env);
+        let mut seen: Vec<Address> = Vec::new(&env);
+        let mut all_valid = true;
+        let mut total: Option<i128> = Some(0);
+        for (i, recipient) in recipients.iter().enumerate() {
+            let valid = match amounts.get(i as u32) {
+                Some(amount) => {
+                    total = total.and_then(|t| t.checked_add(amount));
+                    let earmark = earmarks.get(recipient.clone()).unwrap_or_else(|| {
+                        Self::get_earmark(env.clone(), program_id.clone(), recipient.clone())
+                    });
+                    let drawn = earmark.min(amount.max(0));
+                    earmarks.set(recipient.clone(), earmark - drawn);
+                    unearmarked -= amount - drawn;
+
+                    recipient != contract
+                        && !(cooldown_active && seen.contains(&recipient))
+                        && Self::is_payable_entry(&env, &program_data, &recipient, amount)
+                }
+                None => false,
+            };
+            seen.push_back(recipient);
+            all_valid &= valid;
+            entries.push_back(valid);
         }
 
-        let now = env.ledger().timestamp();
-        if now < schedule.release_timestamp {
-            panic!("Schedule not yet due for release");
-        }
+        let feasible = all_valid
+            && !recipients.is_empty()
+            && recipients.len() == amounts.len()
+            && recipients.len() <= Self::get_max_batch_size_internal(&env)
+            && !Self::is_paused_internal(&env)
+            && !Self::check_paused(&env, symbol_short!("release"))
+            && unearmarked >= 0
+            && total.map_or(false, |t| {
+                t <= program_data.remaining_balance
+                    && t <= threshold_monitor::get_daily_remaining(&env)
+            });
 
-        // Get token client
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+        (entries, feasible)
+    }
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            schedule.amount,
-        );
+    // ========================================================================
+    // Payment Streams
+    // ========================================================================
 
-        // Transfer funds
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+    /// Opens a stream paying `recipient` `rate_per_sec` between `start` and
+    /// `stop`, reserving the whole stream amount from the program's
+    /// `remaining_balance`. Returns the stream id.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn create_stream(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        rate_per_sec: i128,
+        start: u64,
+        stop: u64,
+        nonce: u64,
+    ) -> u64 {
+        if rate_per_sec <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        if stop <= start {
+            panic_with_error!(&env, EscrowError::InvalidTimestamp);
+        }
 
-        // Update schedule
-        schedule.released = true;
-        schedule.released_at = Some(now);
-        schedule.released_by = Some(env.current_contract_address());
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
+        Self::require_min_funding(&env, &program_data);
+
+        let reserved = rate_per_sec
+            .checked_mul((stop - start) as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        if reserved > program_data.remaining_balance {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+        let mut unearmarked = Self::unearmarked_balance(&env, &program_data);
+        Self::draw_earmark(&env, &program_id, &recipient, reserved, &mut unearmarked);
+        program_data.remaining_balance -= reserved;
+        Self::save_program(&env, &program_data);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, reserved);
 
-        // Update program data
-        program_data.remaining_balance -= schedule.amount;
+        let id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextStreamId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextStreamId, &(id + 1));
 
-        // Add to release history
-        let history_entry = ProgramReleaseHistory {
-            schedule_id,
+        let stream = PaymentStream {
+            id,
             program_id: program_id.clone(),
-            amount: schedule.amount,
-            recipient: schedule.recipient.clone(),
-            released_at: now,
-            released_by: env.current_contract_address(),
-            release_type: ReleaseType::Automatic,
+            recipient: recipient.clone(),
+            rate_per_sec,
+            start,
+            stop,
+            last_withdraw: start,
+            withdrawn: 0,
+            status: StreamStatus::Active,
         };
+        env.storage().persistent().set(&DataKey::Stream(id), &stream);
 
-        let mut history: Vec<ProgramReleaseHistory> = env
+        let index_key = DataKey::RecipientStreams(recipient.clone());
+        let mut active: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
+            .get(&index_key)
             .unwrap_or(Vec::new(&env));
-        history.push_back(history_entry);
-
-        // Store updates
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
-        );
-        env.storage().instance().set(&program_key, &program_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
-
-        let receipt_id = Self::increment_receipt_id(&env);
-
-        // Emit events
-        env.events().publish(
-            (PROG_SCHEDULE_RELEASED,),
-            ProgramScheduleReleased {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount: schedule.amount,
-                recipient: schedule.recipient.clone(),
-                released_at: now,
-                released_by: env.current_contract_address(),
-                release_type: ReleaseType::Automatic,
-            },
-        );
+        active.push_back(id);
+        env.storage().persistent().set(&index_key, &active);
 
-        // Track successful operation
-        monitoring::track_operation(
-            &env,
-            symbol_short!("rel_manual"),
-            program_data.authorized_payout_key.clone(),
-            true,
         env.events().publish(
-            (PAYOUT,),
-            PayoutEvent {
-                version: EVENT_VERSION_V2,
-                program_id: program_data.program_id.clone(),
-                recipient: schedule.recipient.clone(),
-                amount: schedule.amount,
-                remaining_balance: program_data.remaining_balance,
-                receipt_id,
-            },
+            (symbol_short!("strm_new"), program_id),
+            (id, recipient, rate_per_sec, start, stop),
         );
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
+        id
     }
 
-    /// Manually releases funds for a program schedule (authorized payout key only).
-    /// Can be called before the release timestamp by authorized key.
+    /// Transfers everything vested since the last withdrawal to the stream's
+    /// recipient and returns the amount paid. Vesting stops at `stop`.
     ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program containing the schedule
-    /// * `schedule_id` - The schedule to release
+    /// # Authorization
+    /// - The stream recipient
     ///
     /// # Panics
-    /// * If program doesn't exist
-    /// * If caller is not authorized payout key
-    /// * If schedule doesn't exist
-    /// * If schedule is already released
-    ///
-    /// # State Changes
-    /// - Transfers tokens to recipient
-    /// - Updates schedule status to released
-    /// - Adds to release history
-    /// - Updates program remaining balance
-    /// - Emits ScheduleReleased event
+    /// * `FundsPaused` while releases are paused
+    /// * `CircuitBreakerOpen` / `DailyLimitExceeded` as for `single_payout`
+    pub fn withdraw_stream(env: Env, stream_id: u64) -> i128 {
+        let mut stream = Self::load_active_stream(&env, stream_id);
+        stream.recipient.require_auth();
+
+        let amount = Self::pay_vested(&env, &mut stream);
+        if stream.last_withdraw == stream.stop {
+            stream.status = StreamStatus::Completed;
+            Self::unindex_stream(&env, &stream);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(stream_id), &stream);
+
+        amount
+    }
+
+    /// Stops a stream: pays the recipient what has vested so far and
+    /// returns the unvested remainder to the program's `remaining_balance`.
+    /// Returns the refunded amount.
     ///
     /// # Authorization
-    /// - Only authorized payout key can call this function
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
     ///
-    /// # Example
-    /// ```rust
-    /// // Authorized key can release early
-    /// escrow_client.release_program_schedule_manual(&"Hackathon2024", &1);
-    /// ```
-    pub fn release_program_schedule_manual(env: Env, program_id: String, schedule_id: u64) {
-        let start = env.ledger().timestamp();
+    /// # Panics
+    /// * `FundsPaused` while releases are paused, since cancelling pays out
+    ///   the vested part
+    pub fn cancel_stream(env: Env, stream_id: u64, nonce: u64) -> i128 {
+        let mut stream = Self::load_active_stream(&env, stream_id);
 
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        let mut program_data: ProgramData = Self::load_program(&env, &stream.program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
 
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
+        Self::pay_vested(&env, &mut stream);
+        let refund = stream.rate_per_sec * (stream.stop - stream.last_withdraw) as i128;
 
-        // Verify authorization
-        program_data.authorized_payout_key.require_auth();
+        program_data.remaining_balance += refund;
+        Self::save_program(&env, &program_data);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, -refund);
 
-        Self::assert_dependencies_satisfied(&env, &program_id);
+        stream.status = StreamStatus::Cancelled;
+        Self::unindex_stream(&env, &stream);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stream(stream_id), &stream);
 
-        // Get schedule
-        if !env
+        env.events().publish(
+            (symbol_short!("strm_cncl"), stream.program_id),
+            (stream_id, refund, env.ledger().timestamp()),
+        );
+
+        refund
+    }
+
+    /// Returns a payment stream by id.
+    pub fn get_stream(env: Env, stream_id: u64) -> Option<PaymentStream> {
+        env.storage().persistent().get(&DataKey::Stream(stream_id))
+    }
+
+    /// Token-style view of what `recipient` can withdraw right now: the
+    /// vested, not yet withdrawn part of every active stream paying them.
+    /// Reads only the recipient's own stream index, never the global list.
+    pub fn balance_of(env: Env, recipient: Address) -> i128 {
+        let active: Vec<u64> = env
             .storage()
             .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            panic!("Schedule not found");
+            .get(&DataKey::RecipientStreams(recipient))
+            .unwrap_or(Vec::new(&env));
+        let now = env.ledger().timestamp();
+        let mut claimable: i128 = 0;
+        for id in active.iter() {
+            if let Some(stream) = env
+                .storage()
+                .persistent()
+                .get::<_, PaymentStream>(&DataKey::Stream(id))
+            {
+                let vested_until = now.min(stream.stop);
+                if vested_until > stream.last_withdraw {
+                    claimable = claimable
+                        .checked_add(stream.rate_per_sec * (vested_until - stream.last_withdraw) as i128)
+                        .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+                }
+            }
         }
+        claimable
+    }
 
-        let mut schedule: ProgramReleaseSchedule = env
+    /// Drops a finished stream from its recipient's `RecipientStreams` index.
+    fn unindex_stream(env: &Env, stream: &PaymentStream) {
+        let index_key = DataKey::RecipientStreams(stream.recipient.clone());
+        let active: Vec<u64> = env
             .storage()
             .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
-
-        // Check if already released
-        if schedule.released {
-            panic!("Schedule already released");
+            .get(&index_key)
+            .unwrap_or(Vec::new(env));
+        let mut kept = Vec::new(env);
+        for id in active.iter() {
+            if id != stream.id {
+                kept.push_back(id);
+            }
+        }
+        if kept.is_empty() {
+            env.storage().persistent().remove(&index_key);
+        } else {
+            env.storage().persistent().set(&index_key, &kept);
         }
+    }
 
-        // Get token client
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
+    fn load_active_stream(env: &Env, stream_id: u64) -> PaymentStream {
+        let stream: PaymentStream = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Stream(stream_id))
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
+        if stream.status != StreamStatus::Active {
+            panic_with_error!(env, EscrowError::AlreadyProcessed);
+        }
+        stream
+    }
 
-        // Enforce optional per-program spending limit for this window
-        Self::enforce_program_spending_limit_internal(
-            &env,
-            &program_id,
-            &program_data.token_address,
-            schedule.amount,
-        );
+    /// Panics unless an outflow of `amount` from `program_data` passes the
+    /// gates `single_payout` applies: release pause, circuit breaker, token
+    /// thresholds and the daily outflow cap.
+    fn enforce_outflow_gates(env: &Env, program_data: &ProgramData, amount: i128) {
+        if Self::is_paused_internal(env) || Self::check_paused(env, symbol_short!("release")) {
+            panic_with_error!(env, EscrowError::FundsPaused);
+        }
+        Self::require_threshold_config_if_needed(env);
+        if error_recovery::check_and_allow_with_thresholds(env).is_err() {
+            panic_with_error!(env, EscrowError::CircuitBreakerOpen);
+        }
+        Self::enforce_token_thresholds(env, program_data);
+        if threshold_monitor::check_daily_limit(env, amount).is_err() {
+            panic_with_error!(env, EscrowError::DailyLimitExceeded);
+        }
+    }
 
-        // Transfer funds
-        token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
+    /// Feeds a completed outflow into the threshold monitor.
+    fn record_outflow_metrics(env: &Env, token: &Address, amount: i128) {
+        threshold_monitor::record_outflow(env, amount);
+        threshold_monitor::record_token_outflow(env, token, amount);
+        threshold_monitor::record_operation_success(env);
+    }
 
-        // Update schedule
-        let now = env.ledger().timestamp();
-        schedule.released = true;
-        schedule.released_at = Some(now);
-        schedule.released_by = Some(program_data.authorized_payout_key.clone());
+    /// Pays `rate_per_sec * (min(now, stop) - last_withdraw)` to the
+    /// recipient and advances `last_withdraw`. The caller persists `stream`.
+    ///
+    /// Gated like `single_payout` via `enforce_outflow_gates`; the pause is
+    /// checked even when nothing has vested.
+    fn pay_vested(env: &Env, stream: &mut PaymentStream) -> i128 {
+        if Self::is_paused_internal(env) || Self::check_paused(env, symbol_short!("release")) {
+            panic_with_error!(env, EscrowError::FundsPaused);
+        }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= schedule.amount;
+        let vested_until = env.ledger().timestamp().min(stream.stop);
+        if vested_until <= stream.last_withdraw {
+            return 0;
+        }
 
-        // Add to release history
-        let history_entry = ProgramReleaseHistory {
-            schedule_id,
-            program_id: program_id.clone(),
-            amount: schedule.amount,
-            recipient: schedule.recipient.clone(),
-            released_at: now,
-            released_by: program_data.authorized_payout_key.clone(),
-            release_type: ReleaseType::Manual,
-        };
+        let amount = stream.rate_per_sec * (vested_until - stream.last_withdraw) as i128;
+        stream.last_withdraw = vested_until;
+        stream.withdrawn += amount;
 
-        let mut history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id.clone()))
-            .unwrap_or(vec![&env]);
-        history.push_back(history_entry);
+        let program_data: ProgramData = Self::load_program(&env, &stream.program_id)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::ProgramNotFound));
+        Self::enforce_outflow_gates(env, &program_data, amount);
 
-        // Store updates
-        env.storage().persistent().set(
-            &DataKey::ReleaseSchedule(program_id.clone(), schedule_id),
-            &schedule,
-        );
-        env.storage().instance().set(&program_key, &updated_data);
-        env.storage()
-            .persistent()
-            .set(&DataKey::ReleaseHistory(program_id.clone()), &history);
+        let client = token::Client::new(env, &program_data.token_address);
+        client.transfer(&env.current_contract_address(), &stream.recipient, &amount);
+        Self::adjust_tracked_balance(env, &program_data.token_address, -amount);
+        Self::record_recipient_payout(env, &stream.recipient, amount);
+        Self::record_outflow_metrics(env, &program_data.token_address, amount);
 
-        // Emit program schedule released event
         env.events().publish(
-            (PROG_SCHEDULE_RELEASED,),
-            ProgramScheduleReleased {
-                program_id: program_id.clone(),
-                schedule_id,
-                amount: schedule.amount,
-                recipient: schedule.recipient.clone(),
-                released_at: now,
-                released_by: program_data.authorized_payout_key.clone(),
-                release_type: ReleaseType::Manual,
-            },
-        );
-
-        // Track successful operation
-        monitoring::track_operation(
-            &env,
-            symbol_short!("rel_man"),
-            program_data.authorized_payout_key,
-            true,
+            (symbol_short!("strm_wd"), stream.program_id.clone()),
+            (stream.id, stream.recipient.clone(), amount),
         );
 
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("rel_man"), duration);
+        amount
     }
 
     // ========================================================================
-    // View Functions (Read-only)
+    // Recurring Payouts
     // ========================================================================
 
+    /// Schedules `count` installments of `amount` to `recipient`, one every
+    /// `interval_secs`, reserving `amount * count` from the program's
+    /// `remaining_balance`. Returns the schedule id.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn create_recurring(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        interval_secs: u64,
+        count: u32,
+        nonce: u64,
+    ) -> u64 {
+        if amount <= 0 || count == 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        if interval_secs == 0 {
+            panic_with_error!(&env, EscrowError::InvalidTimestamp);
+        }
 
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
+        Self::require_min_funding(&env, &program_data);
+
+        let reserved = amount
+            .checked_mul(count as i128)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        if reserved > program_data.remaining_balance {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+        let mut unearmarked = Self::unearmarked_balance(&env, &program_data);
+        Self::draw_earmark(&env, &program_id, &recipient, reserved, &mut unearmarked);
+        program_data.remaining_balance -= reserved;
+        Self::save_program(&env, &program_data);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, reserved);
 
-    /// Retrieves the remaining balance for a specific program.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID to query
-    ///
-    /// # Returns
-    /// * `i128` - Remaining balance
-    ///
-    /// # Panics
-    /// * If program doesn't exist
-    pub fn get_remaining_balance(env: Env, program_id: String) -> i128 {
-        let program_key = DataKey::Program(program_id);
-        let program_data: ProgramData = env
+        let id: u64 = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        program_data.remaining_balance
-    }
+            .get(&DataKey::NextRecurringId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextRecurringId, &(id + 1));
 
-    /// Update fee configuration (admin only - uses authorized_payout_key)
-    ///
-    /// # Arguments
-    /// * `lock_fee_rate` - Optional new lock fee rate (basis points)
-    /// * `payout_fee_rate` - Optional new payout fee rate (basis points)
-    /// * `fee_recipient` - Optional new fee recipient address
-    /// * `fee_enabled` - Optional fee enable/disable flag
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        payout_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
-    ) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+        let recurring = RecurringPayout {
+            id,
+            program_id: program_id.clone(),
+            recipient: recipient.clone(),
+            amount,
+            interval_secs,
+            count,
+            paid: 0,
+            last_paid_at: env.ledger().timestamp(),
+            status: StreamStatus::Active,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Recurring(id), &recurring);
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+        env.events().publish(
+            (symbol_short!("rec_new"), program_id),
+            (id, recipient, amount, interval_secs, count),
+        );
 
-        if let Some(rate) = lock_fee_rate {
-            if rate < 0 || rate > MAX_FEE_RATE {
-                panic!(
-                    "Invalid lock fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.lock_fee_rate = rate;
-        }
+        id
+    }
 
-        if let Some(rate) = payout_fee_rate {
-            if rate < 0 || rate > MAX_FEE_RATE {
-                panic!(
-                    "Invalid payout fee rate: must be between 0 and {}",
-                    MAX_FEE_RATE
-                );
-            }
-            fee_config.payout_fee_rate = rate;
+    /// Pays the next installment once `interval_secs` have passed since the
+    /// previous one. Callable by anyone; the schedule advances by exactly
+    /// one interval, so a late keeper can catch up with repeated calls.
+    ///
+    /// # Panics
+    /// * `InstallmentNotDue` if the next installment is not yet due
+    /// * `AlreadyProcessed` if the schedule is completed or cancelled
+    /// * `FundsPaused` / `CircuitBreakerOpen` / `DailyLimitExceeded` as for
+    ///   `single_payout`
+    pub fn execute_recurring(env: Env, recurring_id: u64) -> RecurringPayout {
+        let mut recurring = Self::load_active_recurring(&env, recurring_id);
+
+        let due_at = recurring
+            .last_paid_at
+            .saturating_add(recurring.interval_secs);
+        if env.ledger().timestamp() < due_at {
+            panic_with_error!(&env, EscrowError::InstallmentNotDue);
         }
 
-        if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
-        }
+        let program_data: ProgramData = Self::load_program(&env, &recurring.program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        Self::enforce_outflow_gates(&env, &program_data, recurring.amount);
 
-        if let Some(enabled) = fee_enabled {
-            fee_config.fee_enabled = enabled;
+        let client = token::Client::new(&env, &program_data.token_address);
+        client.transfer(
+            &env.current_contract_address(),
+            &recurring.recipient,
+            &recurring.amount,
+        );
+        Self::adjust_tracked_balance(&env, &program_data.token_address, -recurring.amount);
+        Self::record_recipient_payout(&env, &recurring.recipient, recurring.amount);
+        Self::record_outflow_metrics(&env, &program_data.token_address, recurring.amount);
+
+        recurring.paid += 1;
+        recurring.last_paid_at = due_at;
+        if recurring.paid == recurring.count {
+            recurring.status = StreamStatus::Completed;
         }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Recurring(recurring_id), &recurring);
 
-        env.storage().instance().set(&FEE_CONFIG, &fee_config);
-
-        // Emit fee config updated event
         env.events().publish(
-            (symbol_short!("fee_cfg"),),
-            (
-                fee_config.lock_fee_rate,
-                fee_config.payout_fee_rate,
-                fee_config.fee_recipient,
-                fee_config.fee_enabled,
-            ),
+            (symbol_short!("rec_pay"), recurring.program_id.clone()),
+            (recurring_id, recurring.recipient.clone(), recurring.amount, recurring.paid),
         );
-    }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+        recurring
     }
 
-    /// Gets the total number of programs registered.
+    /// Stops a recurring schedule and returns its unpaid installments to
+    /// the program's `remaining_balance`. Returns the refunded amount.
     ///
-    /// # Returns
-    /// * `u32` - Count of registered programs
-    pub fn get_program_count(env: Env) -> u32 {
-        let registry: Vec<String> = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_REGISTRY)
-            .unwrap_or(vec![&env]);
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn cancel_recurring(env: Env, recurring_id: u64, nonce: u64) -> i128 {
+        let mut recurring = Self::load_active_recurring(&env, recurring_id);
 
-        registry.len()
-    }
+        let mut program_data: ProgramData = Self::load_program(&env, &recurring.program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
 
-    // ========================================================================
-    // Monitoring & Analytics Functions
-    // ========================================================================
+        let refund = recurring.amount * (recurring.count - recurring.paid) as i128;
+        program_data.remaining_balance += refund;
+        Self::save_program(&env, &program_data);
+        Self::adjust_tracked_balance(&env, &program_data.token_address, -refund);
 
-    /// Health check - returns contract health status
-    pub fn health_check(env: Env) -> monitoring::HealthStatus {
-        monitoring::health_check(&env)
-    }
+        recurring.status = StreamStatus::Cancelled;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Recurring(recurring_id), &recurring);
 
-    /// Get analytics - returns usage analytics
-    pub fn get_analytics(env: Env) -> monitoring::Analytics {
-        monitoring::get_analytics(&env)
+        env.events().publish(
+            (symbol_short!("rec_cncl"), recurring.program_id),
+            (recurring_id, refund, env.ledger().timestamp()),
+        );
+
+        refund
     }
 
-    /// Get state snapshot - returns current state
-    pub fn get_state_snapshot(env: Env) -> monitoring::StateSnapshot {
-        monitoring::get_state_snapshot(&env)
+    /// Returns a recurring payout schedule by id.
+    pub fn get_recurring(env: Env, recurring_id: u64) -> Option<RecurringPayout> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Recurring(recurring_id))
     }
 
-    /// Get performance stats for a function
-    pub fn get_performance_stats(env: Env, function_name: Symbol) -> monitoring::PerformanceStats {
-        monitoring::get_performance_stats(&env, function_name)
+    fn load_active_recurring(env: &Env, recurring_id: u64) -> RecurringPayout {
+        let recurring: RecurringPayout = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Recurring(recurring_id))
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
+        if recurring.status != StreamStatus::Active {
+            panic_with_error!(env, EscrowError::AlreadyProcessed);
+        }
+        recurring
     }
 
     // ========================================================================
-    // Program Spending Limit Helpers & Admin
+    // Reservations
     // ========================================================================
 
-    /// Internal helper that enforces the optional per-program spending limit.
+    /// Lists up to `limit` of `program_id`'s outstanding reservations,
+    /// starting at position `start`: unreleased release schedules, then
+    /// active streams, then active recurring payouts, each by id.
     ///
-    /// If no limit is configured or the limit is disabled, this is a no-op.
-    /// Otherwise, it tracks the released amount in a fixed-size time window
-    /// and panics if the new total would exceed the configured maximum.
-    fn enforce_program_spending_limit_internal(
-        env: &Env,
-        program_id: &String,
-        token: &Address,
-        amount: i128,
-    ) {
-        // Zero or negative amounts are ignored by the limiter; other
-        // validation happens in the caller.
-        if amount <= 0 {
-            return;
-        }
+    /// Schedules are held back from `remaining_balance` when paying out;
+    /// streams and recurring payouts were already deducted from it.
+    pub fn get_reservations(env: Env, program_id: String, start: u32, limit: u32) -> Vec<Reservation> {
+        let mut reservations = Vec::new(&env);
 
-        let cfg_key = DataKey::ProgramSpendingConfig(program_id.clone(), token.clone());
-        let config: Option<ProgramSpendingConfig> = env.storage().instance().get(&cfg_key);
-        let config = match config {
-            Some(cfg)
-                if cfg.enabled && cfg.window_size > 0 && cfg.max_amount > 0 =>
+        let next_schedule: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1);
+        for id in 1..next_schedule {
+            if let Some(schedule) = env
+                .storage()
+                .persistent()
+                .get::<_, ProgramReleaseSchedule>(&DataKey::ReleaseSchedule(program_id.clone(), id))
             {
-                cfg
+                if !schedule.released {
+                    reservations.push_back(Reservation {
+                        kind: symbol_short!("schedule"),
+                        id,
+                        amount: schedule.amount,
+                        unlock_ts: schedule.release_timestamp,
+                    });
+                }
             }
-            _ => {
-                // No active limit configured for this program/token.
-                return;
+        }
+
+        let next_stream: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextStreamId)
+            .unwrap_or(1);
+        for id in 1..next_stream {
+            if let Some(stream) = env
+                .storage()
+                .persistent()
+                .get::<_, PaymentStream>(&DataKey::Stream(id))
+            {
+                if stream.status == StreamStatus::Active && stream.program_id == program_id {
+                    reservations.push_back(Reservation {
+                        kind: symbol_short!("stream"),
+                        id,
+                        amount: stream.rate_per_sec * (stream.stop - stream.last_withdraw) as i128,
+                        unlock_ts: stream.stop,
+                    });
+                }
             }
-        };
+        }
 
-        let now = env.ledger().timestamp();
-        let state_key = DataKey::ProgramSpendingState(program_id.clone(), token.clone());
-        let mut state: ProgramSpendingState = env
+        let next_recurring: u64 = env
             .storage()
             .instance()
-            .get(&state_key)
-            .unwrap_or(ProgramSpendingState {
-                window_start: now,
-                amount_released: 0,
-            });
+            .get(&DataKey::NextRecurringId)
+            .unwrap_or(1);
+        for id in 1..next_recurring {
+            if let Some(recurring) = env
+                .storage()
+                .persistent()
+                .get::<_, RecurringPayout>(&DataKey::Recurring(id))
+            {
+                if recurring.status == StreamStatus::Active && recurring.program_id == program_id {
+                    let left = recurring.count - recurring.paid;
+                    reservations.push_back(Reservation {
+                        kind: symbol_short!("recurring"),
+                        id,
+                        amount: recurring.amount * left as i128,
+                        unlock_ts: recurring
+                            .last_paid_at
+                            .saturating_add(recurring.interval_secs.saturating_mul(left as u64)),
+                    });
+                }
+            }
+        }
 
-        // If we're outside the current window, start a new one.
-        if now
-            .saturating_sub(state.window_start)
-            >= config.window_size
-        {
-            state.window_start = now;
-            state.amount_released = 0;
+        let end = start.saturating_add(limit).min(reservations.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        reservations.slice(start..end)
+    }
+
+    // ========================================================================
+    // Payout Queue
+    // ========================================================================
+
+    /// Appends a payout to `program_id`'s FIFO queue and returns the new
+    /// queue length. Funds are not reserved; balance and thresholds are
+    /// checked when the entry is processed.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn enqueue_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        nonce: u64,
+    ) -> u32 {
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
         }
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
+
+        let mut queue = Self::get_payout_queue(env.clone(), program_id.clone());
+        queue.push_back(QueuedPayout {
+            recipient: recipient.clone(),
+            amount,
+            enqueued_at: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutQueue(program_id.clone()), &queue);
 
-        let new_total = state
-            .amount_released
-            .checked_add(amount)
-            .unwrap_or_else(|| panic!("Spending amount overflow"));
+        env.events().publish(
+            (symbol_short!("q_add"), program_id),
+            (recipient, amount, queue.len()),
+        );
+        queue.len()
+    }
 
-        if new_total > config.max_amount {
-            env.events().publish(
-                (symbol_short!("limit"), symbol_short!("prog_spend")),
-                (
-                    program_id.clone(),
-                    token.clone(),
-                    amount,
-                    new_total,
-                    config.max_amount,
-                    config.window_size,
-                ),
+    /// Pays up to `max_items` queued payouts for `program_id` in FIFO order
+    /// and returns how many were paid. Callable by anyone; the payout key
+    /// authorized each entry when it was enqueued.
+    ///
+    /// Stops early, leaving the head of the queue in place, once the next
+    /// entry exceeds `max_payable_now` plus its recipient's earmark
+    /// (balance, daily limit, window outflow, `max_single_payout`, or an
+    /// open breaker). Every other
+    /// payout check still applies and reverts the call.
+    pub fn process_queue(env: Env, program_id: String, max_items: u32) -> u32 {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let mut queue = Self::get_payout_queue(env.clone(), program_id.clone());
+
+        let mut processed = 0u32;
+        while processed < max_items {
+            let entry = match queue.first() {
+                Some(entry) => entry,
+                None => break,
+            };
+            let earmark =
+                Self::get_earmark(env.clone(), program_id.clone(), entry.recipient.clone());
+            if entry.amount > Self::max_payable_internal(&env, &program_id, earmark) {
+                break;
+            }
+            queue.pop_front();
+            Self::execute_single_payout(
+                env.clone(),
+                program_id.clone(),
+                entry.recipient,
+                entry.amount,
+                u64::MAX,
+                Some(program_data.authorized_payout_key.clone()),
             );
-            panic!("Program spending limit exceeded for current window");
+            processed += 1;
         }
 
-        state.amount_released = new_total;
-        env.storage().instance().set(&state_key, &state);
+        let queue_key = DataKey::PayoutQueue(program_id.clone());
+        if queue.is_empty() {
+            env.storage().persistent().remove(&queue_key);
+        } else {
+            env.storage().persistent().set(&queue_key, &queue);
+        }
+        env.events().publish(
+            (symbol_short!("q_run"), program_id),
+            (processed, queue.len()),
+        );
+        processed
+    }
+
+    /// Pending queued payouts for `program_id`, oldest first.
+    pub fn get_payout_queue(env: Env, program_id: String) -> Vec<QueuedPayout> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutQueue(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
     }
 
     // ========================================================================
-    // Anti-Abuse Administrative Functions
+    // Cross-Escrow Forwarding
     // ========================================================================
 
-    /// Sets the administrative address for anti-abuse configuration.
-    /// Can only be called once or by the existing admin.
-    pub fn set_admin(env: Env, new_admin: Address) {
-        if let Some(current_admin) = anti_abuse::get_admin(&env) {
-            current_admin.require_auth();
+    /// Moves `amount` out of `program_id` into `target_program_id` on another
+    /// program-escrow deployment: tokens are transferred to `target_contract`
+    /// and its `lock_program_funds` is invoked so the downstream escrow
+    /// accounts for them.
+    ///
+    /// Reverts entirely if the target program does not exist or uses a
+    /// different token. The target's `lock_program_funds` requires its own
+    /// payout key's auth as usual.
+    ///
+    /// The forward counts as an outflow: pause, circuit breaker, thresholds,
+    /// daily and spending limits apply as for `single_payout`, and earmarks
+    /// and unreleased schedules cannot be forwarded.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn forward_to_escrow(
+        env: Env,
+        program_id: String,
+        target_contract: Address,
+        target_program_id: String,
+        amount: i128,
+        nonce: u64,
+    ) -> ProgramData {
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
+        }
+        if target_contract == env.current_contract_address() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
         }
-        anti_abuse::set_admin(&env, new_admin);
-    }
 
-    /// Updates the rate limit configuration.
-    /// Only the admin can call this.
-    pub fn update_rate_limit_config(
-        env: Env,
-        window_size: u64,
-        max_operations: u32,
-        cooldown_period: u64,
-    ) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        program_data.authorized_payout_key.require_auth();
+        Self::consume_nonce(&env, &program_data.authorized_payout_key, nonce);
 
-        anti_abuse::set_config(
+        // A forward leaves the program like any payout, so it passes the same gates
+        Self::enforce_outflow_gates(&env, &program_data, amount);
+        Self::require_min_funding(&env, &program_data);
+        Self::enforce_program_spending_limit_internal(
             &env,
-            anti_abuse::AntiAbuseConfig {
-                window_size,
-                max_operations,
-                cooldown_period,
-            },
+            &program_id,
+            &program_data.token_address,
+            amount,
         );
-    }
+        if let Err(breach) = threshold_monitor::check_window_single_outflow(&env, amount) {
+            threshold_monitor::emit_program_threshold_breach_event(&env, &program_id, &breach);
+            panic_with_error!(&env, EscrowError::SinglePayoutExceeded);
+        }
 
-    /// Adds or removes an address from the whitelist.
-    /// Only the admin can call this.
-    pub fn set_whitelist(env: Env, address: Address, whitelisted: bool) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+        let scheduled = get_program_total_scheduled_amount(&env, &program_id);
+        if amount > Self::unearmarked_balance(&env, &program_data) - scheduled {
+            panic_with_error!(&env, EscrowError::InsufficientBalance);
+        }
+        program_data.remaining_balance -= amount;
+        Self::save_program(&env, &program_data);
 
-        anti_abuse::set_whitelist(&env, address, whitelisted);
-    }
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &target_contract, &amount);
+        Self::record_outflow_metrics(&env, &program_data.token_address, amount);
 
-    /// Checks if an address is whitelisted.
-    pub fn is_whitelisted(env: Env, address: Address) -> bool {
-        anti_abuse::is_whitelisted(&env, address)
-    }
+        let downstream = ProgramEscrowContractClient::new(&env, &target_contract)
+            .lock_program_funds(&target_program_id, &amount);
+        if downstream.token_address != program_data.token_address {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
 
-    /// Gets the current rate limit configuration.
-    pub fn get_rate_limit_config(env: Env) -> anti_abuse::AntiAbuseConfig {
-        anti_abuse::get_config(&env)
+        env.events().publish(
+            (symbol_short!("forward"), program_id),
+            (target_contract, target_program_id, amount),
+        );
+
+        program_data
     }
 
     // ========================================================================
-    // Program Spending Limit Admin & Views
+    // Low Balance Alert
     // ========================================================================
 
-    /// Configure or update the optional per-program spending limit for the
-    /// current token.
+    /// Sets the `remaining_balance` under which a payout emits a one-shot
+    /// `LOW_BALANCE` event for `program_id`, so operators know to top up;
+    /// `0` disables the alert.
     ///
-    /// This function can only be called by the program's authorized payout key.
-    /// Passing `enabled = false` stores the configuration but disables
-    /// enforcement until re-enabled.
-    pub fn set_program_spending_limit(
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_low_balance_threshold(
         env: Env,
         program_id: String,
-        window_size: u64,
-        max_amount: i128,
-        enabled: bool,
+        low_balance_threshold: i128,
+        nonce: u64,
     ) {
-        if max_amount < 0 {
-            panic!("max_amount must be non-negative");
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        if low_balance_threshold < 0 {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if !Self::has_program(&env, &program_id)
+        {
+            panic_with_error!(&env, EscrowError::ProgramNotFound);
         }
+        env.storage().instance().set(
+            &DataKey::LowBalanceThreshold(program_id),
+            &low_balance_threshold,
+        );
+    }
 
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
+    /// Returns the low balance alert threshold for `program_id` (`0` = off).
+    pub fn get_low_balance_threshold(env: Env, program_id: String) -> i128 {
+        env.storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .get(&DataKey::LowBalanceThreshold(program_id))
+            .unwrap_or(0)
+    }
 
-        // Only the authorized payout key for this program may update limits.
-        program_data.authorized_payout_key.require_auth();
+    // ========================================================================
+    // History Retention
+    // ========================================================================
 
-        let cfg = ProgramSpendingConfig {
-            window_size,
-            max_amount,
-            enabled,
-        };
+    /// Sets how many of the newest payout records `prune_history` keeps for
+    /// `program_id`; `0` (the default) keeps everything.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn set_retention_count(env: Env, program_id: String, retention_count: u32, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        let token = program_data.token_address.clone();
-        let cfg_key = DataKey::ProgramSpendingConfig(program_id, token);
-        env.storage().instance().set(&cfg_key, &cfg);
+        if !Self::has_program(&env, &program_id)
+        {
+            panic_with_error!(&env, EscrowError::ProgramNotFound);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::HistoryRetention(program_id), &retention_count);
     }
 
-    /// Returns the current spending limit configuration for a program and its
-    /// configured token, if any.
-    pub fn get_program_spending_limit(
-        env: Env,
-        program_id: String,
-    ) -> Option<ProgramSpendingConfig> {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
+    /// Returns the configured retention count for `program_id`.
+    pub fn get_retention_count(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HistoryRetention(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Drops all but the newest `retention_count` payout records of
+    /// `program_id` and returns how many were removed.
+    ///
+    /// Balances are untouched, so `total_disbursed` is preserved, and the
+    /// removed records still count towards `payout_count`. Payout indices
+    /// (e.g. for `clawback_payout`) keep counting from the first payout ever
+    /// made.
+    ///
+    /// # Authorization
+    /// - Admin only; `nonce` must equal the admin's current nonce
+    pub fn prune_history(env: Env, program_id: String, nonce: u64) -> u32 {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        let cfg_key =
-            DataKey::ProgramSpendingConfig(program_id, program_data.token_address.clone());
-        env.storage().instance().get(&cfg_key)
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let retention = Self::get_retention_count(env.clone(), program_id.clone());
+        let len = program_data.payout_history.len();
+        if retention == 0 || len <= retention {
+            return 0;
+        }
+
+        let removed = len - retention;
+        program_data.payout_history = program_data.payout_history.slice(removed..);
+        Self::save_program(&env, &program_data);
+        env.storage().instance().set(
+            &DataKey::PrunedPayouts(program_id.clone()),
+            &(Self::pruned_payout_count(&env, &program_id) + removed),
+        );
+
+        env.events().publish(
+            (HISTORY_PRUNED, program_id),
+            (removed, retention, env.ledger().timestamp()),
+        );
+
+        removed
     }
 
-    /// Returns the current spending state (window start and amount released)
-    /// for a program/token pair, if any state has been recorded yet.
-    pub fn get_program_spending_state(
+    /// Returns the payout record at absolute `index` of `program_id`'s
+    /// history, counting from its first payout ever, matching the indices
+    /// used by `clawback_payout`.
+    ///
+    /// # Panics
+    /// * `NotFound` if `index` is past the end or was removed by `prune_history`
+    pub fn get_payout_at(env: Env, program_id: String, index: u64) -> PayoutRecord {
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let pruned = Self::pruned_payout_count(&env, &program_id) as u64;
+        index
+            .checked_sub(pruned)
+            .filter(|&i| i < program_data.payout_history.len() as u64)
+            .and_then(|i| program_data.payout_history.get(i as u32))
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotFound))
+    }
+
+    // ========================================================================
+    // Raffle Payouts
+    // ========================================================================
+
+    /// Pays `amount` to one of `candidates`, chosen by
+    /// `raffle_winner_index(seed, ledger sequence, nonce)`. The winning index
+    /// and seed are published so anyone can recompute the draw.
+    ///
+    /// # Authorization
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn raffle_payout(
         env: Env,
         program_id: String,
-    ) -> Option<ProgramSpendingState> {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
+        candidates: Vec<Address>,
+        amount: i128,
+        seed: BytesN<32>,
+        nonce: u64,
+    ) -> ProgramData {
+        if candidates.is_empty() {
+            panic_with_error!(&env, EscrowError::EmptyBatch);
+        }
 
-        let state_key =
-            DataKey::ProgramSpendingState(program_id, program_data.token_address.clone());
-        env.storage().instance().get(&state_key)
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let payout_key = program_data.authorized_payout_key;
+        payout_key.require_auth();
+        Self::consume_nonce(&env, &payout_key, nonce);
+
+        let index = raffle_winner_index(&env, &seed, nonce, candidates.len());
+        let winner = candidates.get(index).unwrap();
+
+        env.events().publish(
+            (symbol_short!("raffle"), program_id.clone()),
+            (index, winner.clone(), seed, env.ledger().sequence(), amount),
+        );
+
+        Self::execute_single_payout(env, program_id, winner, amount, u64::MAX, Some(payout_key))
     }
 
-    /// Creates an on-chain snapshot of critical configuration (admin-only).
-    /// Returns the snapshot id.
-    pub fn create_config_snapshot(env: Env) -> u64 {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+    // ========================================================================
+    // Config Export / Import
+    // ========================================================================
 
-        let next_id: u64 = env
+    /// Bundles the contract-wide configuration into one `ConfigExport`.
+    pub fn export_config(env: Env) -> ConfigExport {
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&ConfigSnapshotKey::SnapshotCounter)
-            .unwrap_or(0)
-            + 1;
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        let threshold_config = if threshold_monitor::has_threshold_config(&env) {
+            Some(threshold_monitor::get_threshold_config(&env))
+        } else {
+            None
+        };
 
-        let snapshot = ConfigSnapshot {
-            id: next_id,
-            timestamp: env.ledger().timestamp(),
+        let mut token_thresholds = Vec::new(&env);
+        for token in threshold_monitor::get_configured_tokens(&env).iter() {
+            if let Some(config) = threshold_monitor::get_threshold_config_for(&env, &token) {
+                token_thresholds.push_back((token, config));
+            }
+        }
+        let mut signer_rate_limits = Vec::new(&env);
+        for signer in Self::rate_limited_signers(&env).iter() {
+            if let Some(limit) = Self::get_rate_limit(env.clone(), signer.clone()) {
+                signer_rate_limits.push_back((signer, limit.min_interval_secs));
+            }
+        }
+
+        ConfigExport {
+            admin,
+            anti_abuse_admin: anti_abuse::get_admin(&env),
             fee_config: Self::get_fee_config_internal(&env),
             anti_abuse_config: anti_abuse::get_config(&env),
-            anti_abuse_admin: anti_abuse::get_admin(&env),
+            threshold_config,
+            token_thresholds,
+            require_threshold_config: env
+                .storage()
+                .instance()
+                .has(&DataKey::RequireThresholdConfig),
+            daily_outflow_limit: threshold_monitor::get_daily_outflow_limit(&env),
+            max_batch_size: Self::get_max_batch_size_internal(&env),
+            event_level: Self::get_event_level(env.clone()),
+            auth_mode: Self::get_auth_mode(env.clone()),
+            rounding_mode: Self::rounding_mode(&env),
+            signer_rate_limits,
             is_paused: Self::is_paused_internal(&env),
-        };
-
-        env.storage()
-            .instance()
-            .set(&ConfigSnapshotKey::Snapshot(next_id), &snapshot);
+            pause_reason: Self::get_pause_reason(env.clone()),
+        }
+    }
 
-        let mut index: Vec<u64> = env
+    /// Applies an `export_config` bundle to this deployment (admin only).
+    ///
+    /// Only allowed on a fresh deployment: once any payout has been made the
+    /// call panics with `AlreadyProcessed`. The snapshot's admin becomes the
+    /// admin of this contract. Values are validated as by their setters, so
+    /// an invalid entry panics with `InvalidConfig`.
+    pub fn import_config(env: Env, snapshot: ConfigExport, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&ConfigSnapshotKey::SnapshotIndex)
-            .unwrap_or(vec![&env]);
-        index.push_back(next_id);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        if index.len() > CONFIG_SNAPSHOT_LIMIT {
-            let oldest_snapshot_id = index.get(0).unwrap();
+        if Self::get_storage_footprint(env.clone()).payout_count > 0 {
+            panic_with_error!(&env, EscrowError::AlreadyProcessed);
+        }
+
+        if let Some(config) = snapshot.threshold_config {
+            if threshold_monitor::set_threshold_config(&env, config).is_err() {
+                panic_with_error!(&env, EscrowError::InvalidConfig);
+            }
+        }
+        for (token, config) in snapshot.token_thresholds.iter() {
+            if threshold_monitor::set_threshold_config_for(&env, &token, config).is_err() {
+                panic_with_error!(&env, EscrowError::InvalidConfig);
+            }
+        }
+        if threshold_monitor::set_daily_outflow_limit(&env, snapshot.daily_outflow_limit).is_err() {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        if snapshot.max_batch_size == 0 || snapshot.event_level > EVENT_LEVEL_VERBOSE {
+            panic_with_error!(&env, EscrowError::InvalidConfig);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxBatchSize, &snapshot.max_batch_size);
+        env.storage()
+            .instance()
+            .set(&DataKey::EventLevel, &snapshot.event_level);
+        env.storage()
+            .instance()
+            .set(&DataKey::AuthMode, &snapshot.auth_mode);
+        env.storage()
+            .instance()
+            .set(&DataKey::RoundingMode, &snapshot.rounding_mode);
+        for (signer, secs) in snapshot.signer_rate_limits.iter() {
+            env.storage().persistent().set(
+                &DataKey::SignerRateLimit(signer.clone()),
+                &SignerRateLimit {
+                    min_interval_secs: secs,
+                    last_payout_ts: None,
+                },
+            );
+            Self::index_rate_limited_signer(&env, &signer);
+        }
+        if snapshot.require_threshold_config {
+            env.storage()
+                .instance()
+                .set(&DataKey::RequireThresholdConfig, &true);
+        } else {
             env.storage()
                 .instance()
-                .remove(&ConfigSnapshotKey::Snapshot(oldest_snapshot_id));
-
-            let mut trimmed = Vec::new(&env);
-            for i in 1..index.len() {
-                trimmed.push_back(index.get(i).unwrap());
-            }
-            index = trimmed;
+                .remove(&DataKey::RequireThresholdConfig);
         }
 
         env.storage()
             .instance()
-            .set(&ConfigSnapshotKey::SnapshotIndex, &index);
+            .set(&FEE_CONFIG, &snapshot.fee_config);
+        anti_abuse::set_config(&env, snapshot.anti_abuse_config);
+        match snapshot.anti_abuse_admin {
+            Some(anti_abuse_admin) => anti_abuse::set_admin(&env, anti_abuse_admin),
+            None => anti_abuse::clear_admin(&env),
+        }
         env.storage()
             .instance()
-            .set(&ConfigSnapshotKey::SnapshotCounter, &next_id);
+            .set(&DataKey::IsPaused, &snapshot.is_paused);
+        if snapshot.is_paused {
+            env.storage()
+                .instance()
+                .set(&DataKey::PauseReason, &snapshot.pause_reason);
+        } else {
+            env.storage().instance().remove(&DataKey::PauseReason);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Admin, &snapshot.admin);
 
         env.events().publish(
-            (symbol_short!("cfg_snap"), symbol_short!("create")),
-            (next_id, snapshot.timestamp),
+            (symbol_short!("cfg_snap"), symbol_short!("import")),
+            (admin, snapshot.admin, env.ledger().timestamp()),
         );
-
-        next_id
+        Self::record_config_change(&env, symbol_short!("import"));
     }
 
-    /// Lists retained configuration snapshots in chronological order.
-    pub fn list_config_snapshots(env: Env) -> Vec<ConfigSnapshot> {
-        let index: Vec<u64> = env
-            .storage()
-            .instance()
-            .get(&ConfigSnapshotKey::SnapshotIndex)
-            .unwrap_or(vec![&env]);
+    // ========================================================================
+    // Aggregated Batch Payouts
+    // ========================================================================
 
-        let mut snapshots = Vec::new(&env);
-        for snapshot_id in index.iter() {
-            if let Some(snapshot) = env
-                .storage()
-                .instance()
-                .get(&ConfigSnapshotKey::Snapshot(snapshot_id))
-            {
-                snapshots.push_back(snapshot);
+    /// Like `batch_payout`, but rows naming the same recipient are summed
+    /// first so each unique recipient gets one transfer and one
+    /// `PayoutRecord`. Balance, daily and per-recipient `max_single_payout`
+    /// checks apply to the aggregated amounts.
+    pub fn batch_payout_aggregated(
+        env: Env,
+        program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        nonce: u64,
+    ) -> ProgramData {
+        if recipients.len() > Self::get_max_batch_size_internal(&env) {
+            panic_with_error!(&env, EscrowError::BatchTooLarge);
+        }
+        if recipients.len() != amounts.len() {
+            panic_with_error!(&env, EscrowError::BatchLengthMismatch);
+        }
+
+        let program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        let payout_key = program_data.authorized_payout_key;
+        payout_key.require_auth();
+        Self::consume_nonce(&env, &payout_key, nonce);
+
+        let mut unique: Vec<Address> = Vec::new(&env);
+        let mut totals: Vec<i128> = Vec::new(&env);
+        for (recipient, amount) in recipients.iter().zip(amounts.iter()) {
+            if amount <= 0 {
+                panic_with_error!(&env, EscrowError::InvalidAmount);
+            }
+            match unique.first_index_of(&recipient) {
+                Some(i) => {
+                    let total = totals
+                        .get(i)
+                        .unwrap()
+                        .checked_add(amount)
+                        .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+                    totals.set(i, total);
+                }
+                None => {
+                    unique.push_back(recipient);
+                    totals.push_back(amount);
+                }
             }
         }
 
-        snapshots
+        // Already authorized above; don't ask the payout key to sign again
+        let signers = vec![&env, payout_key];
+        Self::execute_batch_payout(env, program_id, unique, totals, Some(signers))
     }
 
-    /// Restores contract configuration from a prior snapshot (admin-only).
-    pub fn restore_config_snapshot(env: Env, snapshot_id: u64) {
-        let admin = anti_abuse::get_admin(&env).expect("Admin not set");
-        admin.require_auth();
+    // ========================================================================
+    // Post-Breach Safe Mode
+    // ========================================================================
 
-        let snapshot: ConfigSnapshot = env
+    /// Configures post-breach safe mode (admin only). When enabled, payouts
+    /// to non-whitelisted recipients fail with `RecipientNotWhitelisted` for
+    /// `safe_mode_duration_secs` after window outflow reaches
+    /// `outflow_volume_threshold`.
+    pub fn set_safe_mode_config(env: Env, config: SafeModeConfig, nonce: u64) {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&ConfigSnapshotKey::Snapshot(snapshot_id))
-            .unwrap_or_else(|| panic!("Snapshot not found"));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
         env.storage()
             .instance()
-            .set(&FEE_CONFIG, &snapshot.fee_config);
-        anti_abuse::set_config(&env, snapshot.anti_abuse_config);
-
-        match snapshot.anti_abuse_admin {
-            Some(snapshot_admin) => anti_abuse::set_admin(&env, snapshot_admin),
-            None => anti_abuse::clear_admin(&env),
-        }
+            .set(&DataKey::SafeModeConfig, &config);
+        Self::record_config_change(&env, symbol_short!("safe_mode"));
+    }
 
+    /// Returns the safe mode configuration (disabled by default).
+    pub fn get_safe_mode_config(env: Env) -> SafeModeConfig {
         env.storage()
             .instance()
-            .set(&DataKey::IsPaused, &snapshot.is_paused);
+            .get(&DataKey::SafeModeConfig)
+            .unwrap_or_default()
+    }
 
-        env.events().publish(
-            (symbol_short!("cfg_snap"), symbol_short!("restore")),
-            (snapshot_id, env.ledger().timestamp()),
-        );
+    /// Whether payouts are currently restricted to whitelisted recipients.
+    pub fn is_safe_mode_active(env: Env) -> bool {
+        let config = Self::get_safe_mode_config(env.clone());
+        if !config.safe_mode_after_breach {
+            return false;
+        }
+        match threshold_monitor::get_last_breach_at(&env) {
+            Some(breach_at) => {
+                env.ledger().timestamp() < breach_at.saturating_add(config.safe_mode_duration_secs)
+            }
+            None => false,
+        }
     }
 
     // ========================================================================
-    // Schedule View Functions
+    // Balance Reconciliation
     // ========================================================================
 
-    /// Retrieves a specific program release schedule.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program containing the schedule
-    /// * `schedule_id` - The schedule ID to retrieve
-    ///
-    /// # Returns
-    /// * `ProgramReleaseSchedule` - The schedule details
+    /// Compares the contract's on-ledger `token` balance with what the
+    /// escrow accounts for, returning `(ledger_balance, tracked, difference)`.
     ///
-    /// # Panics
-    /// * If schedule doesn't exist
-    pub fn get_program_release_schedule(
-        env: Env,
-        program_id: String,
-        schedule_id: u64,
-    ) -> ProgramReleaseSchedule {
-        env.storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id, schedule_id))
-            .unwrap_or_else(|| panic!("Schedule not found"))
+    /// `tracked` is the `remaining_balance` of every program holding `token`
+    /// plus the unwithdrawn part of their active payment streams, the unpaid
+    /// installments of their recurring payouts and the escrow of pending
+    /// prepared locks. A positive `difference` is untracked surplus, e.g. a
+    /// direct transfer into the contract; a negative one means the ledger
+    /// holds less than is owed.
+    pub fn reconcile(env: Env, token: Address) -> (i128, i128, i128) {
+        let ledger_balance =
+            token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let tracked = Self::tracked_token_balance(&env, &token);
+        (ledger_balance, tracked, ledger_balance - tracked)
     }
 
-    /// Get aggregate statistics for the program
-    pub fn get_program_aggregate_stats(env: Env) -> ProgramAggregateStats {
-        let program_data: ProgramData = env
+    /// Credits the untracked surplus reported by `reconcile` for the
+    /// program's token to `program_id` (admin only), adding it to both
+    /// `total_funds` and `remaining_balance`. Escrow owed back to depositors
+    /// of pending prepared locks is tracked, so it is never swept. Panics
+    /// with `NoSurplus` when the ledger holds nothing beyond what is already
+    /// tracked.
+    pub fn sweep_untracked(env: Env, program_id: String, nonce: u64) -> i128 {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
-        let schedules: Vec<ProgramReleaseSchedule> = env
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
+
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+
+        let (_, _, surplus) = Self::reconcile(env.clone(), program_data.token_address.clone());
+        if surplus <= 0 {
+            panic_with_error!(&env, EscrowError::NoSurplus);
+        }
+
+        let total_funds = program_data
+            .total_funds
+            .checked_add(surplus)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        Self::enforce_max_total_funds(&env, &program_id, total_funds);
+        program_data.total_funds = total_funds;
+        program_data.remaining_balance += surplus;
+        Self::save_program(&env, &program_data);
+
+        env.events().publish(
+            (SWEEP, program_id),
+            (surplus, program_data.remaining_balance, admin),
+        );
+
+        surplus
+    }
+
+    /// Lowers `program_id`'s `remaining_balance` when the ledger holds less
+    /// of its token than the escrow tracks, e.g. after an external clawback
+    /// (admin only). Returns the reduction, emitting `BALANCE_SYNCED` with
+    /// it when non-zero. Never increases the balance; credit surplus with
+    /// `sweep_untracked` instead.
+    pub fn sync_balance(env: Env, program_id: String, nonce: u64) -> i128 {
+        Self::require_not_finalized(&env);
+        let admin: Address = env
             .storage()
             .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::NotInitialized));
+        admin.require_auth();
+        Self::consume_nonce(&env, &admin, nonce);
 
-        let mut scheduled_count = 0u32;
-        let mut released_count = 0u32;
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
 
-        for i in 0..schedules.len() {
-            let schedule = schedules.get(i).unwrap();
-            if schedule.released {
-                released_count += 1;
-            } else {
-                scheduled_count += 1;
-            }
+        let (_, _, difference) = Self::reconcile(env.clone(), program_data.token_address.clone());
+        if difference >= 0 {
+            return 0;
         }
-
-        ProgramAggregateStats {
-            total_funds: program_data.total_funds,
-            remaining_balance: program_data.remaining_balance,
-            total_paid_out: program_data.total_funds - program_data.remaining_balance,
-            authorized_payout_key: program_data.authorized_payout_key.clone(),
-            payout_history: program_data.payout_history.clone(),
-            token_address: program_data.token_address.clone(),
-            payout_count: program_data.payout_history.len(),
-            scheduled_count,
-            released_count,
+        let delta = (-difference).min(program_data.remaining_balance);
+        if delta <= 0 {
+            return 0;
         }
+        program_data.remaining_balance -= delta;
+        Self::save_program(&env, &program_data);
+        Self::update_low_balance_alert(&env, &program_data);
+
+        env.events().publish(
+            (BALANCE_SYNCED, program_id),
+            (delta, program_data.remaining_balance, admin),
+        );
+
+        delta
     }
 
-    /// Returns time-weighted average metrics over the last 24 hours (sliding window).
-    ///
-    /// * **window_secs**: 86400 (24 × 1h periods).
-    /// * **avg_lock_size**: Sum of lock amounts in window / number of locks; 0 if no locks.
-    /// * **avg_settlement_time_secs**: Sum of (payout_timestamp − last_lock_timestamp) / number of payouts; 0 if none.
-    ///
-    /// Gas-efficient and bounded (24 buckets only).
-    pub fn get_time_weighted_metrics(env: Env) -> TimeWeightedMetrics {
-        twa::get_time_weighted_metrics(&env)
+    /// What the escrow owes in `token`, kept current by `save_program` and
+    /// by every change to streams, recurring payouts and prepared locks.
+    fn tracked_token_balance(env: &Env, token: &Address) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TrackedBalance(token.clone()))
+            .unwrap_or(0)
     }
 
-    /// Retrieves all release schedules for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
+    fn adjust_tracked_balance(env: &Env, token: &Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let tracked = Self::tracked_token_balance(env, token)
+            .checked_add(delta)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::AmountOverflow));
+        env.storage()
+            .instance()
+            .set(&DataKey::TrackedBalance(token.clone()), &tracked);
+    }
+
+    // ========================================================================
+    // Fund And Pay
+    // ========================================================================
+
+    /// Pulls `amount` from `depositor` into the program and pays it straight
+    /// to `recipient` in one atomic call, so no balance is left dangling.
+    /// The deposit is credited to `total_funds` first; the payout then runs
+    /// the usual `single_payout` checks and leaves `remaining_balance` where
+    /// it started.
     ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All schedules for the program
-    pub fn get_all_prog_release_schedules(
+    /// # Authorization
+    /// - `depositor`
+    /// - `authorized_payout_key`; `nonce` must equal that key's current nonce
+    pub fn fund_and_pay(
         env: Env,
         program_id: String,
-    ) -> Vec<ProgramReleaseSchedule> {
-        let mut schedules = Vec::new(&env);
-        let next_id: u64 = env
-            .storage()
-            .persistent()
-            .get(&DataKey::NextScheduleId(program_id.clone()))
-            .unwrap_or(1);
-
-        for schedule_id in 1..next_id {
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            {
-                let schedule: ProgramReleaseSchedule = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-                    .unwrap();
-                schedules.push_back(schedule);
-            }
+        depositor: Address,
+        recipient: Address,
+        amount: i128,
+        nonce: u64,
+    ) -> ProgramData {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::enforce_funding_not_frozen(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
         }
+        Self::enforce_max_single_lock(&env, amount);
 
-        schedules
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        Self::require_token_allowed(&env, &program_data.token_address);
+        Self::require_program_open(&env, &program_data.program_id);
+        let payout_key = program_data.authorized_payout_key.clone();
+        payout_key.require_auth();
+        Self::consume_nonce(&env, &payout_key, nonce);
+        depositor.require_auth();
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        program_data.total_funds = program_data
+            .total_funds
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        program_data.remaining_balance = program_data
+            .remaining_balance
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        Self::enforce_max_total_funds(&env, &program_id, program_data.total_funds);
+        Self::save_program(&env, &program_data);
+
+        let receipt_id = Self::increment_receipt_id(&env);
+        env.events().publish(
+            (FUNDS_LOCKED, program_id.clone()),
+            FundsLockedEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                amount,
+                remaining_balance: program_data.remaining_balance,
+                receipt_id,
+                percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+            },
+        );
+
+        Self::execute_single_payout(env, program_id, recipient, amount, u64::MAX, Some(payout_key))
     }
 
-    /// Retrieves pending (unreleased) schedules for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
+    // ========================================================================
+    // Earmarked Deposits
+    // ========================================================================
+
+    /// Pulls `amount` from `depositor` into the program, earmarked for
+    /// `recipient`. Earmarked funds count toward the program's balance but
+    /// can only be paid to `recipient`, and payouts to `recipient` draw from
+    /// their earmark before the program's unearmarked balance.
     ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All pending schedules
-    pub fn get_pending_program_schedules(
+    /// # Authorization
+    /// - `depositor`
+    pub fn lock_earmarked(
         env: Env,
         program_id: String,
-    ) -> Vec<ProgramReleaseSchedule> {
-        let all_schedules = Self::get_all_prog_release_schedules(env.clone(), program_id.clone());
-        let mut pending = Vec::new(&env);
-
-        for schedule in all_schedules.iter() {
-            if !schedule.released {
-                pending.push_back(schedule.clone());
-            }
+        depositor: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic_with_error!(&env, EscrowError::FundsPaused);
+        }
+        Self::enforce_funding_not_frozen(&env);
+        if amount <= 0 {
+            panic_with_error!(&env, EscrowError::InvalidAmount);
         }
+        Self::enforce_max_single_lock(&env, amount);
 
-        pending
-    }
+        let mut program_data: ProgramData = Self::load_program(&env, &program_id)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::ProgramNotFound));
+        Self::require_token_allowed(&env, &program_data.token_address);
+        Self::require_program_open(&env, &program_data.program_id);
+        depositor.require_auth();
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        program_data.total_funds = program_data
+            .total_funds
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        program_data.remaining_balance = program_data
+            .remaining_balance
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        Self::enforce_max_total_funds(&env, &program_id, program_data.total_funds);
+        Self::save_program(&env, &program_data);
+        Self::update_low_balance_alert(&env, &program_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::LastLockAt(program_id.clone()), &env.ledger().timestamp());
 
-    /// Retrieves due schedules (timestamp passed but not released) for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
-    ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseSchedule>` - All due but unreleased schedules
-    pub fn get_due_program_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
-        let pending = Self::get_pending_program_schedules(env.clone(), program_id.clone());
-        let mut due = Vec::new(&env);
-        let now = env.ledger().timestamp();
+        let earmark = Self::get_earmark(env.clone(), program_id.clone(), recipient.clone())
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(&env, EscrowError::AmountOverflow));
+        let earmark_total = Self::earmarked_total(&env, &program_id) + amount;
+        env.storage().instance().set(
+            &DataKey::Earmark(program_id.clone(), recipient.clone()),
+            &earmark,
+        );
+        env.storage()
+            .instance()
+            .set(&DataKey::EarmarkTotal(program_id.clone()), &earmark_total);
 
-        for schedule in pending.iter() {
-            if schedule.release_timestamp <= now {
-                due.push_back(schedule.clone());
-            }
-        }
+        let receipt_id = Self::increment_receipt_id(&env);
+        env.events().publish(
+            (FUNDS_LOCKED, program_id.clone()),
+            FundsLockedEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                amount,
+                remaining_balance: program_data.remaining_balance,
+                receipt_id,
+                percent_funded_bps: Self::percent_funded_bps(&env, &program_data),
+            },
+        );
+        env.events().publish(
+            (EARMARKED, program_id),
+            (depositor, recipient, amount, earmark),
+        );
 
-        due
+        program_data
     }
 
-    /// Retrieves release history for a program.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    /// * `program_id` - The program to query
-    ///
-    /// # Returns
-    /// * `Vec<ProgramReleaseHistory>` - Complete release history
-    pub fn get_program_release_history(env: Env, program_id: String) -> Vec<ProgramReleaseHistory> {
+    /// Returns the balance of `program_id` still earmarked for `recipient`
+    /// (`0` when none).
+    pub fn get_earmark(env: Env, program_id: String, recipient: Address) -> i128 {
         env.storage()
-            .persistent()
-            .get(&DataKey::ReleaseHistory(program_id))
-            .unwrap_or(vec![&env])
+            .instance()
+            .get(&DataKey::Earmark(program_id, recipient))
+            .unwrap_or(0)
     }
 
-    /// Compute reputation score from on-chain program state.
-    ///
-    /// Scores are in basis points (10000 = 100%).
-    /// - `completion_rate_bps`: completed_releases / total_scheduled
-    /// - `payout_fulfillment_rate_bps`: funds_distributed / funds_locked
-    /// - `overall_score_bps`: weighted average (60% completion, 40% fulfillment)
-    pub fn get_program_reputation(env: Env, program_id: String) -> ProgramReputationScore {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
+    fn earmarked_total(env: &Env, program_id: &String) -> i128 {
+        env.storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-        let schedules = Self::get_all_prog_release_schedules(env.clone(), program_id);
-
-        let now = env.ledger().timestamp();
-        let total_payouts = program_data.payout_history.len();
-        let total_scheduled = schedules.len();
-        let mut completed_releases = 0u32;
-        let mut pending_releases = 0u32;
-        let mut overdue_releases = 0u32;
+            .get(&DataKey::EarmarkTotal(program_id.clone()))
+            .unwrap_or(0)
+    }
 
-        for i in 0..schedules.len() {
-            let schedule = schedules.get(i).unwrap();
-            if schedule.released {
-                completed_releases += 1;
-            } else {
-                pending_releases += 1;
-                if schedule.release_timestamp <= now {
-                    overdue_releases += 1;
-                }
-            }
-        }
+    /// The part of the program's balance not earmarked for anyone. Every
+    /// path that moves funds to someone other than an earmark's recipient
+    /// is bounded by this.
+    fn unearmarked_balance(env: &Env, program_data: &ProgramData) -> i128 {
+        program_data.remaining_balance - Self::earmarked_total(env, &program_data.program_id)
+    }
 
-        let total_funds_locked = program_data.total_funds;
-        let total_funds_distributed = program_data.total_funds - program_data.remaining_balance;
+    /// Removes the sweepable balance from `program_data` and returns it.
+    /// Earmarked funds stay behind for their recipients and unreleased
+    /// release schedules stay funded, as in `withdraw_excess`.
+    fn take_unearmarked(env: &Env, program_data: &mut ProgramData) -> i128 {
+        let scheduled = get_program_total_scheduled_amount(env, &program_data.program_id);
+        let amount = (Self::unearmarked_balance(env, program_data) - scheduled).max(0);
+        program_data.remaining_balance -= amount;
+        amount
+    }
 
-        let completion_rate_bps: u32 = if total_scheduled > 0 {
-            ((completed_releases as u64 * BASIS_POINTS as u64) / total_scheduled as u64) as u32
-        } else {
-            10_000
-        };
+    /// Charges a payout of `amount` to `recipient` against their earmark
+    /// first and `unearmarked` for the rest, panicking with
+    /// `InsufficientBalance` if that would dip into someone else's earmark.
+    fn draw_earmark(
+        env: &Env,
+        program_id: &String,
+        recipient: &Address,
+        amount: i128,
+        unearmarked: &mut i128,
+    ) {
+        let earmark = Self::get_earmark(env.clone(), program_id.clone(), recipient.clone());
+        let drawn = earmark.min(amount);
+        if amount - drawn > *unearmarked {
+            reentrancy_guard::clear_entered(env);
+            panic_with_error!(env, EscrowError::InsufficientBalance);
+        }
+        *unearmarked -= amount - drawn;
+        if drawn == 0 {
+            return;
+        }
 
-        let payout_fulfillment_rate_bps: u32 = if total_funds_locked > 0 {
-            ((total_funds_distributed as u64 * BASIS_POINTS as u64) / total_funds_locked as u64)
-                as u32
+        let key = DataKey::Earmark(program_id.clone(), recipient.clone());
+        if drawn == earmark {
+            env.storage().instance().remove(&key);
         } else {
-            10_000
-        };
-
-        let overall_score_bps: u32 =
-            (completion_rate_bps as u64 * 60 + payout_fulfillment_rate_bps as u64 * 40) as u32
-                / 100;
-
-        ProgramReputationScore {
-            total_payouts,
-            total_scheduled,
-            completed_releases,
-            pending_releases,
-            overdue_releases,
-            dispute_count: 0,
-            refund_count: 0,
-            total_funds_locked,
-            total_funds_distributed,
-            completion_rate_bps,
-            payout_fulfillment_rate_bps,
-            overall_score_bps,
+            env.storage().instance().set(&key, &(earmark - drawn));
         }
+        env.storage().instance().set(
+            &DataKey::EarmarkTotal(program_id.clone()),
+            &(Self::earmarked_total(env, program_id) - drawn),
+        );
     }
 }
 
@@ -3580,6 +8722,19 @@ fn get_program_total_scheduled_amount(env: &Env, program_id: &String) -> i128 {
     total
 }
 
+/// Index drawn by `raffle_payout`: the first 8 bytes of
+/// `sha256(seed || ledger_sequence || nonce)` (big-endian) modulo `count`.
+fn raffle_winner_index(env: &Env, seed: &BytesN<32>, nonce: u64, count: u32) -> u32 {
+    let mut input = Bytes::from_array(env, &seed.to_array());
+    input.extend_from_array(&env.ledger().sequence().to_be_bytes());
+    input.extend_from_array(&nonce.to_be_bytes());
+
+    let digest = env.crypto().sha256(&input).to_array();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(head) % count as u64) as u32
+}
+
 // ========================================================================
 // Program Registration Tests
 // ========================================================================
@@ -4132,7 +9287,7 @@ mod test_pause;
     }
 
     #[test]
-    #[should_panic(expected = "Program not found")]
+    #[should_panic(expected = "Error(Contract, #5)")]
     fn test_get_nonexistent_program() {
         let env = Env::default();
         let contract_id = env.register_contract(None, ProgramEscrowContract);
@@ -4270,7 +9425,7 @@ pub fn preview_split_payout(
     }
 
     #[test]
-    #[should_panic(expected = "Program spending limit exceeded for current window")]
+    #[should_panic(expected = "Error(Contract, #17)")]
     fn test_program_spending_limit_enforced_for_batch_payout() {
         let env = Env::default();
         env.mock_all_auths();
@@ -4380,7 +9535,7 @@ pub fn preview_split_payout(
     // ========================================================================
 
     #[test]
-    #[should_panic(expected = "Operation in cooldown period")]
+    #[should_panic(expected = "Error(Contract, #21)")]
     fn test_anti_abuse_cooldown_panic() {
         let env = Env::default();
         env.mock_all_auths();
@@ -4404,7 +9559,7 @@ pub fn preview_split_payout(
     }
 
     #[test]
-    #[should_panic(expected = "Rate limit exceeded")]
+    #[should_panic(expected = "Error(Contract, #21)")]
     fn test_anti_abuse_limit_panic() {
         let env = Env::default();
         env.mock_all_auths();
@@ -4451,3 +9606,226 @@ pub fn preview_split_payout(
 #[cfg(test)]
 #[cfg(any())]
 mod rbac_tests;
+
+#[cfg(test)]
+mod test_daily_limit;
+
+#[cfg(test)]
+mod test_init_key_auth;
+
+#[cfg(test)]
+mod test_health_check;
+
+#[cfg(test)]
+mod test_ownership;
+
+#[cfg(test)]
+mod test_max_batch_size;
+
+#[cfg(test)]
+mod test_signer_rate_limit;
+
+#[cfg(test)]
+mod test_withdraw_excess;
+
+#[cfg(test)]
+mod test_hashed_program_id;
+
+#[cfg(test)]
+mod test_payout_digest;
+
+#[cfg(test)]
+mod test_batch_single_threshold;
+
+#[cfg(test)]
+mod test_drain_to_admin;
+
+#[cfg(test)]
+mod test_event_level;
+
+#[cfg(test)]
+mod test_two_phase_lock;
+
+#[cfg(test)]
+mod test_payout_delegate;
+
+#[cfg(test)]
+mod test_max_total_funds;
+
+#[cfg(test)]
+mod test_failure_rate;
+
+#[cfg(test)]
+mod test_clawback_payout;
+
+#[cfg(test)]
+mod test_rounding_mode;
+
+#[cfg(test)]
+mod test_recipient_stats;
+
+#[cfg(test)]
+mod test_scoped_payout_auth;
+
+#[cfg(test)]
+mod test_program_summary;
+
+#[cfg(test)]
+mod test_decimal_lock;
+
+#[cfg(test)]
+mod test_finalize_program;
+
+#[cfg(test)]
+mod test_payout_expiry;
+
+#[cfg(test)]
+mod test_window_rolled;
+
+#[cfg(test)]
+mod test_batch_reclaim;
+
+#[cfg(test)]
+mod test_native_xlm;
+
+#[cfg(test)]
+mod test_validate_batch;
+
+#[cfg(test)]
+mod test_clear_cooldown;
+
+#[cfg(test)]
+mod test_payment_streams;
+
+#[cfg(test)]
+mod test_storage_footprint;
+
+#[cfg(test)]
+mod test_require_threshold_config;
+
+#[cfg(test)]
+mod test_token_thresholds;
+
+#[cfg(test)]
+mod test_forward_to_escrow;
+
+#[cfg(test)]
+mod test_max_payable_now;
+
+#[cfg(test)]
+mod test_signed_payout;
+
+#[cfg(test)]
+mod test_prune_history;
+
+#[cfg(test)]
+mod test_operation_reason;
+
+#[cfg(test)]
+mod test_raffle_payout;
+#[cfg(test)]
+mod test_lock_overflow;
+#[cfg(test)]
+mod test_config_export;
+#[cfg(test)]
+mod test_batch_payout_aggregated;
+#[cfg(test)]
+mod test_threshold_warning;
+#[cfg(test)]
+mod test_safe_mode;
+#[cfg(test)]
+mod test_reconcile;
+#[cfg(test)]
+mod test_sweep_untracked;
+#[cfg(test)]
+mod test_ledger_sequence_auth;
+#[cfg(test)]
+mod test_min_funding;
+#[cfg(test)]
+mod test_get_payout_at;
+#[cfg(test)]
+mod test_reclaim_delay;
+#[cfg(test)]
+mod test_init_program_full;
+#[cfg(test)]
+mod test_percent_funded;
+#[cfg(test)]
+mod test_recipient_cooldown;
+#[cfg(test)]
+mod test_is_initialized;
+#[cfg(test)]
+mod test_recurring;
+#[cfg(test)]
+mod test_self_payout;
+#[cfg(test)]
+mod test_nonce_status;
+#[cfg(test)]
+mod test_batch_lock;
+#[cfg(test)]
+mod test_freeze_funding;
+#[cfg(test)]
+mod test_config_changes;
+#[cfg(test)]
+mod test_balance_of;
+#[cfg(test)]
+mod test_batch_ordering;
+#[cfg(test)]
+mod test_low_balance;
+#[cfg(test)]
+mod test_metrics_history;
+#[cfg(test)]
+mod test_fund_and_pay;
+#[cfg(test)]
+mod test_token_allow_list;
+#[cfg(test)]
+mod test_threshold_hot_reload;
+#[cfg(test)]
+mod test_preview_fee;
+#[cfg(test)]
+mod test_notify_recipient;
+#[cfg(test)]
+mod test_batch_mode;
+#[cfg(test)]
+mod test_payout_queue;
+#[cfg(test)]
+mod test_spike_detection;
+#[cfg(test)]
+mod test_lazy_funding;
+#[cfg(test)]
+mod test_guardian;
+#[cfg(test)]
+mod test_payout_receipt;
+#[cfg(test)]
+mod test_sync_balance;
+#[cfg(test)]
+mod test_program_expiry;
+#[cfg(test)]
+mod test_multi_signer_batch;
+#[cfg(test)]
+mod test_pause_reason;
+#[cfg(test)]
+mod test_decimal_threshold_defaults;
+#[cfg(test)]
+mod test_cooldown_remaining;
+#[cfg(test)]
+mod test_program_event_topics;
+#[cfg(test)]
+mod test_program_infos;
+#[cfg(test)]
+mod test_program_storage_tier;
+#[cfg(test)]
+mod test_reservations;
+#[cfg(test)]
+mod test_empty_batch;
+#[cfg(test)]
+mod test_max_single_lock;
+#[cfg(test)]
+mod test_signer_set;
+#[cfg(test)]
+mod test_window_alignment;
+
+#[cfg(test)]
+mod test_reclaim_split;
+
+#[cfg(test)]
+mod test_earmark;
@@ -0,0 +1,45 @@
+//! # Payout History Digest
+//!
+//! Maintains a running SHA-256 hash chain over every `PayoutRecord` appended
+//! to a program's `payout_history`, so auditors can detect tampering or gaps
+//! by replaying payout events off-chain instead of downloading all records.
+//!
+//! ## Folding rule
+//!
+//! ```text
+//! digest_0 = [0u8; 32]
+//! digest_n = sha256(digest_{n-1} || xdr(recipient) || amount.to_be_bytes() || timestamp.to_be_bytes())
+//! ```
+//!
+//! `amount` is the net amount stored on the record (16 bytes, big-endian)
+//! and `timestamp` is the ledger timestamp (8 bytes, big-endian).
+
+use soroban_sdk::{xdr::ToXdr, Bytes, BytesN, Env, String};
+
+use crate::{DataKey, PayoutRecord};
+
+/// Current digest for a program; all zeroes before the first payout.
+pub fn get(env: &Env, program_id: &String) -> BytesN<32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutDigest(program_id.clone()))
+        .unwrap_or_else(|| BytesN::from_array(env, &[0u8; 32]))
+}
+
+/// Folds `record` into the program's digest and returns the new value.
+pub fn record(env: &Env, program_id: &String, record: &PayoutRecord) -> BytesN<32> {
+    let next = fold(env, &get(env, program_id), record);
+    env.storage()
+        .instance()
+        .set(&DataKey::PayoutDigest(program_id.clone()), &next);
+    next
+}
+
+/// One step of the hash chain, exposed for tests and off-chain parity checks.
+pub fn fold(env: &Env, previous: &BytesN<32>, record: &PayoutRecord) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &previous.to_array());
+    preimage.append(&record.recipient.clone().to_xdr(env));
+    preimage.extend_from_array(&record.amount.to_be_bytes());
+    preimage.extend_from_array(&record.timestamp.to_be_bytes());
+    env.crypto().sha256(&preimage).into()
+}
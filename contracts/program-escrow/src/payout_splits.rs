@@ -25,8 +25,11 @@
 // Expose the public functions inside the `ProgramEscrowContract` impl block.
 // ============================================================
 
-use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
-use crate::{DataKey, ProgramData, PayoutRecord, PROGRAM_DATA};
+use soroban_sdk::{
+    contracttype, panic_with_error, symbol_short, token, Address, Env, String, Symbol, Vec,
+};
+use crate::{DataKey, EscrowError, ProgramData, PayoutRecord, PROGRAM_DATA};
+use crate::token_math::{mul_div, RoundingMode};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -87,13 +90,25 @@ fn get_program(env: &Env) -> ProgramData {
     env.storage()
         .instance()
         .get(&PROGRAM_DATA)
-        .unwrap_or_else(|| panic!("Program not initialized"))
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotInitialized))
 }
 
 fn save_program(env: &Env, data: &ProgramData) {
     env.storage().instance().set(&PROGRAM_DATA, data);
 }
 
+/// One beneficiary's share of `total_amount` under `mode`, clamped so the
+/// running `distributed` total can never exceed `total_amount`.
+fn share_of(
+    total_amount: i128,
+    share_bps: i128,
+    distributed: i128,
+    mode: RoundingMode,
+) -> Option<i128> {
+    let share = mul_div(total_amount, share_bps, TOTAL_BASIS_POINTS, mode)?;
+    Some(share.min(total_amount - distributed))
+}
+
 // ---------------------------------------------------------------------------
 // Public API
 // ---------------------------------------------------------------------------
@@ -119,10 +134,10 @@ pub fn set_split_config(
 
     let n = beneficiaries.len();
     if n == 0 {
-        panic!("SplitConfig: must have at least one beneficiary");
+        panic_with_error!(env, EscrowError::InvalidConfig);
     }
     if n > 50 {
-        panic!("SplitConfig: maximum 50 beneficiaries");
+        panic_with_error!(env, EscrowError::InvalidConfig);
     }
 
     // Validate individual shares and compute total.
@@ -130,14 +145,14 @@ pub fn set_split_config(
     for i in 0..n {
         let entry = beneficiaries.get(i).unwrap();
         if entry.share_bps <= 0 {
-            panic!("SplitConfig: share_bps must be positive");
+            panic_with_error!(env, EscrowError::InvalidConfig);
         }
         total = total
             .checked_add(entry.share_bps)
-            .unwrap_or_else(|| panic!("SplitConfig: share overflow"));
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::AmountOverflow));
     }
     if total != TOTAL_BASIS_POINTS {
-        panic!("SplitConfig: shares must sum to 10000 basis points");
+        panic_with_error!(env, EscrowError::InvalidConfig);
     }
 
     let config = SplitConfig {
@@ -179,7 +194,7 @@ pub fn disable_split_config(env: &Env, program_id: &String) {
         .storage()
         .persistent()
         .get(&key)
-        .unwrap_or_else(|| panic!("No split config found for program"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
 
     config.active = false;
     env.storage().persistent().set(&key, &config);
@@ -211,10 +226,10 @@ pub fn execute_split_payout(
     program.authorized_payout_key.require_auth();
 
     if total_amount <= 0 {
-        panic!("SplitPayout: amount must be greater than zero");
+        panic_with_error!(env, EscrowError::InvalidAmount);
     }
     if total_amount > program.remaining_balance {
-        panic!("SplitPayout: insufficient escrow balance");
+        panic_with_error!(env, EscrowError::InsufficientBalance);
     }
 
     // Load and validate config.
@@ -222,10 +237,10 @@ pub fn execute_split_payout(
         .storage()
         .persistent()
         .get(&split_key(program_id))
-        .unwrap_or_else(|| panic!("SplitPayout: no split config found for program"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
 
     if !config.active {
-        panic!("SplitPayout: split config is disabled");
+        panic_with_error!(env, EscrowError::InvalidConfig);
     }
 
     let n = config.beneficiaries.len();
@@ -234,26 +249,25 @@ pub fn execute_split_payout(
     let now = env.ledger().timestamp();
 
     // Compute individual amounts using bp arithmetic; accumulate dust.
-    // dust = total_amount - sum(floor(total_amount * share_bps / 10_000))
+    // dust = total_amount - sum(round(total_amount * share_bps / 10_000))
+    let mode = crate::ProgramEscrowContract::rounding_mode(env);
     let mut amounts: soroban_sdk::Vec<i128> = soroban_sdk::Vec::new(env);
     let mut distributed: i128 = 0;
 
     for i in 0..n {
         let entry = config.beneficiaries.get(i).unwrap();
-        let share_amount = total_amount
-            .checked_mul(entry.share_bps)
-            .and_then(|x| x.checked_div(TOTAL_BASIS_POINTS))
-            .unwrap_or_else(|| panic!("SplitPayout: arithmetic overflow"));
+        let share_amount = share_of(total_amount, entry.share_bps, distributed, mode)
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::AmountOverflow));
         amounts.push_back(share_amount);
         distributed = distributed
             .checked_add(share_amount)
-            .unwrap_or_else(|| panic!("SplitPayout: sum overflow"));
+            .unwrap_or_else(|| panic_with_error!(env, EscrowError::AmountOverflow));
     }
 
     // Dust goes to index 0.
     let dust = total_amount - distributed;
     if dust < 0 {
-        panic!("SplitPayout: internal accounting error");
+        panic_with_error!(env, EscrowError::AmountOverflow);
     }
     let first_amount = amounts.get(0).unwrap() + dust;
     amounts.set(0, first_amount);
@@ -271,11 +285,13 @@ pub fn execute_split_payout(
 
         token_client.transfer(&contract_addr, &entry.recipient, &amount);
 
-        program.payout_history.push_back(PayoutRecord {
+        let record = PayoutRecord {
             recipient: entry.recipient.clone(),
             amount,
             timestamp: now,
-        });
+        };
+        crate::payout_digest::record(env, &program.program_id, &record);
+        program.payout_history.push_back(record);
     }
 
     program.remaining_balance -= total_amount;
@@ -313,19 +329,17 @@ pub fn preview_split(
         .storage()
         .persistent()
         .get(&split_key(program_id))
-        .unwrap_or_else(|| panic!("No split config found for program"));
+        .unwrap_or_else(|| panic_with_error!(env, EscrowError::NotFound));
 
     let n = config.beneficiaries.len();
     let mut preview: Vec<BeneficiarySplit> = Vec::new(env);
     let mut distributed: i128 = 0;
     let mut computed: soroban_sdk::Vec<i128> = soroban_sdk::Vec::new(env);
+    let mode = crate::ProgramEscrowContract::rounding_mode(env);
 
     for i in 0..n {
         let entry = config.beneficiaries.get(i).unwrap();
-        let share_amount = total_amount
-            .checked_mul(entry.share_bps)
-            .and_then(|x| x.checked_div(TOTAL_BASIS_POINTS))
-            .unwrap_or(0);
+        let share_amount = share_of(total_amount, entry.share_bps, distributed, mode).unwrap_or(0);
         computed.push_back(share_amount);
         distributed += share_amount;
     }
@@ -1794,7 +1794,7 @@ fn test_batch_payout_maximum_batch_size() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot process empty batch")]
+#[should_panic(expected = "Error(Contract, #12)")]
 fn test_batch_payout_empty_batch_panic() {
     // Test that empty batch is rejected
     let env = Env::default();
@@ -1808,7 +1808,7 @@ fn test_batch_payout_empty_batch_panic() {
 }
 
 #[test]
-#[should_panic(expected = "Recipients and amounts vectors must have the same length")]
+#[should_panic(expected = "Error(Contract, #14)")]
 fn test_batch_payout_mismatched_arrays_panic() {
     // Test that mismatched recipient/amount arrays are rejected
     let env = Env::default();
@@ -1822,7 +1822,7 @@ fn test_batch_payout_mismatched_arrays_panic() {
 }
 
 #[test]
-#[should_panic(expected = "All amounts must be greater than zero")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_batch_payout_invalid_amount_zero_panic() {
     // Test that zero amounts are rejected
     let env = Env::default();
@@ -1836,7 +1836,7 @@ fn test_batch_payout_invalid_amount_zero_panic() {
 }
 
 #[test]
-#[should_panic(expected = "All amounts must be greater than zero")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_batch_payout_invalid_amount_negative_panic() {
     // Test that negative amounts are rejected
     let env = Env::default();
@@ -1850,7 +1850,7 @@ fn test_batch_payout_invalid_amount_negative_panic() {
 }
 
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_batch_payout_insufficient_balance_panic() {
     // Test that insufficient balance is rejected
     let env = Env::default();
@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+//! # Claimable Balance Tests — Program Escrow
+//!
+//! `balance_of` exposes a recipient's withdrawable stream allocation with
+//! a token-style signature.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+#[test]
+fn test_balance_of_tracks_vested_allocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(900);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(&env, "claimable");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let recipient = Address::generate(&env);
+    let first = client.create_stream(&program_id, &recipient, &10, &1_000, &1_100, &0);
+    client.create_stream(&program_id, &recipient, &5, &1_000, &1_200, &1);
+    client.create_stream(&program_id, &Address::generate(&env), &7, &1_000, &1_100, &2);
+
+    assert_eq!(client.balance_of(&recipient), 0);
+
+    env.ledger().set_timestamp(1_050);
+    assert_eq!(client.balance_of(&recipient), 500 + 250);
+
+    client.withdraw_stream(&first);
+    assert_eq!(client.balance_of(&recipient), 250);
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.balance_of(&recipient), 500 + 1_000);
+}
+
+#[test]
+fn test_finished_streams_leave_recipient_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(900);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(&env, "claimable");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let recipient = Address::generate(&env);
+    let done = client.create_stream(&program_id, &recipient, &10, &1_000, &1_100, &0);
+    let cancelled = client.create_stream(&program_id, &recipient, &5, &1_000, &1_200, &1);
+
+    env.ledger().set_timestamp(1_100);
+    client.withdraw_stream(&done);
+    client.cancel_stream(&cancelled, &2);
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(client.balance_of(&recipient), 0);
+    env.as_contract(&contract_id, || {
+        assert!(!env
+            .storage()
+            .persistent()
+            .has(&DataKey::RecipientStreams(recipient.clone())));
+    });
+}
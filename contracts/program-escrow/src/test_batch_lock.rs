@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Pooled Funding Tests — Program Escrow
+//!
+//! `batch_lock` pulls contributions from several depositors into one
+//! program in a single call.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String, Symbol, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    alice: Address,
+    bob: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "community");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+
+    let alice = Address::generate(env);
+    let bob = Address::generate(env);
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+    token_sac.mint(&alice, &1_000);
+    token_sac.mint(&bob, &1_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        alice,
+        bob,
+    }
+}
+
+#[test]
+fn test_two_depositors_fund_in_one_call() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let data = s.client.batch_lock(
+        &s.program_id,
+        &vec![&env, s.alice.clone(), s.bob.clone()],
+        &vec![&env, 300_i128, 700],
+    );
+
+    assert_eq!(data.total_funds, 1_000);
+    assert_eq!(data.remaining_balance, 1_000);
+    assert_eq!(s.token.balance(&s.alice), 700);
+    assert_eq!(s.token.balance(&s.bob), 300);
+    assert_eq!(s.token.balance(&s.client.address), 1_000);
+
+    let lock_events = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map_or(false, |t| Symbol::try_from_val(&env, &t).map_or(false, |s| s == FUNDS_LOCKED))
+        })
+        .count();
+    assert_eq!(lock_events, 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_mismatched_vectors_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.batch_lock(
+        &s.program_id,
+        &vec![&env, s.alice.clone(), s.bob.clone()],
+        &vec![&env, 300_i128],
+    );
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! # Batch Mode Tests — Program Escrow
+//!
+//! `batch_payout_with_mode` keeps `batch_payout`'s all-or-nothing behaviour
+//! under `Atomic`, while `BestEffort` skips invalid entries and reports a
+//! per-entry outcome.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    payout_key: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "batch-mode");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        payout_key,
+        program_id,
+    }
+}
+
+#[test]
+fn test_atomic_mode_aborts_on_invalid_entry() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alice = Address::generate(&env);
+
+    let result = s.client.try_batch_payout_with_mode(
+        &s.program_id,
+        &vec![&env, alice.clone(), s.payout_key.clone()],
+        &vec![&env, 100_i128, 200],
+        &BatchMode::Atomic,
+    );
+
+    assert_eq!(result, Err(Ok(EscrowError::SelfPayout)));
+    assert_eq!(s.token.balance(&alice), 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
+
+#[test]
+fn test_best_effort_skips_invalid_entry() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let outcomes = s.client.batch_payout_with_mode(
+        &s.program_id,
+        &vec![&env, alice.clone(), s.payout_key.clone(), bob.clone()],
+        &vec![&env, 100_i128, 200, 300],
+        &BatchMode::BestEffort,
+    );
+
+    assert_eq!(outcomes, vec![&env, true, false, true]);
+    assert_eq!(s.token.balance(&alice), 100);
+    assert_eq!(s.token.balance(&s.payout_key), 0);
+    assert_eq!(s.token.balance(&bob), 300);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_600);
+}
+
+#[test]
+fn test_best_effort_consumes_scoped_nonce_once() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+
+    let outcomes = s.client.batch_payout_with_mode(
+        &s.program_id,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 0_i128, 250],
+        &BatchMode::BestEffort,
+    );
+
+    assert_eq!(outcomes, vec![&env, false, true]);
+    assert_eq!(s.client.get_nonce_status(&s.payout_key).0, 1);
+}
+
+#[test]
+fn test_best_effort_skips_repeat_recipient_under_cooldown() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_recipient_cooldown(&60, &0);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let outcomes = s.client.batch_payout_with_mode(
+        &s.program_id,
+        &vec![&env, alice.clone(), bob.clone(), alice.clone()],
+        &vec![&env, 100_i128, 200, 300],
+        &BatchMode::BestEffort,
+    );
+
+    assert_eq!(outcomes, vec![&env, true, true, false]);
+    assert_eq!(s.token.balance(&alice), 100);
+    assert_eq!(s.token.balance(&bob), 200);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_700);
+}
+
+#[test]
+fn test_best_effort_all_skipped_still_consumes_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+
+    let outcomes = s.client.batch_payout_with_mode(
+        &s.program_id,
+        &vec![&env, Address::generate(&env), s.payout_key.clone()],
+        &vec![&env, 0_i128, 250],
+        &BatchMode::BestEffort,
+    );
+
+    assert_eq!(outcomes, vec![&env, false, false]);
+    assert_eq!(s.client.get_nonce_status(&s.payout_key).0, 1);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+//! # Batch Ordering Tests — Program Escrow
+//!
+//! `batch_payout` records recipients in input order and reverts the whole
+//! batch when any entry fails.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, token::Client<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "ordering");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, token::Client::new(env, &token_addr), program_id)
+}
+
+#[test]
+fn test_history_matches_input_order() {
+    let env = Env::default();
+    let (client, _token, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &10, &u64::MAX);
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let recipients = vec![&env, c.clone(), a.clone(), b.clone(), a.clone()];
+    let amounts = vec![&env, 400_i128, 100, 300, 200];
+    client.batch_payout(&program_id, &recipients, &amounts);
+
+    for i in 0..recipients.len() {
+        let record = client.get_payout_at(&program_id, &(1 + i as u64));
+        assert_eq!(record.recipient, recipients.get(i).unwrap());
+        assert_eq!(record.amount, amounts.get(i).unwrap());
+    }
+}
+
+#[test]
+fn test_failure_mid_batch_leaves_no_effect() {
+    let env = Env::default();
+    let (client, token, program_id) = setup(&env);
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let result = client.try_batch_payout(
+        &program_id,
+        &vec![&env, a.clone(), b.clone(), Address::generate(&env)],
+        &vec![&env, 100_i128, 200, 0],
+    );
+
+    assert_eq!(result, Err(Ok(EscrowError::InvalidAmount)));
+    assert_eq!(token.balance(&a), 0);
+    assert_eq!(token.balance(&b), 0);
+    assert_eq!(client.get_remaining_balance(&program_id), 10_000);
+    assert_eq!(
+        client.try_get_payout_at(&program_id, &0),
+        Err(Ok(EscrowError::NotFound))
+    );
+}
@@ -0,0 +1,90 @@
+#![cfg(test)]
+
+//! # Aggregated Batch Payout Tests — Program Escrow
+//!
+//! `batch_payout_aggregated` folds duplicate recipients into one transfer
+//! and one `PayoutRecord` each. The payout key signs and spends one nonce
+//! for the whole call.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(
+    env: &Env,
+) -> (ProgramEscrowContractClient<'static>, token::Client<'static>, String, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "aggregated");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, token::Client::new(env, &token_addr), program_id, payout_key)
+}
+
+#[test]
+fn test_duplicate_recipient_is_paid_once_with_combined_amount() {
+    let env = Env::default();
+    let (client, token, program_id, _) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let data = client.batch_payout_aggregated(
+        &program_id,
+        &vec![&env, alice.clone(), bob.clone(), alice.clone()],
+        &vec![&env, 100_i128, 200, 50],
+        &0,
+    );
+
+    assert_eq!(token.balance(&alice), 150);
+    assert_eq!(token.balance(&bob), 200);
+    assert_eq!(data.remaining_balance, 9_650);
+
+    assert_eq!(data.payout_history.len(), 2);
+    let first = data.payout_history.get(0).unwrap();
+    assert_eq!(first.recipient, alice);
+    assert_eq!(first.amount, 150);
+    assert_eq!(data.payout_history.get(1).unwrap().recipient, bob);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_aggregated_total_checked_against_balance() {
+    let env = Env::default();
+    let (client, _token, program_id, _) = setup(&env);
+    let alice = Address::generate(&env);
+
+    client.batch_payout_aggregated(
+        &program_id,
+        &vec![&env, alice.clone(), alice],
+        &vec![&env, 6_000_i128, 6_000],
+        &0,
+    );
+}
+
+#[test]
+fn test_aggregated_batch_authorizes_once() {
+    let env = Env::default();
+    let (client, _token, program_id, payout_key) = setup(&env);
+    client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+    let alice = Address::generate(&env);
+
+    client.batch_payout_aggregated(
+        &program_id,
+        &vec![&env, alice.clone(), alice],
+        &vec![&env, 100_i128, 200],
+        &0,
+    );
+    assert_eq!(client.get_nonce(&payout_key), 1);
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+//! # Batch Reclaim Tests — Program Escrow
+//!
+//! `batch_reclaim` sweeps the remaining balance of every program the caller
+//! owns to one destination, skipping the rest instead of failing. Funds
+//! committed to unreleased schedules stay behind.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String, Symbol, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    owner: Address,
+    stranger: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let owner = Address::generate(env);
+    let stranger = Address::generate(env);
+    for (name, creator, amount) in [
+        ("reclaim-a", &owner, 1_000_i128),
+        ("reclaim-b", &owner, 2_500),
+        ("reclaim-c", &stranger, 4_000),
+    ] {
+        let program_id = String::from_str(env, name);
+        client.init_program(&program_id, &Address::generate(env), &token_addr, creator, &None, &None);
+        token_sac.mint(&client.address, &amount);
+        client.lock_program_funds(&program_id, &amount);
+    }
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        owner,
+        stranger,
+    }
+}
+
+fn all_programs(env: &Env) -> Vec<String> {
+    vec![
+        env,
+        String::from_str(env, "reclaim-a"),
+        String::from_str(env, "reclaim-b"),
+        String::from_str(env, "reclaim-c"),
+    ]
+}
+
+#[test]
+fn test_reclaims_owned_programs_and_skips_others() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+
+    let total = s
+        .client
+        .batch_reclaim(&s.owner, &all_programs(&env), &treasury, &reason(&env), &0);
+
+    assert_eq!(total, 3_500);
+    assert_eq!(s.token.balance(&treasury), 3_500);
+    assert_eq!(s.token.balance(&s.client.address), 4_000);
+
+    let skipped = Symbol::new(&env, "rcl_skip");
+    let skips = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map_or(false, |t| Symbol::try_from_val(&env, &t).map_or(false, |s| s == skipped))
+        })
+        .count();
+    assert_eq!(skips, 1);
+
+    assert_eq!(
+        s.client.get_remaining_balance(&String::from_str(&env, "reclaim-a")),
+        0
+    );
+    assert_eq!(
+        s.client.get_remaining_balance(&String::from_str(&env, "reclaim-b")),
+        0
+    );
+    assert_eq!(
+        s.client.get_remaining_balance(&String::from_str(&env, "reclaim-c")),
+        4_000
+    );
+}
+
+#[test]
+fn test_unknown_program_is_skipped() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+
+    let ids = vec![
+        &env,
+        String::from_str(&env, "missing"),
+        String::from_str(&env, "reclaim-c"),
+    ];
+    assert_eq!(s.client.batch_reclaim(&s.stranger, &ids, &treasury, &reason(&env), &0), 4_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_batch_reclaim_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+
+    s.client
+        .batch_reclaim(&s.owner, &all_programs(&env), &treasury, &reason(&env), &0);
+    s.client
+        .batch_reclaim(&s.owner, &all_programs(&env), &treasury, &reason(&env), &0);
+}
+
+#[test]
+fn test_reclaim_keeps_pending_schedule_funded() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    let program_a = String::from_str(&env, "reclaim-a");
+    s.client
+        .create_program_release_schedule(&program_a, &600, &1_000, &Address::generate(&env));
+
+    let ids = vec![&env, program_a.clone()];
+    assert_eq!(s.client.batch_reclaim(&s.owner, &ids, &treasury, &reason(&env), &0), 400);
+    assert_eq!(s.client.get_remaining_balance(&program_a), 600);
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "treasury sweep")
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+//! # Per-Recipient Batch Threshold Tests — Program Escrow
+//!
+//! `batch_payout` must enforce `max_single_payout` against each entry, so a
+//! batch whose total is within limits is still rejected when one recipient
+//! would receive more than the single-payout cap.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, IntoVal, String, Symbol, Vec,
+};
+
+const MAX_SINGLE: i128 = 1_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "batch-threshold");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &100_000);
+    client.lock_program_funds(&program_id, &100_000);
+
+    env.as_contract(&client.address, || {
+        let mut config = threshold_monitor::ThresholdConfig::default();
+        config.max_single_payout = MAX_SINGLE;
+        threshold_monitor::init_threshold_monitor(env);
+        threshold_monitor::set_threshold_config(env, config).unwrap();
+    });
+
+    (client, program_id)
+}
+
+fn recipients(env: &Env, n: u32) -> Vec<Address> {
+    let mut out = Vec::new(env);
+    for _ in 0..n {
+        out.push_back(Address::generate(env));
+    }
+    out
+}
+
+#[test]
+fn test_batch_rejected_when_one_entry_exceeds_single_cap() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    // Total (1_700) is tiny next to the outflow threshold; one entry is not.
+    let amounts = vec![&env, 100_i128, 1_500, 100];
+    let res = client.try_batch_payout(&program_id, &recipients(&env, 3), &amounts);
+
+    assert!(res.is_err());
+    assert_eq!(client.get_remaining_balance(&program_id), 100_000);
+}
+
+#[test]
+fn test_batch_within_single_cap_succeeds() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let amounts = vec![&env, 900_i128, 900, 900];
+    let data = client.batch_payout(&program_id, &recipients(&env, 3), &amounts);
+
+    assert_eq!(data.remaining_balance, 100_000 - 2_700);
+}
+
+#[test]
+fn test_oversized_entry_reports_outflow_breach() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+    let amounts = vec![&env, 100_i128, 1_500, 100];
+
+    env.as_contract(&client.address, || {
+        let breach = threshold_monitor::check_batch_payout_amounts(&env, &amounts).unwrap_err();
+        assert_eq!(breach.metric_type, Symbol::new(&env, "outflow"));
+        assert_eq!(breach.threshold_value, MAX_SINGLE);
+        assert_eq!(breach.actual_value, 1_500);
+        threshold_monitor::emit_threshold_breach_event(&env, &breach);
+    });
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<soroban_sdk::Val> =
+        (Symbol::new(&env, "th_breach"), Symbol::new(&env, "outflow")).into_val(&env);
+    assert_eq!(topics, expected);
+}
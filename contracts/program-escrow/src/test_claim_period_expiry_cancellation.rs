@@ -165,7 +165,7 @@ fn test_claim_within_window_succeeds() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "ClaimExpired")]
+#[should_panic(expected = "Error(Contract, #27)")]
 fn test_claim_after_expiry_fails() {
     let t = setup();
     let env = &t.env;
@@ -303,7 +303,7 @@ fn test_admin_cancel_expired_claim_succeeds() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "Unauthorized")]
+#[should_panic(expected = "Error(Contract, #8)")]
 fn test_non_admin_cannot_cancel_claim() {
     let t = setup();
     let env = &t.env;
@@ -325,7 +325,7 @@ fn test_non_admin_cannot_cancel_claim() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "ClaimAlreadyProcessed")]
+#[should_panic(expected = "Error(Contract, #26)")]
 fn test_cannot_double_claim() {
     let t = setup();
     let env = &t.env;
@@ -349,7 +349,7 @@ fn test_cannot_double_claim() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "ClaimAlreadyProcessed")]
+#[should_panic(expected = "Error(Contract, #26)")]
 fn test_cannot_execute_cancelled_claim() {
     let t = setup();
     let env = &t.env;
@@ -372,7 +372,7 @@ fn test_cannot_execute_cancelled_claim() {
 // ═══════════════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "Unauthorized")]
+#[should_panic(expected = "Error(Contract, #8)")]
 fn test_wrong_recipient_cannot_execute_claim() {
     let t = setup();
     let env = &t.env;
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+//! # Payout Clawback Tests — Program Escrow
+//!
+//! With a clawback-enabled Stellar asset administered by the escrow, the
+//! admin can reclaim a recorded payout within `clawback_window_secs`. The
+//! clawed tokens are re-minted to the escrow so its balance stays backed.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, IssuerFlags, Ledger},
+    token, Address, Env, String,
+};
+
+const WINDOW: u64 = 3_600;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    recipient: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(token_admin);
+    sac.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let token_addr = sac.address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_clawback_window(&WINDOW, &0);
+
+    let program_id = String::from_str(env, "clawback-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token_sac.mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    // The escrow must administer the asset to authorize clawbacks itself.
+    token_sac.set_admin(&client.address);
+
+    let recipient = Address::generate(env);
+    client.single_payout(&program_id, &recipient, &1_000, &u64::MAX);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        recipient,
+    }
+}
+
+#[test]
+fn test_clawback_within_window_restores_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+
+    env.ledger().set_timestamp(10_000 + WINDOW);
+    let data = s.client.clawback_payout(&s.program_id, &0, &1);
+
+    assert_eq!(data.remaining_balance, 10_000);
+    assert_eq!(s.token.balance(&s.recipient), 0);
+    assert_eq!(s.token.balance(&s.client.address), data.remaining_balance);
+    assert_eq!(s.client.get_recipient_stats(&s.recipient), (0, 0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_clawback_after_window_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(10_000 + WINDOW + 1);
+    s.client.clawback_payout(&s.program_id, &0, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_payout_clawed_back_only_once() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.clawback_payout(&s.program_id, &0, &1);
+    s.client.clawback_payout(&s.program_id, &0, &2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+fn test_zero_window_disables_clawback() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_clawback_window(&0, &1);
+    s.client.clawback_payout(&s.program_id, &0, &2);
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+//! # Cooldown Clear Tests — Program Escrow
+//!
+//! A threshold breach applies a cooldown scaled by the backoff multiplier.
+//! `clear_cooldown` ends the cooldown and resets the multiplier, so the next
+//! breach uses the base period again.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const START: u64 = 1_000;
+
+fn setup(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+
+    env.as_contract(&client.address, || {
+        threshold_monitor::init_threshold_monitor(env);
+    });
+
+    client
+}
+
+/// Forces an outflow-volume breach through the breaker and closes the
+/// circuit again so the next breach can be observed.
+fn breach(env: &Env, client: &ProgramEscrowContractClient) {
+    env.as_contract(&client.address, || {
+        let config = threshold_monitor::get_threshold_config(env);
+        let mut metrics = threshold_monitor::get_current_metrics(env);
+        metrics.total_outflow = config.outflow_volume_threshold;
+        env.storage()
+            .persistent()
+            .set(&threshold_monitor::ThresholdKey::CurrentMetrics, &metrics);
+
+        assert_eq!(
+            error_recovery::check_and_allow_with_thresholds(env),
+            Err(threshold_monitor::ERR_THRESHOLD_BREACHED)
+        );
+        error_recovery::close_circuit(env);
+    });
+}
+
+/// Grows the backoff multiplier by one `cooldown_multiplier` step.
+fn back_off(env: &Env, client: &ProgramEscrowContractClient) {
+    env.as_contract(&client.address, || {
+        threshold_monitor::increase_cooldown_multiplier(env);
+    });
+}
+
+fn cooldown_state(env: &Env, client: &ProgramEscrowContractClient) -> (u64, u32) {
+    env.as_contract(&client.address, || {
+        (
+            threshold_monitor::get_cooldown_end(env),
+            threshold_monitor::get_cooldown_multiplier(env),
+        )
+    })
+}
+
+#[test]
+fn test_clear_cooldown_resets_backoff() {
+    let env = Env::default();
+    let client = setup(&env);
+    let base = client.get_threshold_config().cooldown_period_secs;
+
+    breach(&env, &client);
+    assert_eq!(cooldown_state(&env, &client), (START + base, 1));
+    back_off(&env, &client);
+    back_off(&env, &client);
+    breach(&env, &client);
+    assert_eq!(cooldown_state(&env, &client), (START + 4 * base, 4));
+
+    env.ledger().set_timestamp(START + 10);
+    client.clear_cooldown(&0);
+    assert_eq!(cooldown_state(&env, &client), (START + 10, 1));
+
+    breach(&env, &client);
+    assert_eq!(cooldown_state(&env, &client), (START + 10 + base, 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_clear_cooldown_rejects_replayed_nonce() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    client.clear_cooldown(&0);
+    client.clear_cooldown(&0);
+}
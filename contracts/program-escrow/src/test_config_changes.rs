@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+//! # Config Change Log Tests — Program Escrow
+//!
+//! Every contract-level setter bumps `config_version` and appends a
+//! `ConfigChange` to a bounded log.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, Symbol,
+};
+
+fn setup(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+    client
+}
+
+#[test]
+fn test_two_changes_yield_version_two() {
+    let env = Env::default();
+    let client = setup(&env);
+    assert_eq!(client.get_config_version(), 0);
+
+    env.ledger().set_timestamp(100);
+    client.set_reclaim_delay(&3_600, &0);
+    env.ledger().set_timestamp(200);
+    client.set_recipient_cooldown(&60, &1);
+
+    assert_eq!(client.get_config_version(), 2);
+    let changes = client.get_config_changes(&0, &10);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes.get(0).unwrap(),
+        ConfigChange {
+            version: 1,
+            field: Symbol::new(&env, "rcl_delay"),
+            timestamp: 100,
+        }
+    );
+    assert_eq!(
+        changes.get(1).unwrap(),
+        ConfigChange {
+            version: 2,
+            field: Symbol::new(&env, "rcpt_cd"),
+            timestamp: 200,
+        }
+    );
+    assert_eq!(client.get_config_changes(&1, &10).len(), 1);
+}
+
+#[test]
+fn test_log_is_bounded() {
+    let env = Env::default();
+    let client = setup(&env);
+
+    for nonce in 0..(MAX_CONFIG_CHANGES as u64 + 5) {
+        client.set_reclaim_delay(&nonce, &nonce);
+    }
+
+    let changes = client.get_config_changes(&0, &u32::MAX);
+    assert_eq!(changes.len(), MAX_CONFIG_CHANGES);
+    assert_eq!(changes.get(0).unwrap().version, 6);
+    assert_eq!(client.get_config_version(), MAX_CONFIG_CHANGES as u64 + 5);
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+//! # Config Export / Import Tests — Program Escrow
+//!
+//! `export_config` on one deployment followed by `import_config` on a fresh
+//! one reproduces the same contract-wide configuration.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn deploy(env: &Env) -> ProgramEscrowContractClient<'static> {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    ProgramEscrowContractClient::new(env, &contract_id)
+}
+
+fn custom_thresholds() -> threshold_monitor::ThresholdConfig {
+    threshold_monitor::ThresholdConfig {
+        failure_rate_threshold: 7,
+        outflow_volume_threshold: 50_000,
+        max_single_payout: 9_000,
+        time_window_secs: 1_200,
+        cooldown_period_secs: 90,
+        cooldown_multiplier: 3,
+        warn_ratio_bps: 0,
+        window_max_single_outflow: 0,
+    }
+}
+
+#[test]
+fn test_export_import_round_trip() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let source = deploy(&env);
+    source.initialize_contract_with_options(&admin, &true);
+    source.set_threshold_config(&custom_thresholds(), &0);
+    source.set_daily_outflow_limit(&25_000, &1);
+    source.update_rate_limit_config(&600, &50, &5);
+    source.set_max_batch_size(&20, &2);
+    source.set_event_level(&EVENT_LEVEL_VERBOSE, &3);
+    source.set_auth_mode(&PayoutAuthMode::ScopedArgs, &4);
+    source.set_rounding_mode(&RoundingMode::Ceil, &5);
+    let token = Address::generate(&env);
+    source.set_threshold_config_for(&token, &custom_thresholds(), &6);
+    let signer = Address::generate(&env);
+    source.set_rate_limit(&signer, &120, &7);
+
+    let target = deploy(&env);
+    target.initialize_contract(&admin);
+    let exported = source.export_config();
+    target.import_config(&exported, &0);
+
+    assert_eq!(target.export_config(), exported);
+    assert_eq!(target.get_threshold_config(), custom_thresholds());
+    assert_eq!(target.get_daily_outflow_limit(), 25_000);
+    assert_eq!(target.get_rate_limit_config(), source.get_rate_limit_config());
+    assert_eq!(target.get_fee_config(), source.get_fee_config());
+    assert_eq!(target.get_max_batch_size(), 20);
+    assert_eq!(target.get_event_level(), EVENT_LEVEL_VERBOSE);
+    assert_eq!(target.get_auth_mode(), PayoutAuthMode::ScopedArgs);
+    assert_eq!(target.get_rounding_mode(), RoundingMode::Ceil);
+    assert_eq!(
+        target.get_threshold_config_for(&token),
+        source.get_threshold_config_for(&token)
+    );
+    assert_eq!(target.get_rate_limit(&signer).unwrap().min_interval_secs, 120);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_import_rejected_after_first_payout() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let source = deploy(&env);
+    source.initialize_contract(&admin);
+
+    let target = deploy(&env);
+    target.initialize_contract(&admin);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let program_id = String::from_str(&env, "live");
+    target.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&target.address, &1_000);
+    target.lock_program_funds(&program_id, &1_000);
+    target.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    target.import_config(&source.export_config(), &0);
+}
+
+#[test]
+fn test_import_carries_pause_and_new_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+
+    let source = deploy(&env);
+    source.initialize_contract(&new_admin);
+    source.pause(&new_admin, &String::from_str(&env, "migrating"));
+
+    let target = deploy(&env);
+    target.initialize_contract(&admin);
+    let exported = source.export_config();
+    assert!(exported.is_paused);
+    assert_eq!(exported.admin, new_admin);
+
+    target.import_config(&exported, &0);
+    assert!(target.is_paused());
+    assert_eq!(target.get_pause_reason(), String::from_str(&env, "migrating"));
+    assert_eq!(target.export_config().admin, new_admin);
+}
@@ -0,0 +1,52 @@
+#![cfg(test)]
+
+//! # Cooldown Countdown Tests — Program Escrow
+//!
+//! `cooldown_remaining` counts down the seconds until a threshold cooldown
+//! ends, reaching 0 once it has expired.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+const START: u64 = 1_000;
+
+fn setup(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+    client
+}
+
+#[test]
+fn test_no_cooldown_reports_zero() {
+    let env = Env::default();
+    let client = setup(&env);
+    assert_eq!(client.cooldown_remaining(), 0);
+}
+
+#[test]
+fn test_countdown_decreases_until_expired() {
+    let env = Env::default();
+    let client = setup(&env);
+    env.as_contract(&client.address, || {
+        threshold_monitor::apply_cooldown(&env);
+    });
+    let period = threshold_monitor::ThresholdConfig::default().cooldown_period_secs;
+
+    assert_eq!(client.cooldown_remaining(), period);
+
+    env.ledger().set_timestamp(START + 100);
+    assert_eq!(client.cooldown_remaining(), period - 100);
+
+    env.ledger().set_timestamp(START + period);
+    assert_eq!(client.cooldown_remaining(), 0);
+
+    env.ledger().set_timestamp(START + period * 2);
+    assert_eq!(client.cooldown_remaining(), 0);
+}
@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+//! # Daily Outflow Limit Tests — Program Escrow
+//!
+//! Verifies the calendar-day cap that sits alongside the sliding-window
+//! threshold: payouts are rejected once the UTC day's allowance is used up,
+//! and the counter resets at the `timestamp / 86400` boundary.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+const DAY: u64 = 86_400;
+
+fn setup(env: &Env, initial_balance: i128) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "daily-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_daily_limit_defaults_to_unlimited() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env, 1_000);
+
+    assert_eq!(client.get_daily_outflow_limit(), 0);
+    assert_eq!(client.get_daily_remaining(), i128::MAX);
+}
+
+#[test]
+fn test_daily_remaining_decreases_with_payouts() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10 * DAY + 100);
+    let (client, program_id) = setup(&env, 10_000);
+    client.set_daily_outflow_limit(&1_000, &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &300, &u64::MAX);
+    assert_eq!(client.get_daily_remaining(), 700);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 200i128, 100i128];
+    client.batch_payout(&program_id, &recipients, &amounts);
+    assert_eq!(client.get_daily_remaining(), 400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_single_payout_over_daily_limit_rejected() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10 * DAY);
+    let (client, program_id) = setup(&env, 10_000);
+    client.set_daily_outflow_limit(&1_000, &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &800, &u64::MAX);
+    client.single_payout(&program_id, &Address::generate(&env), &201, &u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_batch_payout_over_daily_limit_rejected() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10 * DAY);
+    let (client, program_id) = setup(&env, 10_000);
+    client.set_daily_outflow_limit(&1_000, &0);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 600i128, 401i128];
+    client.batch_payout(&program_id, &recipients, &amounts);
+}
+
+#[test]
+fn test_daily_counter_resets_at_day_boundary() {
+    let env = Env::default();
+    env.ledger().set_timestamp(10 * DAY + DAY - 60);
+    let (client, program_id) = setup(&env, 10_000);
+    client.set_daily_outflow_limit(&1_000, &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    assert_eq!(client.get_daily_remaining(), 0);
+
+    // Cross into the next UTC day: the full allowance is available again.
+    env.ledger().set_timestamp(11 * DAY);
+    assert_eq!(client.get_daily_remaining(), 1_000);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    assert_eq!(client.get_daily_remaining(), 0);
+    assert_eq!(client.get_remaining_balance(&program_id), 8_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_negative_daily_limit_rejected() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env, 1_000);
+    client.set_daily_outflow_limit(&-1, &0);
+}
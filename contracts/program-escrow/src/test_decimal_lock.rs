@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+//! # Decimals-Aware Locking Tests — Program Escrow
+//!
+//! Token decimals are recorded at init, and `lock_program_funds_decimal`
+//! composes raw amounts from `(whole, fractional)` human units.
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, token, Address, Env, String};
+
+/// Minimal 6-decimal token; transfers are accepted without bookkeeping.
+#[contract]
+struct SixDecimalToken;
+
+#[contractimpl]
+impl SixDecimalToken {
+    pub fn decimals(_env: Env) -> u32 {
+        6
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {}
+}
+
+fn register(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+    client
+}
+
+#[test]
+fn test_seven_decimal_asset_composes_raw_amount() {
+    let env = Env::default();
+    let client = register(&env);
+
+    let token_admin = Address::generate(&env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let depositor = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&depositor, &100_000_000);
+
+    let program_id = String::from_str(&env, "seven");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &depositor, &None, &None);
+    assert_eq!(client.get_token_decimals(&program_id), Some(7));
+
+    let data = client.lock_program_funds_decimal(&program_id, &3, &5_000_000, &depositor);
+
+    assert_eq!(data.total_funds, 35_000_000);
+    assert_eq!(token::Client::new(&env, &token_addr).balance(&depositor), 65_000_000);
+}
+
+#[test]
+fn test_six_decimal_asset_composes_raw_amount() {
+    let env = Env::default();
+    let client = register(&env);
+    let token_addr = env.register_contract(None, SixDecimalToken);
+
+    let program_id = String::from_str(&env, "six");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &Address::generate(&env), &None, &None);
+    assert_eq!(client.get_token_decimals(&program_id), Some(6));
+
+    let data =
+        client.lock_program_funds_decimal(&program_id, &12, &345_000, &Address::generate(&env));
+
+    assert_eq!(data.total_funds, 12_345_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_fraction_wider_than_decimals_rejected() {
+    let env = Env::default();
+    let client = register(&env);
+    let token_addr = env.register_contract(None, SixDecimalToken);
+
+    let program_id = String::from_str(&env, "six");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &Address::generate(&env), &None, &None);
+
+    client.lock_program_funds_decimal(&program_id, &1, &1_000_000, &Address::generate(&env));
+}
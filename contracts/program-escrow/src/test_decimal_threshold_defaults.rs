@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+//! # Decimals-Aware Threshold Default Tests — Program Escrow
+//!
+//! Without an explicit `ThresholdConfig`, the fallback limits are scaled to
+//! the decimals of the first program's token instead of assuming 7.
+
+use super::*;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+/// Minimal 6-decimal token; only `decimals` is needed at init.
+#[contract]
+struct SixDecimalToken;
+
+#[contractimpl]
+impl SixDecimalToken {
+    pub fn decimals(_env: Env) -> u32 {
+        6
+    }
+}
+
+#[test]
+fn test_defaults_scale_with_decimals() {
+    let six = threshold_monitor::ThresholdConfig::default_for_decimals(6);
+    let seven = threshold_monitor::ThresholdConfig::default_for_decimals(7);
+
+    assert_eq!(six.outflow_volume_threshold, 5_000_000_000000);
+    assert_eq!(six.max_single_payout, 500_000_000000);
+    assert_eq!(seven.outflow_volume_threshold, six.outflow_volume_threshold * 10);
+    assert_eq!(seven.max_single_payout, six.max_single_payout * 10);
+    assert_eq!(six.time_window_secs, seven.time_window_secs);
+    assert_eq!(six.cooldown_period_secs, seven.cooldown_period_secs);
+    assert_eq!(seven, threshold_monitor::ThresholdConfig::default());
+}
+
+#[test]
+fn test_fallback_uses_first_program_token_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    client.initialize_contract(&Address::generate(&env));
+
+    let six_token = env.register_contract(None, SixDecimalToken);
+    let owner = Address::generate(&env);
+    client.init_program(
+        &String::from_str(&env, "usdc"),
+        &Address::generate(&env),
+        &six_token,
+        &owner,
+        &None,
+        &None,
+    );
+    let expected = threshold_monitor::ThresholdConfig::default_for_decimals(6);
+    assert_eq!(client.get_threshold_config(), expected);
+
+    // A later 7-decimal program does not shift the live defaults
+    let sac = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    client.init_program(
+        &String::from_str(&env, "xlm"),
+        &Address::generate(&env),
+        &sac,
+        &owner,
+        &None,
+        &None,
+    );
+    assert_eq!(client.get_threshold_config(), expected);
+}
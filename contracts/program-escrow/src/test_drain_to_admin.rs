@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+//! # Drain Tests — Program Escrow
+//!
+//! `drain_to_admin` returns a paused program's whole remaining balance to
+//! its owner and refuses to run while the contract is live.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    owner: Address,
+}
+
+fn setup(env: &Env, initial_balance: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let owner = Address::generate(env);
+    let program_id = String::from_str(env, "drain-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &owner, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        owner,
+    }
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_drain_fails_while_unpaused() {
+    let env = Env::default();
+    let s = setup(&env, 5_000);
+
+    s.client.drain_to_admin(&s.program_id, &reason(&env), &0);
+}
+
+#[test]
+fn test_drain_succeeds_while_paused() {
+    let env = Env::default();
+    let s = setup(&env, 5_000);
+    s.client.pause(&s.client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+
+    let drained = s.client.drain_to_admin(&s.program_id, &reason(&env), &0);
+
+    assert_eq!(drained, 5_000);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+    assert_eq!(s.token.balance(&s.owner), 5_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_drain_only_moves_target_program_balance() {
+    let env = Env::default();
+    let s = setup(&env, 5_000);
+
+    let other = String::from_str(&env, "other-prog");
+    s.client.init_program(
+        &other,
+        &Address::generate(&env),
+        &s.token.address,
+        &Address::generate(&env),
+        &None,
+        &None,
+    );
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&s.client.address, &2_000);
+    s.client.lock_program_funds(&other, &2_000);
+
+    s.client.pause(&s.client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+    s.client.drain_to_admin(&s.program_id, &reason(&env), &0);
+
+    assert_eq!(s.client.get_remaining_balance(&other), 2_000);
+    assert_eq!(s.token.balance(&s.client.address), 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_drain_rejects_stale_nonce() {
+    let env = Env::default();
+    let s = setup(&env, 5_000);
+    s.client.pause(&s.client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+
+    s.client.drain_to_admin(&s.program_id, &reason(&env), &1);
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "program wind-down")
+}
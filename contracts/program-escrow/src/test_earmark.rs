@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+//! # Earmarked Deposit Tests — Program Escrow
+//!
+//! `lock_earmarked` funds a program for one named recipient. Payouts to
+//! that recipient spend their earmark first; nobody else can be paid out
+//! of it, and no reclaim, sweep or reservation can take it.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    alice: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "earmarked");
+    client.init_program(
+        &program_id,
+        &Address::generate(env),
+        &token_addr,
+        &admin,
+        &None,
+        &None,
+    );
+    token_sac.mint(&client.address, &1_000);
+    client.lock_program_funds(&program_id, &1_000);
+
+    let donor = Address::generate(env);
+    let alice = Address::generate(env);
+    token_sac.mint(&donor, &500);
+    client.lock_earmarked(&program_id, &donor, &alice, &500);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        alice,
+    }
+}
+
+#[test]
+fn test_earmarked_funds_not_payable_to_others() {
+    let env = Env::default();
+    let s = setup(&env);
+    let bob = Address::generate(&env);
+
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 1_500);
+    assert_eq!(s.client.get_earmark(&s.program_id, &s.alice), 500);
+    assert_eq!(
+        s.client
+            .try_single_payout(&s.program_id, &bob, &1_001, &u64::MAX),
+        Err(Ok(EscrowError::InsufficientBalance))
+    );
+    assert_eq!(
+        s.client.try_batch_payout(
+            &s.program_id,
+            &vec![&env, bob.clone(), Address::generate(&env)],
+            &vec![&env, 1_000_i128, 1_i128],
+        ),
+        Err(Ok(EscrowError::InsufficientBalance))
+    );
+
+    s.client
+        .single_payout(&s.program_id, &bob, &1_000, &u64::MAX);
+    assert_eq!(s.token.balance(&bob), 1_000);
+
+    s.client
+        .single_payout(&s.program_id, &s.alice, &500, &u64::MAX);
+    assert_eq!(s.token.balance(&s.alice), 500);
+    assert_eq!(s.client.get_earmark(&s.program_id, &s.alice), 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+}
+
+#[test]
+fn test_payout_draws_from_earmark_first() {
+    let env = Env::default();
+    let s = setup(&env);
+    let bob = Address::generate(&env);
+
+    s.client
+        .single_payout(&s.program_id, &s.alice, &700, &u64::MAX);
+    assert_eq!(s.client.get_earmark(&s.program_id, &s.alice), 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 800);
+
+    s.client.single_payout(&s.program_id, &bob, &800, &u64::MAX);
+    assert_eq!(s.token.balance(&bob), 800);
+}
+
+#[test]
+fn test_max_payable_now_excludes_earmarks() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.max_payable_now(&s.program_id), 1_000);
+}
+
+#[test]
+fn test_reclaim_leaves_earmark_for_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    let owner_wallet = Address::generate(&env);
+
+    let reclaimed = s.client.reclaim_split(
+        &s.program_id,
+        &vec![&env, owner_wallet.clone()],
+        &vec![&env, 10_000_u32],
+        &0,
+    );
+    assert_eq!(reclaimed, 1_000);
+    assert_eq!(s.token.balance(&owner_wallet), 1_000);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 500);
+
+    s.client
+        .single_payout(&s.program_id, &s.alice, &500, &u64::MAX);
+    assert_eq!(s.token.balance(&s.alice), 500);
+}
+
+#[test]
+fn test_withdraw_excess_cannot_take_earmark() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client.try_withdraw_excess(
+            &s.program_id,
+            &1_001,
+            &Address::generate(&env),
+            &String::from_str(&env, "over-locked"),
+            &0,
+        ),
+        Err(Ok(EscrowError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_stream_to_other_recipient_cannot_reserve_earmark() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    // 1_100 over 100 seconds: more than the 1_000 nobody has claim to
+    assert_eq!(
+        s.client.try_create_stream(
+            &s.program_id,
+            &Address::generate(&env),
+            &11,
+            &1_000,
+            &1_100,
+            &0,
+        ),
+        Err(Ok(EscrowError::InsufficientBalance))
+    );
+    s.client
+        .create_stream(&s.program_id, &s.alice, &11, &1_000, &1_100, &0);
+    assert_eq!(s.client.get_earmark(&s.program_id, &s.alice), 0);
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+//! # Empty Batch Tests — Program Escrow
+//!
+//! An empty `batch_payout` is rejected with `EmptyBatch` before any
+//! authorization runs, so it never burns the payout key's nonce.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String, Vec};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "empty-batch");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id, payout_key)
+}
+
+#[test]
+fn test_empty_batch_rejected_without_consuming_nonce() {
+    let env = Env::default();
+    let (client, program_id, payout_key) = setup(&env);
+
+    let result = client.try_batch_payout(&program_id, &Vec::new(&env), &Vec::new(&env));
+    assert_eq!(result, Err(Ok(EscrowError::EmptyBatch)));
+    assert_eq!(client.get_nonce(&payout_key), 0);
+
+    // The untouched nonce is still usable by the next real batch
+    client.batch_payout(&program_id, &vec![&env, Address::generate(&env)], &vec![&env, 100_i128]);
+    assert_eq!(client.get_nonce(&payout_key), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_empty_batch_panics_with_empty_batch_error() {
+    let env = Env::default();
+    let (client, program_id, _) = setup(&env);
+
+    client.batch_payout(&program_id, &Vec::new(&env), &Vec::new(&env));
+}
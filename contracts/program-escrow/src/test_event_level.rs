@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+//! # Event Verbosity Tests — Program Escrow
+//!
+//! `set_event_level` gates payout-path events: minimal drops them, normal
+//! keeps the per-call events, verbose adds per-recipient payouts and nonce
+//! changes. Pause events fire at every level.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String, Symbol, TryFromVal, Vec,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "events-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+/// Number of events from the last invocation whose first topic is `name`.
+fn count_topic(env: &Env, name: &str) -> u32 {
+    let wanted = Symbol::new(env, name);
+    let mut count = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        if let Some(first) = topics.get(0) {
+            if Symbol::try_from_val(env, &first).map_or(false, |s| s == wanted) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn batch_of_two(env: &Env) -> (Vec<Address>, Vec<i128>) {
+    (
+        vec![env, Address::generate(env), Address::generate(env)],
+        vec![env, 100_i128, 200],
+    )
+}
+
+#[test]
+fn test_default_level_is_normal() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    assert_eq!(client.get_event_level(), EVENT_LEVEL_NORMAL);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(count_topic(&env, "Payout"), 1);
+
+    let (recipients, amounts) = batch_of_two(&env);
+    client.batch_payout(&program_id, &recipients, &amounts);
+    assert_eq!(count_topic(&env, "BatchPay"), 1);
+    assert_eq!(count_topic(&env, "Payout"), 0);
+}
+
+#[test]
+fn test_minimal_level_suppresses_payout_events() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_event_level(&EVENT_LEVEL_MINIMAL, &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(count_topic(&env, "Payout"), 0);
+
+    let (recipients, amounts) = batch_of_two(&env);
+    client.batch_payout(&program_id, &recipients, &amounts);
+    assert_eq!(count_topic(&env, "BatchPay"), 0);
+
+    // Pause events are never gated.
+    client.pause(&client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+    assert_eq!(count_topic(&env, "pause"), 1);
+}
+
+#[test]
+fn test_verbose_level_emits_every_payout_and_nonce() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.set_event_level(&EVENT_LEVEL_VERBOSE, &0);
+    assert_eq!(client.get_event_level(), EVENT_LEVEL_VERBOSE);
+
+    let (recipients, amounts) = batch_of_two(&env);
+    client.batch_payout(&program_id, &recipients, &amounts);
+    assert_eq!(count_topic(&env, "BatchPay"), 1);
+    assert_eq!(count_topic(&env, "Payout"), 2);
+
+    client.set_event_level(&EVENT_LEVEL_VERBOSE, &1);
+    assert_eq!(count_topic(&env, "nonce"), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_unknown_event_level_rejected() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    client.set_event_level(&3, &0);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Failure-Rate Counter Tests — Program Escrow
+//!
+//! Successful payouts increment the threshold monitor's `success_count`, and
+//! `get_failure_rate` reports `(failures, total)` for the current window.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "rate-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.as_contract(&client.address, || {
+        threshold_monitor::init_threshold_monitor(env);
+    });
+
+    (client, program_id)
+}
+
+#[test]
+fn test_successful_payouts_raise_success_count() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    assert_eq!(client.get_failure_rate(), (0, 0));
+
+    for _ in 0..3 {
+        client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    }
+    client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 100_i128, 100],
+    );
+
+    assert_eq!(client.get_failure_rate(), (0, 4));
+    env.as_contract(&client.address, || {
+        assert_eq!(threshold_monitor::get_current_metrics(&env).success_count, 4);
+        assert_eq!(
+            error_recovery::get_state(&env),
+            error_recovery::CircuitState::Closed
+        );
+    });
+}
+
+#[test]
+fn test_failures_counted_in_total() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    env.as_contract(&client.address, || {
+        threshold_monitor::record_operation_failure(&env);
+    });
+
+    assert_eq!(client.get_failure_rate(), (1, 2));
+}
+
+#[test]
+fn test_failure_rate_resets_after_window() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(client.get_failure_rate(), (0, 1));
+
+    let window = env.as_contract(&client.address, || {
+        threshold_monitor::get_threshold_config(&env).time_window_secs
+    });
+    env.ledger().set_timestamp(1_000 + window);
+
+    assert_eq!(client.get_failure_rate(), (0, 0));
+}
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+//! # Finalization Tests — Program Escrow
+//!
+//! `finalize_program` freezes governance setters and key rotation for good,
+//! while payouts keep flowing.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, Symbol, TryFromVal,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "final-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup { client, program_id }
+}
+
+#[test]
+fn test_finalize_emits_event_and_sets_flag() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(!s.client.is_finalized());
+
+    s.client.finalize_program(&0);
+
+    assert!(s.client.is_finalized());
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(topic, Symbol::new(&env, "Finalized"));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_set_threshold_config_fails_after_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.finalize_program(&0);
+
+    s.client
+        .set_threshold_config(&threshold_monitor::ThresholdConfig::default(), &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_rotate_payout_key_fails_after_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.finalize_program(&0);
+
+    s.client
+        .rotate_payout_key(&s.program_id, &Address::generate(&env), &1);
+}
+
+#[test]
+fn test_single_payout_still_works_after_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.finalize_program(&0);
+
+    let data = s
+        .client
+        .single_payout(&s.program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    assert_eq!(data.remaining_balance, 9_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_finalize_is_one_way() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.finalize_program(&0);
+    s.client.finalize_program(&1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_transfer_ownership_fails_after_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.finalize_program(&0);
+
+    s.client
+        .transfer_ownership(&s.program_id, &Address::generate(&env), &1);
+}
@@ -0,0 +1,164 @@
+#![cfg(test)]
+
+//! # Cross-Escrow Forwarding Tests — Program Escrow
+//!
+//! Two deployments of the contract: `forward_to_escrow` moves funds from a
+//! parent program into a sub-program on the other deployment, which
+//! accounts for them through its own `lock_program_funds`. The parent treats
+//! the forward as an outflow subject to its pause and limits.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    parent: ProgramEscrowContractClient<'static>,
+    child: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    token_addr: Address,
+    parent_program: String,
+    child_program: String,
+}
+
+fn deploy(env: &Env) -> ProgramEscrowContractClient<'static> {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+    client
+}
+
+fn setup(env: &Env) -> Setup {
+    // The child's payout key authorizes its `lock_program_funds` as a
+    // sub-invocation of the parent's call.
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let parent = deploy(env);
+    let child = deploy(env);
+
+    let parent_program = String::from_str(env, "grant-round");
+    parent.init_program(&parent_program, &Address::generate(env), &token_addr, &Address::generate(env), &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&parent.address, &10_000);
+    parent.lock_program_funds(&parent_program, &10_000);
+
+    let child_program = String::from_str(env, "sub-grant");
+    child.init_program(&child_program, &Address::generate(env), &token_addr, &Address::generate(env), &None, &None);
+
+    Setup {
+        parent,
+        child,
+        token: token::Client::new(env, &token_addr),
+        token_addr,
+        parent_program,
+        child_program,
+    }
+}
+
+#[test]
+fn test_forward_credits_downstream_escrow() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let data = s.parent.forward_to_escrow(
+        &s.parent_program,
+        &s.child.address,
+        &s.child_program,
+        &4_000,
+        &0,
+    );
+
+    assert_eq!(data.remaining_balance, 6_000);
+    assert_eq!(s.child.get_remaining_balance(&s.child_program), 4_000);
+    assert_eq!(s.token.balance(&s.parent.address), 6_000);
+    assert_eq!(s.token.balance(&s.child.address), 4_000);
+}
+
+#[test]
+fn test_forward_to_missing_program_reverts() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let result = s.parent.try_forward_to_escrow(
+        &s.parent_program,
+        &s.child.address,
+        &String::from_str(&env, "not-there"),
+        &4_000,
+        &0,
+    );
+
+    assert!(result.is_err());
+    assert_eq!(s.parent.get_remaining_balance(&s.parent_program), 10_000);
+    assert_eq!(s.token.balance(&s.parent.address), 10_000);
+    assert_eq!(s.token.balance(&s.child.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_forward_to_program_with_other_token_reverts() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    assert_ne!(other_token, s.token_addr);
+    let other_program = String::from_str(&env, "other-token");
+    s.child.init_program(&other_program, &Address::generate(&env), &other_token, &Address::generate(&env), &None, &None);
+
+    s.parent
+        .forward_to_escrow(&s.parent_program, &s.child.address, &other_program, &1_000, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_forward_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.parent
+        .forward_to_escrow(&s.parent_program, &s.child.address, &s.child_program, &1_000, &0);
+    s.parent
+        .forward_to_escrow(&s.parent_program, &s.child.address, &s.child_program, &1_000, &0);
+}
+
+#[test]
+fn test_forward_blocked_while_paused() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.parent
+        .pause(&s.parent.get_admin().unwrap(), &String::from_str(&env, "incident"));
+    assert_eq!(
+        s.parent.try_forward_to_escrow(
+            &s.parent_program,
+            &s.child.address,
+            &s.child_program,
+            &1_000,
+            &0,
+        ),
+        Err(Ok(EscrowError::FundsPaused))
+    );
+    assert_eq!(s.token.balance(&s.parent.address), 10_000);
+}
+
+#[test]
+fn test_forward_counts_against_daily_limit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.parent.set_daily_outflow_limit(&1_500, &0);
+    s.parent
+        .forward_to_escrow(&s.parent_program, &s.child.address, &s.child_program, &1_000, &0);
+    assert_eq!(
+        s.parent.try_forward_to_escrow(
+            &s.parent_program,
+            &s.child.address,
+            &s.child_program,
+            &1_000,
+            &1,
+        ),
+        Err(Ok(EscrowError::DailyLimitExceeded))
+    );
+}
@@ -0,0 +1,55 @@
+#![cfg(test)]
+
+//! # Funding Freeze Tests — Program Escrow
+//!
+//! With `freeze_funding_on_breach` set, `lock_program_funds` is refused
+//! while the circuit breaker is open and allowed again once it closes.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(env: &Env, freeze: bool) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_freeze_funding_on_breach(&freeze, &0);
+
+    let program_id = String::from_str(env, "freeze");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_funding_rejected_while_breaker_open() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, true);
+
+    env.as_contract(&client.address, || error_recovery::open_circuit(&env));
+    assert_eq!(
+        client.try_lock_program_funds(&program_id, &1_000),
+        Err(Ok(EscrowError::CircuitBreakerOpen))
+    );
+
+    env.as_contract(&client.address, || error_recovery::close_circuit(&env));
+    let data = client.lock_program_funds(&program_id, &1_000);
+    assert_eq!(data.remaining_balance, 1_000);
+}
+
+#[test]
+fn test_funding_unaffected_when_flag_off() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, false);
+
+    env.as_contract(&client.address, || error_recovery::open_circuit(&env));
+    let data = client.lock_program_funds(&program_id, &1_000);
+    assert_eq!(data.remaining_balance, 1_000);
+}
@@ -0,0 +1,43 @@
+#![cfg(test)]
+
+//! # Fund-And-Pay Tests — Program Escrow
+//!
+//! `fund_and_pay` deposits exactly the payout amount and pays it out in the
+//! same call, leaving the program's `remaining_balance` unchanged.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+#[test]
+fn test_recipient_paid_and_balance_unchanged() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token = token::Client::new(&env, &token_addr);
+    let token_sac = token::StellarAssetClient::new(&env, &token_addr);
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(&env, "fund-pay");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token_sac.mint(&client.address, &2_000);
+    client.lock_program_funds(&program_id, &2_000);
+
+    let depositor = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    token_sac.mint(&depositor, &750);
+
+    let data = client.fund_and_pay(&program_id, &depositor, &recipient, &750, &0);
+
+    assert_eq!(data.remaining_balance, 2_000);
+    assert_eq!(data.total_funds, 2_750);
+    assert_eq!(token.balance(&recipient), 750);
+    assert_eq!(token.balance(&depositor), 0);
+    assert_eq!(token.balance(&client.address), 2_000);
+}
@@ -0,0 +1,73 @@
+#![cfg(test)]
+
+//! # Payout Index Accessor Tests — Program Escrow
+//!
+//! `get_payout_at` resolves a single payout by its absolute index, which
+//! survives `prune_history`.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String, std::vec::Vec<Address>) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "indexed");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let mut recipients = std::vec::Vec::new();
+    for amount in [100_i128, 200, 300] {
+        let recipient = Address::generate(env);
+        client.single_payout(&program_id, &recipient, &amount, &u64::MAX);
+        recipients.push(recipient);
+    }
+
+    (client, program_id, recipients)
+}
+
+#[test]
+fn test_get_payout_at_known_index() {
+    let env = Env::default();
+    let (client, program_id, recipients) = setup(&env);
+
+    let record = client.get_payout_at(&program_id, &1);
+    assert_eq!(record.recipient, recipients[1]);
+    assert_eq!(record.amount, 200);
+}
+
+#[test]
+fn test_index_is_stable_after_pruning() {
+    let env = Env::default();
+    let (client, program_id, recipients) = setup(&env);
+
+    client.set_retention_count(&program_id, &1, &0);
+    client.prune_history(&program_id, &1);
+
+    assert_eq!(client.get_payout_at(&program_id, &2).recipient, recipients[2]);
+    assert_eq!(
+        client.try_get_payout_at(&program_id, &0),
+        Err(Ok(EscrowError::NotFound))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_get_payout_at_out_of_range_panics() {
+    let env = Env::default();
+    let (client, program_id, _recipients) = setup(&env);
+
+    client.get_payout_at(&program_id, &3);
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! # Guardian Tests — Program Escrow
+//!
+//! The guardian is an incident-response key: it may `pause` and
+//! `force_open_breaker`, but only the admin can unpause and it has no
+//! payout rights.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    guardian: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    let guardian = Address::generate(env);
+    client.set_guardian(&Some(guardian.clone()), &0);
+
+    let program_id = String::from_str(env, "guarded");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        guardian,
+        program_id,
+    }
+}
+
+#[test]
+fn test_guardian_can_pause_and_open_breaker() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.client.get_guardian(), Some(s.guardian.clone()));
+    let reason = String::from_str(&env, "incident");
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.guardian,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "pause",
+                args: (s.guardian.clone(), reason.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .pause(&s.guardian, &reason);
+    assert!(s.client.is_paused());
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.guardian,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "force_open_breaker",
+                args: (s.guardian.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .force_open_breaker(&s.guardian);
+    assert!(s.client.program_health_check(&s.program_id).breaker_open);
+}
+
+#[test]
+fn test_guardian_cannot_unpause() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.pause(&s.guardian, &String::from_str(&env, "incident"));
+
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.guardian,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "unpause",
+                args: Vec::<Val>::new(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_unpause();
+
+    assert!(result.is_err());
+    assert!(s.client.is_paused());
+}
+
+#[test]
+fn test_guardian_cannot_pay_out() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let result = s
+        .client
+        .mock_auths(&[MockAuth {
+            address: &s.guardian,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "single_payout",
+                args: (s.program_id.clone(), recipient.clone(), 100_i128, u64::MAX).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .try_single_payout(&s.program_id, &recipient, &100, &u64::MAX);
+
+    assert!(result.is_err());
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
+
+#[test]
+fn test_stranger_cannot_pause() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(
+        s.client
+            .try_pause(&Address::generate(&env), &String::from_str(&env, "incident")),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+    assert!(!s.client.is_paused());
+}
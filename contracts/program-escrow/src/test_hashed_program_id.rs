@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+//! # Hashed Program Identifier Tests — Program Escrow
+//!
+//! Programs created through `init_program_hashed` are keyed by a
+//! `BytesN<32>` id; the String id is kept for display and for the
+//! existing String-keyed entrypoints.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, BytesN, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token_addr: Address,
+    token_sac: token::StellarAssetClient<'static>,
+    admin: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    Setup {
+        client,
+        token_addr,
+        token_sac,
+        admin,
+    }
+}
+
+fn hash_of(env: &Env, name: &str) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&soroban_sdk::Bytes::from_slice(env, name.as_bytes()))
+        .into()
+}
+
+#[test]
+fn test_hashed_programs_are_isolated() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hash_a = hash_of(&env, "alpha");
+    let hash_b = hash_of(&env, "beta");
+    let name_a = String::from_str(&env, "Alpha Hackathon");
+    let name_b = String::from_str(&env, "Beta Hackathon");
+
+    s.client.init_program_hashed(
+        &hash_a,
+        &name_a,
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+    s.client.init_program_hashed(
+        &hash_b,
+        &name_b,
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+
+    s.token_sac.mint(&s.client.address, &1_000);
+    s.client.lock_program_funds(&name_a, &1_000);
+
+    assert_eq!(s.client.get_program_id_by_hash(&hash_a), Some(name_a));
+    assert_eq!(s.client.get_program_by_hash(&hash_a).remaining_balance, 1_000);
+    assert_eq!(s.client.get_program_by_hash(&hash_b).remaining_balance, 0);
+    assert_eq!(s.client.get_program_by_hash(&hash_b).program_id, name_b);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_duplicate_hashed_id_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hash = hash_of(&env, "alpha");
+
+    s.client.init_program_hashed(
+        &hash,
+        &String::from_str(&env, "Alpha"),
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+    // Same hash under a different display name is still a duplicate.
+    s.client.init_program_hashed(
+        &hash,
+        &String::from_str(&env, "Alpha (copy)"),
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+}
+
+#[test]
+fn test_unknown_hash_resolves_to_none() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_program_id_by_hash(&hash_of(&env, "missing")), None);
+}
+
+#[test]
+fn test_hashed_program_stored_under_hash_key() {
+    let env = Env::default();
+    let s = setup(&env);
+    let hash = hash_of(&env, "gamma");
+    let name = String::from_str(&env, "Gamma Hackathon");
+
+    s.client.init_program_hashed(
+        &hash,
+        &name,
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+
+    env.as_contract(&s.client.address, || {
+        let storage = env.storage().instance();
+        assert!(storage.has(&DataKey::HashedProgram(hash.clone())));
+        assert!(!storage.has(&DataKey::Program(name.clone())));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_hashed_display_id_cannot_shadow_string_program() {
+    let env = Env::default();
+    let s = setup(&env);
+    let name = String::from_str(&env, "Delta");
+    s.client
+        .init_program(&name, &Address::generate(&env), &s.token_addr, &s.admin, &None, &None);
+
+    s.client.init_program_hashed(
+        &hash_of(&env, "delta"),
+        &name,
+        &Address::generate(&env),
+        &s.token_addr,
+        &s.admin,
+        &None,
+        &None,
+    );
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+//! # Health Check Tests — Program Escrow
+//!
+//! `program_health_check` must mirror the state reported by the individual
+//! pause, circuit breaker, cooldown and balance views.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env, initial_balance: i128) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "health-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_health_check_fresh_program() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 5_000);
+
+    let health = client.program_health_check(&program_id);
+    assert!(!health.paused);
+    assert!(!health.breaker_open);
+    assert_eq!(health.cooldown_ends, 0);
+    assert_eq!(health.remaining_balance, 5_000);
+    assert_eq!(health.payout_count, 0);
+    assert_eq!(health.nonce_admin, 0);
+}
+
+#[test]
+fn test_health_check_reflects_payout_and_pause() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, program_id) = setup(&env, 5_000);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_200, &u64::MAX);
+    client.pause(&client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+
+    let health = client.program_health_check(&program_id);
+    assert!(health.paused);
+    assert_eq!(health.remaining_balance, 3_800);
+    assert_eq!(health.payout_count, 1);
+    assert_eq!(health.remaining_balance, client.get_remaining_balance(&program_id));
+}
+
+#[test]
+fn test_health_check_reports_open_breaker_and_cooldown() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, program_id) = setup(&env, 5_000);
+
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+        threshold_monitor::apply_cooldown(&env);
+    });
+
+    let health = client.program_health_check(&program_id);
+    assert!(health.breaker_open);
+    assert_eq!(
+        health.cooldown_ends,
+        1_000 + threshold_monitor::ThresholdConfig::default().cooldown_period_secs
+    );
+}
@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+//! # Payout Key Consent Tests — Program Escrow
+//!
+//! `initialize_program` can optionally require the `authorized_payout_key`
+//! to sign the initialization so deployers cannot designate a key whose
+//! holder has not agreed to it.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal, String,
+};
+
+fn register(env: &Env) -> (ProgramEscrowContractClient<'static>, Address) {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    (client, token_addr)
+}
+
+#[test]
+fn test_init_without_flag_needs_no_key_auth() {
+    let env = Env::default();
+    let (client, token) = register(&env);
+    let payout_key = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let program_id = String::from_str(&env, "no-consent");
+
+    let data = client.initialize_program(
+        &program_id,
+        &payout_key,
+        &token,
+        &creator,
+        &None,
+        &None,
+        &false,
+        &0,
+        &0,
+        &ProgramStorageTier::Instance,
+    );
+
+    assert_eq!(data.authorized_payout_key, payout_key);
+    assert!(env.auths().is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_init_with_flag_fails_without_key_auth() {
+    let env = Env::default();
+    let (client, token) = register(&env);
+    let payout_key = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let program_id = String::from_str(&env, "consent");
+
+    client.initialize_program(
+        &program_id,
+        &payout_key,
+        &token,
+        &creator,
+        &None,
+        &None,
+        &true,
+        &0,
+        &0,
+        &ProgramStorageTier::Instance,
+    );
+}
+
+#[test]
+fn test_init_with_flag_succeeds_with_key_auth() {
+    let env = Env::default();
+    let (client, token) = register(&env);
+    let payout_key = Address::generate(&env);
+    let creator = Address::generate(&env);
+    let program_id = String::from_str(&env, "consent");
+    let none_liquidity: Option<i128> = None;
+    let none_hash: Option<soroban_sdk::Bytes> = None;
+
+    client
+        .mock_auths(&[MockAuth {
+            address: &payout_key,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "initialize_program",
+                args: (
+                    program_id.clone(),
+                    payout_key.clone(),
+                    token.clone(),
+                    creator.clone(),
+                    none_liquidity,
+                    none_hash,
+                    true,
+                    0i128,
+                    0i128,
+                    ProgramStorageTier::Instance,
+                )
+                    .into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .initialize_program(
+            &program_id,
+            &payout_key,
+            &token,
+            &creator,
+            &None,
+            &None,
+            &true,
+            &0,
+            &0,
+            &ProgramStorageTier::Instance,
+        );
+
+    assert!(client.program_exists(&program_id));
+}
@@ -0,0 +1,193 @@
+#![cfg(test)]
+
+//! # Composite Initialization Tests — Program Escrow
+//!
+//! `init_program_full` creates a program and installs its threshold limits
+//! and payout fee in a single call, so limits apply from the first payout.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    admin: Address,
+    payout_key: Address,
+    token_addr: Address,
+    fee_collector: Address,
+    program_id: String,
+}
+
+fn limits() -> threshold_monitor::ThresholdConfig {
+    threshold_monitor::ThresholdConfig {
+        failure_rate_threshold: 10,
+        outflow_volume_threshold: 50_000,
+        max_single_payout: 1_000,
+        time_window_secs: 600,
+        cooldown_period_secs: 300,
+        cooldown_multiplier: 2,
+        warn_ratio_bps: 0,
+        window_max_single_outflow: 0,
+    }
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    Setup {
+        client,
+        admin,
+        payout_key: Address::generate(env),
+        token_addr: env
+            .register_stellar_asset_contract_v2(Address::generate(env))
+            .address(),
+        fee_collector: Address::generate(env),
+        program_id: String::from_str(env, "full-init"),
+    }
+}
+
+#[test]
+fn test_getters_reflect_single_call() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let data = s.client.init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &limits(),
+        &250,
+        &s.fee_collector,
+        &0,
+    );
+
+    assert_eq!(data.authorized_payout_key, s.payout_key);
+    assert_eq!(data.token_address, s.token_addr);
+    assert_eq!(s.client.get_program_owner(&s.program_id), s.admin);
+    assert_eq!(s.client.get_threshold_config(), limits());
+
+    let fee_config = s.client.get_fee_config();
+    assert_eq!(fee_config.payout_fee_rate, 250);
+    assert_eq!(fee_config.fee_recipient, s.fee_collector);
+    assert!(fee_config.fee_enabled);
+}
+
+#[test]
+fn test_limits_enforced_immediately() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &limits(),
+        &0,
+        &s.fee_collector,
+        &0,
+    );
+    token::StellarAssetClient::new(&env, &s.token_addr).mint(&s.client.address, &10_000);
+    s.client.lock_program_funds(&s.program_id, &10_000);
+
+    let result = s.client.try_batch_payout(
+        &s.program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000_i128],
+    );
+    assert_eq!(result, Err(Ok(EscrowError::SinglePayoutExceeded)));
+
+    let data = s.client.batch_payout(
+        &s.program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 999_i128],
+    );
+    assert_eq!(data.remaining_balance, 9_001);
+}
+
+#[test]
+fn test_invalid_threshold_config_leaves_no_program() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let mut config = limits();
+    config.outflow_volume_threshold = 0;
+    let result = s.client.try_init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &config,
+        &0,
+        &s.fee_collector,
+        &0,
+    );
+
+    assert_eq!(result, Err(Ok(EscrowError::InvalidConfig)));
+    assert_eq!(
+        s.client.try_get_remaining_balance(&s.program_id),
+        Err(Ok(EscrowError::ProgramNotFound))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_fee_above_cap_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &limits(),
+        &(MAX_FEE_RATE + 1),
+        &s.fee_collector,
+        &0,
+    );
+}
+
+#[test]
+fn test_existing_threshold_config_not_overwritten() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let mut installed = limits();
+    installed.max_single_payout = 5_000;
+    s.client.set_threshold_config(&installed, &0);
+
+    let result = s.client.try_init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &limits(),
+        &0,
+        &s.fee_collector,
+        &1,
+    );
+
+    assert_eq!(result, Err(Ok(EscrowError::AlreadyInitialized)));
+    assert_eq!(s.client.get_threshold_config(), installed);
+}
+
+#[test]
+fn test_config_changes_are_versioned() {
+    let env = Env::default();
+    let s = setup(&env);
+    let before = s.client.get_config_version();
+
+    s.client.init_program_full(
+        &s.program_id,
+        &s.payout_key,
+        &s.token_addr,
+        &limits(),
+        &250,
+        &s.fee_collector,
+        &0,
+    );
+
+    assert_eq!(s.client.get_config_version(), before + 2);
+}
@@ -0,0 +1,40 @@
+#![cfg(test)]
+
+//! # Initialization Guard Tests — Program Escrow
+//!
+//! `is_initialized` lets clients check for `initialize_contract` up front;
+//! admin-gated calls made before it fail with the typed `NotInitialized`.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+fn deploy(env: &Env) -> ProgramEscrowContractClient<'static> {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    ProgramEscrowContractClient::new(env, &contract_id)
+}
+
+#[test]
+fn test_is_initialized_flips_after_init() {
+    let env = Env::default();
+    let client = deploy(&env);
+
+    assert!(!client.is_initialized());
+    client.initialize_contract(&Address::generate(&env));
+    assert!(client.is_initialized());
+}
+
+#[test]
+fn test_admin_calls_before_init_return_typed_error() {
+    let env = Env::default();
+    let client = deploy(&env);
+
+    assert_eq!(
+        client.try_set_threshold_config(&threshold_monitor::ThresholdConfig::default(), &0),
+        Err(Ok(EscrowError::NotInitialized))
+    );
+    assert_eq!(
+        client.try_update_fee_config(&Some(100), &None, &None, &None),
+        Err(Ok(EscrowError::NotInitialized))
+    );
+}
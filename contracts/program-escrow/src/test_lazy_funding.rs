@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+//! # Lazy Funding Event Tests — Program Escrow
+//!
+//! Initializing a program without liquidity emits `PROGRAM_INITIALIZED`
+//! only; `FUNDS_LOCKED` is reserved for calls that actually move funds in.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, Symbol, TryFromVal,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    client.initialize_contract(&Address::generate(env));
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    (client, token_addr)
+}
+
+fn count(env: &Env, topic: Symbol) -> usize {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map_or(false, |t| Symbol::try_from_val(env, &t).map_or(false, |s| s == topic))
+        })
+        .count()
+}
+
+#[test]
+fn test_zero_balance_init_defers_funding_event() {
+    let env = Env::default();
+    let (client, token_addr) = setup(&env);
+    let creator = Address::generate(&env);
+
+    let program_id = String::from_str(&env, "lazy");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &creator, &None, &None);
+    assert_eq!(count(&env, PROGRAM_INITIALIZED), 1);
+    assert_eq!(count(&env, FUNDS_LOCKED), 0);
+
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &500);
+    client.lock_program_funds(&program_id, &500);
+    assert_eq!(count(&env, FUNDS_LOCKED), 1);
+}
+
+#[test]
+fn test_initial_liquidity_emits_funding_event() {
+    let env = Env::default();
+    let (client, token_addr) = setup(&env);
+    let creator = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&creator, &750);
+
+    let program_id = String::from_str(&env, "seeded");
+    let data = client.init_program(
+        &program_id,
+        &Address::generate(&env),
+        &token_addr,
+        &creator,
+        &Some(750),
+        &None,
+    );
+
+    assert_eq!(data.remaining_balance, 750);
+    assert_eq!(count(&env, PROGRAM_INITIALIZED), 1);
+    assert_eq!(count(&env, FUNDS_LOCKED), 1);
+}
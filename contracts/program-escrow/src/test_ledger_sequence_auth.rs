@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+//! # Ledger-Sequence Replay Protection Tests — Program Escrow
+//!
+//! Under `PayoutAuthMode::LedgerSequence` each payout signer gets at most
+//! one payout per ledger sequence, with no nonce to track.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_auth_mode(&PayoutAuthMode::LedgerSequence, &0);
+
+    let program_id = String::from_str(env, "seq-auth");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_second_payout_in_same_sequence_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    let replay = client.try_single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(replay, Err(Ok(EscrowError::LedgerSequenceUsed)));
+    let batch = client.try_batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+    assert_eq!(batch, Err(Ok(EscrowError::LedgerSequenceUsed)));
+}
+
+#[test]
+fn test_payouts_across_sequences_allowed() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    env.ledger().set_sequence_number(101);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    env.ledger().set_sequence_number(102);
+    client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+
+    assert_eq!(client.get_remaining_balance(&program_id), 9_700);
+}
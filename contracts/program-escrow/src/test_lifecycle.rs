@@ -98,7 +98,7 @@ fn setup_active_program(
 // ---------------------------------------------------------------------------
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_lock_funds_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -107,7 +107,7 @@ fn test_uninitialized_lock_funds_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_single_payout_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -117,7 +117,7 @@ fn test_uninitialized_single_payout_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_batch_payout_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -127,7 +127,7 @@ fn test_uninitialized_batch_payout_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_get_info_rejected() {
     let env = Env::default();
     let (client, _cid) = make_client(&env);
@@ -135,7 +135,7 @@ fn test_uninitialized_get_info_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_get_balance_rejected() {
     let env = Env::default();
     let (client, _cid) = make_client(&env);
@@ -143,7 +143,7 @@ fn test_uninitialized_get_balance_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_create_schedule_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -153,7 +153,7 @@ fn test_uninitialized_create_schedule_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "Program not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_uninitialized_trigger_releases_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -185,7 +185,7 @@ fn test_initialized_state_balance_is_zero() {
 
 /// Re-initializing the same program must be rejected (single-init guard).
 #[test]
-#[should_panic(expected = "Program already initialized")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_initialized_double_init_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -200,7 +200,7 @@ fn test_initialized_double_init_rejected() {
 
 /// Payout from a zero-balance (Initialized) program must be rejected.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_initialized_single_payout_zero_balance_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -215,7 +215,7 @@ fn test_initialized_single_payout_zero_balance_rejected() {
 
 /// Batch payout from a zero-balance (Initialized) program must be rejected.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_initialized_batch_payout_zero_balance_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -307,7 +307,7 @@ fn test_active_top_up_lock_increases_balance() {
 
 /// In Active state, negative lock amounts are rejected.
 #[test]
-#[should_panic(expected = "Amount must be greater than zero")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_active_negative_lock_amount_rejected() {
     let env = Env::default();
     env.mock_all_auths();
@@ -321,7 +321,7 @@ fn test_active_negative_lock_amount_rejected() {
 
 /// Payout exceeding balance must be rejected (Active state guard).
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_active_payout_exceeds_balance_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -331,7 +331,7 @@ fn test_active_payout_exceeds_balance_rejected() {
 
 /// Batch payout total exceeding balance must be rejected.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_active_batch_exceeds_balance_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -343,7 +343,7 @@ fn test_active_batch_exceeds_balance_rejected() {
 
 /// Zero-amount single payout must be rejected.
 #[test]
-#[should_panic(expected = "Amount must be greater than zero")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_active_zero_single_payout_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -353,7 +353,7 @@ fn test_active_zero_single_payout_rejected() {
 
 /// Zero-amount entry in a batch must be rejected.
 #[test]
-#[should_panic(expected = "All amounts must be greater than zero")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_active_zero_amount_in_batch_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -364,7 +364,7 @@ fn test_active_zero_amount_in_batch_rejected() {
 
 /// Mismatched recipients/amounts vectors must be rejected.
 #[test]
-#[should_panic(expected = "Recipients and amounts vectors must have the same length")]
+#[should_panic(expected = "Error(Contract, #14)")]
 fn test_active_batch_mismatched_lengths_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -375,7 +375,7 @@ fn test_active_batch_mismatched_lengths_rejected() {
 
 /// Empty batch must be rejected.
 #[test]
-#[should_panic(expected = "Cannot process empty batch")]
+#[should_panic(expected = "Error(Contract, #12)")]
 fn test_active_empty_batch_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -409,7 +409,7 @@ fn test_active_payout_history_grows() {
 
 /// Pausing lock prevents lock_program_funds.
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_paused_lock_operation_blocked() {
     let env = Env::default();
     env.mock_all_auths();
@@ -427,7 +427,7 @@ fn test_paused_lock_operation_blocked() {
 
 /// Pausing release prevents single_payout.
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_paused_single_payout_blocked() {
     let env = Env::default();
     env.mock_all_auths();
@@ -447,7 +447,7 @@ fn test_paused_single_payout_blocked() {
 
 /// Pausing release prevents batch_payout.
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_paused_batch_payout_blocked() {
     let env = Env::default();
     env.mock_all_auths();
@@ -628,7 +628,7 @@ fn test_drained_after_full_batch_payout() {
 
 /// Further payouts from Drained state must be rejected.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_drained_further_payout_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -929,7 +929,7 @@ fn test_initialized_with_zero_initial_liquidity_stays_initialized() {
 
 /// Batch payout from Drained state must be rejected.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_drained_batch_payout_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -946,7 +946,7 @@ fn test_drained_batch_payout_rejected() {
 
 /// Double initialization remains rejected even after program is drained.
 #[test]
-#[should_panic(expected = "Program already initialized")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_drained_double_init_still_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -1280,7 +1280,7 @@ fn test_initialized_query_operations() {
 
 /// Release schedules respect program remaining balance in Active state.
 #[test]
-#[should_panic(expected = "Insufficient balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_active_schedule_trigger_exceeds_balance_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+//! # Lock Overflow Tests — Program Escrow
+//!
+//! `lock_program_funds` accumulates with `checked_add`, so locking past
+//! `i128::MAX` panics with `AmountOverflow` instead of wrapping.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "overflow");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_lock_up_to_i128_max_is_accepted() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.lock_program_funds(&program_id, &(i128::MAX - 1));
+    let data = client.lock_program_funds(&program_id, &1);
+
+    assert_eq!(data.total_funds, i128::MAX);
+    assert_eq!(data.remaining_balance, i128::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_lock_past_i128_max_panics() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.lock_program_funds(&program_id, &(i128::MAX - 10));
+    client.lock_program_funds(&program_id, &11);
+}
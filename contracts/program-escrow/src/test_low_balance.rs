@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+//! # Low Balance Alert Tests — Program Escrow
+//!
+//! A payout that drops `remaining_balance` below `low_balance_threshold`
+//! emits one `low_bal` event; a lock back above the threshold re-arms it.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, Symbol, TryFromVal,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, token::StellarAssetClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "always-on");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+    token_sac.mint(&client.address, &1_000);
+    client.lock_program_funds(&program_id, &1_000);
+    client.set_low_balance_threshold(&program_id, &500, &0);
+
+    (client, token_sac, program_id)
+}
+
+fn low_balance_events(env: &Env) -> usize {
+    let topic = Symbol::new(env, "low_bal");
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map_or(false, |t| Symbol::try_from_val(env, &t).map_or(false, |s| s == topic))
+        })
+        .count()
+}
+
+#[test]
+fn test_alert_fires_once_per_crossing() {
+    let env = Env::default();
+    let (client, token_sac, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &400, &u64::MAX);
+    assert_eq!(low_balance_events(&env), 0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &200, &u64::MAX);
+    assert_eq!(low_balance_events(&env), 1);
+
+    // Still below: no repeat alert.
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(low_balance_events(&env), 1);
+
+    // Top up above the threshold to re-arm, then drain again.
+    token_sac.mint(&client.address, &700);
+    client.lock_program_funds(&program_id, &700);
+    assert_eq!(client.get_remaining_balance(&program_id), 1_000);
+    assert_eq!(low_balance_events(&env), 1);
+
+    client.single_payout(&program_id, &Address::generate(&env), &600, &u64::MAX);
+    assert_eq!(low_balance_events(&env), 2);
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+//! # Batch Size Limit Tests — Program Escrow
+//!
+//! `batch_payout` rejects batches larger than the configured
+//! `max_batch_size` before touching any state.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String, Vec};
+
+fn setup(env: &Env, initial_balance: i128) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "batch-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    (client, program_id)
+}
+
+fn batch(env: &Env, size: u32) -> (Vec<Address>, Vec<i128>) {
+    let mut recipients = Vec::new(env);
+    let mut amounts = Vec::new(env);
+    for _ in 0..size {
+        recipients.push_back(Address::generate(env));
+        amounts.push_back(10);
+    }
+    (recipients, amounts)
+}
+
+#[test]
+fn test_default_max_batch_size() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env, 1_000);
+
+    assert_eq!(client.get_max_batch_size(), MAX_BATCH_SIZE);
+}
+
+#[test]
+fn test_batch_at_limit_succeeds() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+    client.set_max_batch_size(&3, &0);
+    assert_eq!(client.get_max_batch_size(), 3);
+
+    let (recipients, amounts) = batch(&env, 3);
+    let data = client.batch_payout(&program_id, &recipients, &amounts);
+
+    assert_eq!(data.remaining_balance, 970);
+    assert_eq!(data.payout_history.len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_batch_over_limit_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+    client.set_max_batch_size(&3, &0);
+
+    let (recipients, amounts) = batch(&env, 4);
+    client.batch_payout(&program_id, &recipients, &amounts);
+}
+
+#[test]
+fn test_batch_over_limit_leaves_state_untouched() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+    client.set_max_batch_size(&2, &0);
+
+    let (recipients, amounts) = batch(&env, 3);
+    assert!(client
+        .try_batch_payout(&program_id, &recipients, &amounts)
+        .is_err());
+    assert_eq!(client.get_remaining_balance(&program_id), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_zero_max_batch_size_rejected() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env, 1_000);
+    client.set_max_batch_size(&0, &0);
+}
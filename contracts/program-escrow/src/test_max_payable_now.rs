@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+//! # Max Payable Tests — Program Escrow
+//!
+//! `max_payable_now` folds balance, spending limit, daily cap, window
+//! outflow and single payout limits into one number, and drops to 0 while
+//! payouts are halted.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "payable");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_balance_bounds_payable_without_limits() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    assert_eq!(client.max_payable_now(&program_id), 10_000);
+}
+
+#[test]
+fn test_low_daily_remaining_dominates_balance() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.set_daily_outflow_limit(&1_500, &0);
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+
+    assert_eq!(client.get_remaining_balance(&program_id), 9_000);
+    assert_eq!(client.max_payable_now(&program_id), 500);
+}
+
+#[test]
+fn test_max_single_payout_caps_payable() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let mut config = client.get_threshold_config();
+    config.max_single_payout = 2_000;
+    client.set_threshold_config(&config, &0);
+
+    assert_eq!(client.max_payable_now(&program_id), 1_999);
+}
+
+#[test]
+fn test_paused_contract_pays_nothing() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.pause(&client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+    assert_eq!(client.max_payable_now(&program_id), 0);
+}
+
+#[test]
+fn test_program_spending_limit_caps_payable() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.set_program_spending_limit(&program_id, &3_600, &3_000, &true);
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+
+    assert_eq!(client.max_payable_now(&program_id), 2_000);
+}
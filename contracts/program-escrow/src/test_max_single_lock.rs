@@ -0,0 +1,74 @@
+#![cfg(test)]
+
+//! # Max Single Lock Tests — Program Escrow
+//!
+//! `max_single_lock` caps each deposit call, unlike the lifetime
+//! `max_total_funds`: an oversized lock fails but the same amount split
+//! across smaller calls goes through.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+const CAP: i128 = 5_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_max_single_lock(&CAP, &0);
+
+    let program_id = String::from_str(env, "capped-lock");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &8_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_lock_above_cap_fails_but_split_succeeds() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    assert_eq!(client.get_max_single_lock(), CAP);
+
+    assert_eq!(
+        client.try_lock_program_funds(&program_id, &8_000),
+        Err(Ok(EscrowError::SingleLockExceeded))
+    );
+    assert_eq!(client.get_remaining_balance(&program_id), 0);
+
+    client.lock_program_funds(&program_id, &CAP);
+    let data = client.lock_program_funds(&program_id, &3_000);
+    assert_eq!(data.total_funds, 8_000);
+    assert_eq!(data.remaining_balance, 8_000);
+}
+
+#[test]
+fn test_negative_cap_rejected() {
+    let env = Env::default();
+    let (client, _) = setup(&env);
+
+    assert_eq!(
+        client.try_set_max_single_lock(&-1, &1),
+        Err(Ok(EscrowError::InvalidConfig))
+    );
+    assert_eq!(client.get_max_single_lock(), CAP);
+}
+
+#[test]
+fn test_prepare_lock_above_cap_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    assert_eq!(
+        client.try_prepare_lock(&program_id, &Address::generate(&env), &(CAP + 1)),
+        Err(Ok(EscrowError::SingleLockExceeded))
+    );
+}
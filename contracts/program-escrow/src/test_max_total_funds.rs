@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+//! # Lifetime Funding Cap Tests — Program Escrow
+//!
+//! `max_total_funds` bounds the cumulative amount ever locked into a
+//! program. Locks that would push `total_funds` past the cap are rejected;
+//! a cap of `0` leaves the program unlimited.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+const CAP: i128 = 5_000;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token_sac: token::StellarAssetClient<'static>,
+    token_addr: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "capped");
+    client.initialize_program(
+        &program_id,
+        &Address::generate(env),
+        &token_addr,
+        &admin,
+        &None,
+        &None,
+        &false,
+        &CAP,
+        &0,
+        &ProgramStorageTier::Instance,
+    );
+
+    Setup {
+        client,
+        token_sac: token::StellarAssetClient::new(env, &token_addr),
+        token_addr,
+        program_id,
+    }
+}
+
+#[test]
+fn test_locks_up_to_cap_succeed() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.client.get_max_total_funds(&s.program_id), CAP);
+
+    s.token_sac.mint(&s.client.address, &CAP);
+    s.client.lock_program_funds(&s.program_id, &3_000);
+    let data = s.client.lock_program_funds(&s.program_id, &2_000);
+
+    assert_eq!(data.total_funds, CAP);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_lock_past_cap_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.token_sac.mint(&s.client.address, &(CAP + 1));
+    s.client.lock_program_funds(&s.program_id, &3_000);
+    s.client.lock_program_funds(&s.program_id, &(CAP - 3_000 + 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_payouts_do_not_free_cap_headroom() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.token_sac.mint(&s.client.address, &(CAP * 2));
+    s.client.lock_program_funds(&s.program_id, &CAP);
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &1_000, &u64::MAX);
+
+    // The cap is lifetime, so spending does not make room for new funds.
+    s.client.lock_program_funds(&s.program_id, &1_000);
+}
+
+#[test]
+fn test_zero_cap_is_unlimited() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let open = String::from_str(&env, "open");
+    s.client.init_program(
+        &open,
+        &Address::generate(&env),
+        &s.token_addr,
+        &Address::generate(&env),
+        &None,
+        &None,
+    );
+    assert_eq!(s.client.get_max_total_funds(&open), 0);
+
+    s.token_sac.mint(&s.client.address, &(CAP * 10));
+    let data = s.client.lock_program_funds(&open, &(CAP * 10));
+    assert_eq!(data.total_funds, CAP * 10);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_negative_cap_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.initialize_program(
+        &String::from_str(&env, "negative"),
+        &Address::generate(&env),
+        &s.token_addr,
+        &Address::generate(&env),
+        &None,
+        &None,
+        &false,
+        &-1,
+        &0,
+        &ProgramStorageTier::Instance,
+    );
+}
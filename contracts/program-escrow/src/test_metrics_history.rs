@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+//! # Metrics History Tests — Program Escrow
+//!
+//! Each threshold window rollover appends the closed `WindowMetrics` to a
+//! bounded ring exposed by `get_metrics_history`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const START: u64 = 1_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "history");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.as_contract(&client.address, || {
+        threshold_monitor::init_threshold_monitor(env);
+    });
+
+    (client, program_id)
+}
+
+#[test]
+fn test_ring_keeps_most_recent_windows_in_order() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_metrics_history_len(&3, &0);
+    let window = client.get_threshold_config().time_window_secs;
+
+    for k in 0..6u64 {
+        env.ledger().set_timestamp(START + k * window);
+        client.single_payout(&program_id, &Address::generate(&env), &(10 * (k as i128 + 1)), &u64::MAX);
+    }
+
+    let history = client.get_metrics_history();
+    assert_eq!(history.len(), 3);
+    for (i, metrics) in history.iter().enumerate() {
+        let k = i as u64 + 2;
+        assert_eq!(metrics.window_start, START + k * window);
+        assert_eq!(metrics.total_outflow, 10 * (k as i128 + 1));
+    }
+}
+
+#[test]
+fn test_history_empty_before_first_rollover() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &10, &u64::MAX);
+    assert_eq!(client.get_metrics_history().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_history_len_is_bounded() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    client.set_metrics_history_len(&(threshold_monitor::MAX_METRICS_HISTORY_LEN + 1), &0);
+}
@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+//! # Minimum Funding Tests — Program Escrow
+//!
+//! A program initialized with `min_funding_before_payout` refuses payouts
+//! until at least that much has been locked.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "min-funded");
+    client.initialize_program(
+        &program_id,
+        &Address::generate(env),
+        &token_addr,
+        &admin,
+        &None,
+        &None,
+        &false,
+        &0,
+        &5_000,
+    );
+
+    (client, program_id)
+}
+
+#[test]
+fn test_payouts_blocked_until_min_funding_locked() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.lock_program_funds(&program_id, &3_000);
+
+    let single = client.try_single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(single, Err(Ok(EscrowError::BelowMinFunding)));
+    let batch = client.try_batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+    assert_eq!(batch, Err(Ok(EscrowError::BelowMinFunding)));
+
+    client.lock_program_funds(&program_id, &2_000);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+    assert_eq!(client.get_remaining_balance(&program_id), 4_800);
+}
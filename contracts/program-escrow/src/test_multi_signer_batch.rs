@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+//! # Multi-Signer Batch Tests — Program Escrow
+//!
+//! `multi_signer_batch` lets several program signers each authorize their
+//! own entries with their own nonce. One bad entry reverts the batch.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    payout_key: Address,
+    delegate: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "cosigned");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let delegate = Address::generate(env);
+    client.set_delegate(&program_id, &delegate, &100_000, &0);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        payout_key,
+        delegate,
+    }
+}
+
+#[test]
+fn test_each_signer_nonce_advances() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let payouts = vec![
+        &env,
+        (s.payout_key.clone(), alice.clone(), 400_i128, 1_u64),
+        (s.delegate.clone(), bob.clone(), 600_i128, 0_u64),
+        (s.payout_key.clone(), bob.clone(), 100_i128, 2_u64),
+    ];
+    let data = s.client.multi_signer_batch(&s.program_id, &payouts);
+
+    assert_eq!(data.remaining_balance, 8_900);
+    assert_eq!(s.token.balance(&alice), 400);
+    assert_eq!(s.token.balance(&bob), 700);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 3);
+    assert_eq!(s.client.get_nonce(&s.delegate), 1);
+}
+
+#[test]
+fn test_wrong_nonce_reverts_whole_batch() {
+    let env = Env::default();
+    let s = setup(&env);
+    let alice = Address::generate(&env);
+
+    let payouts = vec![
+        &env,
+        (s.payout_key.clone(), alice.clone(), 400_i128, 1_u64),
+        (s.delegate.clone(), alice.clone(), 600_i128, 5_u64),
+    ];
+    assert_eq!(
+        s.client.try_multi_signer_batch(&s.program_id, &payouts),
+        Err(Ok(EscrowError::InvalidNonce))
+    );
+
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+    assert_eq!(s.token.balance(&alice), 0);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 1);
+    assert_eq!(s.client.get_nonce(&s.delegate), 0);
+}
+
+#[test]
+fn test_non_signer_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let stranger = Address::generate(&env);
+
+    let payouts = vec![&env, (stranger.clone(), stranger.clone(), 100_i128, 0_u64)];
+    assert_eq!(
+        s.client.try_multi_signer_batch(&s.program_id, &payouts),
+        Err(Ok(EscrowError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_rate_limit_charged_to_actual_signers() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_rate_limit(&s.payout_key, &60, &0);
+    s.client.set_rate_limit(&s.delegate, &60, &1);
+    let alice = Address::generate(&env);
+
+    let payouts = vec![
+        &env,
+        (s.delegate.clone(), alice.clone(), 100_i128, 0_u64),
+        (s.delegate.clone(), alice.clone(), 200_i128, 1_u64),
+    ];
+    s.client.multi_signer_batch(&s.program_id, &payouts);
+
+    let delegate_limit = s.client.get_rate_limit(&s.delegate).unwrap();
+    assert_eq!(delegate_limit.last_payout_ts, Some(1_000));
+    let key_limit = s.client.get_rate_limit(&s.payout_key).unwrap();
+    assert_eq!(key_limit.last_payout_ts, None);
+}
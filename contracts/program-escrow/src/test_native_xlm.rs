@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+//! # Native XLM Tests — Program Escrow
+//!
+//! A program whose `token_address` is the native asset's SAC custodies and
+//! disburses XLM through the same `token::Client` path as any other asset.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, xdr, Address, Bytes, Env, String, TryFromVal};
+use std::rc::Rc;
+
+/// Serialized XDR of `Asset::Native`.
+const NATIVE_ASSET_XDR: [u8; 4] = [0, 0, 0, 0];
+
+/// Creates a classic account holding `balance` stroops. The native asset has
+/// no admin to mint with, so the account entry is written directly.
+fn funded_account(env: &Env, seed: u8, balance: i64) -> Address {
+    let account_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+        [seed; 32],
+    )));
+    let key = Rc::new(xdr::LedgerKey::Account(xdr::LedgerKeyAccount {
+        account_id: account_id.clone(),
+    }));
+    let entry = Rc::new(xdr::LedgerEntry {
+        data: xdr::LedgerEntryData::Account(xdr::AccountEntry {
+            account_id: account_id.clone(),
+            balance,
+            flags: 0,
+            home_domain: Default::default(),
+            inflation_dest: None,
+            num_sub_entries: 0,
+            seq_num: xdr::SequenceNumber(0),
+            thresholds: xdr::Thresholds([1; 4]),
+            signers: xdr::VecM::default(),
+            ext: xdr::AccountEntryExt::V0,
+        }),
+        last_modified_ledger_seq: 0,
+        ext: xdr::LedgerEntryExt::V0,
+    });
+    let budget = env.host().budget_cloned();
+    env.host()
+        .with_mut_storage(|storage| storage.put(&key, &entry, None, &budget))
+        .unwrap();
+
+    Address::try_from_val(env, &xdr::ScAddress::Account(account_id)).unwrap()
+}
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    xlm: token::Client<'static>,
+    program_id: String,
+    funder: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let native_addr = env
+        .deployer()
+        .with_stellar_asset(Bytes::from_array(env, &NATIVE_ASSET_XDR))
+        .deploy();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "xlm-grants");
+    client.init_program(&program_id, &Address::generate(env), &native_addr, &admin, &None, &None);
+
+    Setup {
+        client,
+        xlm: token::Client::new(env, &native_addr),
+        program_id,
+        funder: funded_account(env, 7, 1_000_000_000),
+    }
+}
+
+#[test]
+fn test_native_xlm_lock_and_payout() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert_eq!(s.xlm.decimals(), 7);
+
+    s.xlm.transfer(&s.funder, &s.client.address, &50_000_000);
+    let data = s.client.lock_program_funds(&s.program_id, &50_000_000);
+    assert_eq!(data.remaining_balance, 50_000_000);
+    assert_eq!(s.xlm.balance(&s.client.address), 50_000_000);
+
+    let recipient = funded_account(&env, 9, 10_000_000);
+    let data = s
+        .client
+        .single_payout(&s.program_id, &recipient, &20_000_000, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 30_000_000);
+    assert_eq!(s.xlm.balance(&recipient), 30_000_000);
+    assert_eq!(s.xlm.balance(&s.client.address), 30_000_000);
+    assert_eq!(s.xlm.balance(&s.funder), 950_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_native_xlm_payout_cannot_exceed_locked_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.xlm.transfer(&s.funder, &s.client.address, &5_000_000);
+    s.client.lock_program_funds(&s.program_id, &5_000_000);
+
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &6_000_000, &u64::MAX);
+}
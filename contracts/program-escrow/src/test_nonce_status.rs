@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+//! # Nonce Status Tests — Program Escrow
+//!
+//! `get_nonce_status` reports a signer's next nonce together with the
+//! ledger timestamp at which a nonce was last consumed.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+#[test]
+fn test_status_tracks_nonce_and_timestamp() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(&env);
+    let program_id = String::from_str(&env, "nonce-status");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &1_000);
+    client.lock_program_funds(&program_id, &1_000);
+
+    assert_eq!(client.get_nonce_status(&payout_key), (0, 0));
+
+    env.ledger().set_timestamp(5_000);
+    client.batch_payout_aggregated(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+        &0,
+    );
+
+    assert_eq!(client.get_nonce_status(&payout_key), (1, 5_000));
+}
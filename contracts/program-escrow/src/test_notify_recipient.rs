@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+//! # Recipient Notification Tests — Program Escrow
+//!
+//! With `notify_recipient` on, contract recipients get an
+//! `on_payout(from, amount)` callback after funds arrive. Plain accounts
+//! are skipped, and callbacks cannot re-enter the escrow's payout paths.
+
+use super::*;
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, token, vec, Address, Env,
+    String,
+};
+
+/// Records the last `on_payout` callback it received.
+#[contract]
+struct RecordingReceiver;
+
+#[contractimpl]
+impl RecordingReceiver {
+    pub fn on_payout(env: Env, from: Address, amount: i128) {
+        env.storage().instance().set(&symbol_short!("last"), &(from, amount));
+    }
+
+    pub fn last(env: Env) -> Option<(Address, i128)> {
+        env.storage().instance().get(&symbol_short!("last"))
+    }
+}
+
+/// Tries to pull a second payout from inside its callback.
+#[contract]
+struct ReentrantReceiver;
+
+#[contractimpl]
+impl ReentrantReceiver {
+    pub fn init(env: Env, program_id: String) {
+        env.storage().instance().set(&symbol_short!("prog"), &program_id);
+    }
+
+    pub fn on_payout(env: Env, from: Address, _amount: i128) {
+        let program_id: String = env.storage().instance().get(&symbol_short!("prog")).unwrap();
+        let escrow = ProgramEscrowContractClient::new(&env, &from);
+        let reentered = escrow
+            .try_single_payout(&program_id, &env.current_contract_address(), &100, &u64::MAX)
+            .is_ok();
+        env.storage().instance().set(&symbol_short!("reentered"), &reentered);
+    }
+
+    pub fn reentered(env: Env) -> Option<bool> {
+        env.storage().instance().get(&symbol_short!("reentered"))
+    }
+}
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_notify_recipient(&true, &0);
+
+    let program_id = String::from_str(env, "notify");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+    }
+}
+
+#[test]
+fn test_contract_recipient_receives_callback() {
+    let env = Env::default();
+    let s = setup(&env);
+    let receiver_id = env.register_contract(None, RecordingReceiver);
+    let receiver = RecordingReceiverClient::new(&env, &receiver_id);
+
+    s.client.single_payout(&s.program_id, &receiver_id, &300, &u64::MAX);
+    assert_eq!(receiver.last(), Some((s.client.address.clone(), 300)));
+
+    s.client.batch_payout(
+        &s.program_id,
+        &vec![&env, Address::generate(&env), receiver_id.clone()],
+        &vec![&env, 100_i128, 200],
+    );
+    assert_eq!(receiver.last(), Some((s.client.address.clone(), 200)));
+    assert_eq!(s.token.balance(&receiver_id), 500);
+}
+
+#[test]
+fn test_plain_account_and_disabled_flag_skip_callback() {
+    let env = Env::default();
+    let s = setup(&env);
+    let account = Address::generate(&env);
+
+    let data = s.client.single_payout(&s.program_id, &account, &300, &u64::MAX);
+    assert_eq!(data.remaining_balance, 9_700);
+
+    s.client.set_notify_recipient(&false, &1);
+    let receiver_id = env.register_contract(None, RecordingReceiver);
+    s.client.single_payout(&s.program_id, &receiver_id, &300, &u64::MAX);
+
+    assert_eq!(RecordingReceiverClient::new(&env, &receiver_id).last(), None);
+    assert_eq!(s.token.balance(&receiver_id), 300);
+}
+
+#[test]
+fn test_callback_cannot_reenter_payout() {
+    let env = Env::default();
+    let s = setup(&env);
+    let receiver_id = env.register_contract(None, ReentrantReceiver);
+    let receiver = ReentrantReceiverClient::new(&env, &receiver_id);
+    receiver.init(&s.program_id);
+
+    s.client.single_payout(&s.program_id, &receiver_id, &300, &u64::MAX);
+
+    assert_eq!(receiver.reentered(), Some(false));
+    assert_eq!(s.token.balance(&receiver_id), 300);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_700);
+}
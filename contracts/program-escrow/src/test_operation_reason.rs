@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Operation Reason Tests — Program Escrow
+//!
+//! `withdraw_excess`, `drain_to_admin` and `batch_reclaim` take a `reason`
+//! label that is carried in their events and capped at `MAX_REASON_LEN`.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, vec, Address, Env, String, Symbol, TryFromVal,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "labelled");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+/// Data payload of the last event whose first topic is `name`.
+fn last_event_data(env: &Env, name: &str) -> Option<Val> {
+    let wanted = Symbol::new(env, name);
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let first = topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if first == Some(wanted.clone()) {
+            found = Some(data);
+        }
+    }
+    found
+}
+
+#[test]
+fn test_withdraw_excess_event_carries_reason() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let reason = String::from_str(&env, "refund unused sponsor top-up");
+
+    client.withdraw_excess(&program_id, &1_000, &Address::generate(&env), &reason, &0);
+
+    let data = last_event_data(&env, "exc_wdraw").expect("withdraw event");
+    let (_, _, _, emitted): (i128, Address, i128, String) =
+        TryFromVal::try_from_val(&env, &data).unwrap();
+    assert_eq!(emitted, reason);
+}
+
+#[test]
+fn test_reclaim_event_carries_reason() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let reason = String::from_str(&env, "season close");
+    let owner = client.get_program_owner(&program_id);
+
+    client.batch_reclaim(&owner, &vec![&env, program_id], &Address::generate(&env), &reason, &0);
+
+    let data = last_event_data(&env, "reclaim").expect("reclaim event");
+    let (_, _, emitted, _): (i128, Address, String, u64) =
+        TryFromVal::try_from_val(&env, &data).unwrap();
+    assert_eq!(emitted, reason);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+fn test_overlong_reason_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let long = "x".repeat(MAX_REASON_LEN as usize + 1);
+    client.withdraw_excess(
+        &program_id,
+        &1_000,
+        &Address::generate(&env),
+        &String::from_str(&env, &long),
+        &0,
+    );
+}
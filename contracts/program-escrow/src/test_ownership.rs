@@ -0,0 +1,178 @@
+#![cfg(test)]
+
+//! # Program Ownership Tests — Program Escrow
+//!
+//! The program owner (governance) is separate from the operational
+//! `authorized_payout_key`. Ownership moves via a two-step
+//! `transfer_ownership` / `accept_ownership` handshake, and owner-only
+//! actions cannot be performed by the payout key.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+    owner: Address,
+    payout_key: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let owner = Address::generate(env);
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "owned-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &owner, &None, &None);
+
+    Setup {
+        client,
+        program_id,
+        owner,
+        payout_key,
+    }
+}
+
+#[test]
+fn test_creator_is_initial_owner() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_program_owner(&s.program_id), s.owner);
+    assert_eq!(s.client.get_pending_owner(&s.program_id), None);
+}
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let new_owner = Address::generate(&env);
+
+    s.client.transfer_ownership(&s.program_id, &new_owner, &0);
+
+    // Nothing changes until the nominee accepts.
+    assert_eq!(s.client.get_program_owner(&s.program_id), s.owner);
+    assert_eq!(s.client.get_pending_owner(&s.program_id), Some(new_owner.clone()));
+    assert_eq!(s.client.get_nonce(&s.owner), 1);
+
+    s.client.accept_ownership(&s.program_id);
+
+    assert_eq!(s.client.get_program_owner(&s.program_id), new_owner);
+    assert_eq!(s.client.get_pending_owner(&s.program_id), None);
+
+    // The new owner can now rotate the payout key.
+    let rotated = Address::generate(&env);
+    let data = s.client.rotate_payout_key(&s.program_id, &rotated, &0);
+    assert_eq!(data.authorized_payout_key, rotated);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_accept_without_transfer_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.accept_ownership(&s.program_id);
+}
+
+#[test]
+#[should_panic]
+fn test_old_owner_loses_rights_after_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+    let new_owner = Address::generate(&env);
+
+    s.client.transfer_ownership(&s.program_id, &new_owner, &0);
+    s.client.accept_ownership(&s.program_id);
+
+    // Only the old owner's signature is supplied.
+    let rotated = Address::generate(&env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.owner,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "rotate_payout_key",
+                args: (s.program_id.clone(), rotated.clone(), 1u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .rotate_payout_key(&s.program_id, &rotated, &1);
+}
+
+#[test]
+#[should_panic]
+fn test_payout_key_cannot_rotate_itself() {
+    let env = Env::default();
+    let s = setup(&env);
+    let rotated = Address::generate(&env);
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.payout_key,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "rotate_payout_key",
+                args: (s.program_id.clone(), rotated.clone(), 0u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .rotate_payout_key(&s.program_id, &rotated, &0);
+}
+
+#[test]
+#[should_panic]
+fn test_payout_key_cannot_transfer_ownership() {
+    let env = Env::default();
+    let s = setup(&env);
+    let attacker = Address::generate(&env);
+
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.payout_key,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "transfer_ownership",
+                args: (s.program_id.clone(), attacker.clone(), 0u64).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .transfer_ownership(&s.program_id, &attacker, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_transfer_ownership_rejects_replayed_nonce() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client.transfer_ownership(&s.program_id, &Address::generate(&env), &0);
+    s.client.transfer_ownership(&s.program_id, &Address::generate(&env), &0);
+}
+
+#[test]
+fn test_owner_sets_spending_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let s = setup(&env);
+
+    s.client
+        .set_program_spending_limit(&s.program_id, &3_600, &1_000, &true);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths.get(0).unwrap().0, s.owner);
+}
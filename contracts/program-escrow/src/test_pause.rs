@@ -135,7 +135,7 @@ fn test_mixed_pause_states() {
 // --- lock_program_funds enforcement ---
 
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_lock_program_funds_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -148,7 +148,7 @@ fn test_lock_program_funds_paused() {
 // --- single_payout enforcement ---
 
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_single_payout_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -162,7 +162,7 @@ fn test_single_payout_paused() {
 // --- batch_payout enforcement ---
 
 #[test]
-#[should_panic(expected = "Funds Paused")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_batch_payout_paused() {
     let env = Env::default();
     env.mock_all_auths();
@@ -179,7 +179,7 @@ fn test_batch_payout_paused() {
 // --- initialize_contract guard ---
 
 #[test]
-#[should_panic(expected = "Already initialized")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_double_initialize_contract() {
     let env = Env::default();
 
@@ -196,7 +196,7 @@ fn test_double_initialize_contract() {
 // --- set_paused requires initialization ---
 
 #[test]
-#[should_panic(expected = "Not initialized")]
+#[should_panic(expected = "Error(Contract, #1)")]
 fn test_set_paused_before_initialize() {
     let env = Env::default();
     let contract_id = env.register_contract(None, ProgramEscrowContract);
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+//! # Pause Reason Tests — Program Escrow
+//!
+//! `pause` records a short, length-capped reason that front-ends read via
+//! `get_pause_reason`; `unpause` clears it.
+
+extern crate std;
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    (client, admin)
+}
+
+#[test]
+fn test_pause_reason_set_and_cleared() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let empty = String::from_str(&env, "");
+    assert_eq!(client.get_pause_reason(), empty);
+
+    let reason = String::from_str(&env, "oracle incident, investigating");
+    client.pause(&admin, &reason);
+    assert!(client.is_paused());
+    assert_eq!(client.get_pause_reason(), reason);
+
+    client.unpause();
+    assert!(!client.is_paused());
+    assert_eq!(client.get_pause_reason(), empty);
+}
+
+#[test]
+fn test_pause_reason_too_long_rejected() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let long = "x".repeat(MAX_REASON_LEN as usize + 1);
+    let reason = String::from_str(&env, &long);
+
+    assert_eq!(
+        client.try_pause(&admin, &reason),
+        Err(Ok(EscrowError::ReasonTooLong))
+    );
+    assert!(!client.is_paused());
+}
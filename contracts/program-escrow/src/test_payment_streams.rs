@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+//! # Payment Stream Tests — Program Escrow
+//!
+//! `create_stream` reserves `rate_per_sec * (stop - start)` up front;
+//! `withdraw_stream` pays whatever has vested and `cancel_stream` returns
+//! the unvested remainder to the program.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const START: u64 = 1_000;
+const STOP: u64 = 1_100;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    recipient: Address,
+    stream_id: u64,
+}
+
+/// Locks 10_000 and opens a 10-per-second stream over 100 seconds.
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START - 50);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "streams");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let recipient = Address::generate(env);
+    let stream_id = client.create_stream(&program_id, &recipient, &10, &START, &STOP, &0);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        recipient,
+        stream_id,
+    }
+}
+
+#[test]
+fn test_create_stream_reserves_full_amount() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_000);
+    let stream = s.client.get_stream(&s.stream_id).unwrap();
+    assert_eq!(stream.status, StreamStatus::Active);
+    assert_eq!(stream.last_withdraw, START);
+}
+
+#[test]
+fn test_withdraw_mid_stream_pays_vested_only() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(START + 30);
+    assert_eq!(s.client.withdraw_stream(&s.stream_id), 300);
+    assert_eq!(s.token.balance(&s.recipient), 300);
+
+    // Nothing new has vested within the same second.
+    assert_eq!(s.client.withdraw_stream(&s.stream_id), 0);
+
+    env.ledger().set_timestamp(START + 45);
+    assert_eq!(s.client.withdraw_stream(&s.stream_id), 150);
+    assert_eq!(s.client.get_stream(&s.stream_id).unwrap().withdrawn, 450);
+}
+
+#[test]
+fn test_withdraw_after_stop_caps_at_stream_total() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(START + 40);
+    s.client.withdraw_stream(&s.stream_id);
+
+    env.ledger().set_timestamp(STOP + 500);
+    assert_eq!(s.client.withdraw_stream(&s.stream_id), 600);
+    assert_eq!(s.token.balance(&s.recipient), 1_000);
+    assert_eq!(
+        s.client.get_stream(&s.stream_id).unwrap().status,
+        StreamStatus::Completed
+    );
+}
+
+#[test]
+fn test_cancel_mid_stream_refunds_unvested() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(START + 25);
+    let refund = s.client.cancel_stream(&s.stream_id, &1);
+
+    assert_eq!(refund, 750);
+    assert_eq!(s.token.balance(&s.recipient), 250);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_750);
+    assert_eq!(
+        s.client.get_stream(&s.stream_id).unwrap().status,
+        StreamStatus::Cancelled
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_cancelled_stream_cannot_be_withdrawn() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.cancel_stream(&s.stream_id, &1);
+    s.client.withdraw_stream(&s.stream_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_stream_larger_than_balance_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .create_stream(&s.program_id, &s.recipient, &100, &START, &STOP, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_withdraw_blocked_while_release_paused() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_paused(&None, &Some(true), &None, &None);
+    env.ledger().set_timestamp(START + 30);
+    s.client.withdraw_stream(&s.stream_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_cancel_blocked_while_release_paused() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_paused(&None, &Some(true), &None, &None);
+    env.ledger().set_timestamp(START + 30);
+    s.client.cancel_stream(&s.stream_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+fn test_withdraw_respects_daily_outflow_limit() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_daily_outflow_limit(&200, &0);
+    env.ledger().set_timestamp(START + 30);
+    s.client.withdraw_stream(&s.stream_id);
+}
@@ -0,0 +1,187 @@
+#![cfg(test)]
+
+//! # Payout Delegation Tests — Program Escrow
+//!
+//! A cold `authorized_payout_key` can delegate day-to-day payouts to a hot
+//! key until an expiry, and revoke that delegation at any time. Delegated
+//! payouts spend the authorized key's nonce, and the cold key can still pay
+//! through `single_payout_by` while a delegate is active.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+    payout_key: Address,
+    delegate: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "delegated");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        program_id,
+        payout_key,
+        delegate: Address::generate(env),
+    }
+}
+
+/// Pays `amount` to a fresh recipient with only the delegate's signature.
+fn pay_as_delegate(env: &Env, s: &Setup, amount: i128) -> ProgramData {
+    let recipient = Address::generate(env);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.delegate,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "single_payout",
+                args: (s.program_id.clone(), recipient.clone(), amount, u64::MAX).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .single_payout(&s.program_id, &recipient, &amount, &u64::MAX)
+}
+
+#[test]
+fn test_valid_delegate_can_pay_out() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 1);
+
+    let data = pay_as_delegate(&env, &s, 500);
+    assert_eq!(data.remaining_balance, 9_500);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 2);
+}
+
+#[test]
+fn test_cold_key_can_pay_while_delegate_active() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+    let recipient = Address::generate(&env);
+    let data = s.client.mock_auths(&[MockAuth {
+        address: &s.payout_key,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "single_payout_by",
+            args: (
+                s.payout_key.clone(),
+                s.program_id.clone(),
+                recipient.clone(),
+                500_i128,
+                u64::MAX,
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }])
+    .single_payout_by(&s.payout_key, &s.program_id, &recipient, &500, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 9_500);
+}
+
+#[test]
+fn test_scoped_delegate_payout_spends_payout_key_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &500, &u64::MAX);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 2);
+    assert_eq!(s.client.get_nonce(&s.delegate), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_payout_by_stranger_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.single_payout_by(
+        &Address::generate(&env),
+        &s.program_id,
+        &Address::generate(&env),
+        &500,
+        &u64::MAX,
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_expired_delegate_cannot_pay_out() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+    env.ledger().set_timestamp(2_000);
+
+    pay_as_delegate(&env, &s, 500);
+}
+
+#[test]
+#[should_panic]
+fn test_revocation_takes_effect_immediately() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+    s.client.revoke_delegate(&s.program_id, &1);
+    assert_eq!(s.client.get_delegate(&s.program_id), None);
+
+    pay_as_delegate(&env, &s, 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_delegate_expiry_in_past_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &1_000, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_set_delegate_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &2_000, &0);
+    s.client
+        .set_delegate(&s.program_id, &s.delegate, &3_000, &0);
+}
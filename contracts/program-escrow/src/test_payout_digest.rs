@@ -0,0 +1,114 @@
+#![cfg(test)]
+
+//! # Payout History Digest Tests — Program Escrow
+//!
+//! `payout_history_digest` must equal the hash chain recomputed from the
+//! payout records, and must depend on the order of those records.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String, Vec,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, Address, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(5_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    (client, token_addr, admin)
+}
+
+fn add_program(
+    env: &Env,
+    client: &ProgramEscrowContractClient<'static>,
+    token_addr: &Address,
+    admin: &Address,
+    name: &str,
+) -> String {
+    let program_id = String::from_str(env, name);
+    client.init_program(&program_id, &Address::generate(env), token_addr, admin, &None, &None);
+    token::StellarAssetClient::new(env, token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+    program_id
+}
+
+fn record(recipient: &Address, amount: i128, timestamp: u64) -> PayoutRecord {
+    PayoutRecord {
+        recipient: recipient.clone(),
+        amount,
+        timestamp,
+    }
+}
+
+#[test]
+fn test_digest_starts_at_zero() {
+    let env = Env::default();
+    let (client, token_addr, admin) = setup(&env);
+    let program_id = add_program(&env, &client, &token_addr, &admin, "digest-prog");
+
+    assert_eq!(
+        client.payout_history_digest(&program_id),
+        BytesN::from_array(&env, &[0u8; 32])
+    );
+}
+
+#[test]
+fn test_two_payouts_produce_deterministic_digest() {
+    let env = Env::default();
+    let (client, token_addr, admin) = setup(&env);
+    let program_id = add_program(&env, &client, &token_addr, &admin, "digest-prog");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.single_payout(&program_id, &alice, &100, &u64::MAX);
+    env.ledger().set_timestamp(5_500);
+    client.single_payout(&program_id, &bob, &250, &u64::MAX);
+
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let expected = payout_digest::fold(
+        &env,
+        &payout_digest::fold(&env, &zero, &record(&alice, 100, 5_000)),
+        &record(&bob, 250, 5_500),
+    );
+    assert_eq!(client.payout_history_digest(&program_id), expected);
+}
+
+#[test]
+fn test_payout_order_changes_digest() {
+    let env = Env::default();
+    let (client, token_addr, admin) = setup(&env);
+    let forward = add_program(&env, &client, &token_addr, &admin, "forward");
+    let reverse = add_program(&env, &client, &token_addr, &admin, "reverse");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let mut amounts = Vec::new(&env);
+    amounts.push_back(100);
+    amounts.push_back(100);
+
+    let mut ab = Vec::new(&env);
+    ab.push_back(alice.clone());
+    ab.push_back(bob.clone());
+    client.batch_payout(&forward, &ab, &amounts);
+
+    let mut ba = Vec::new(&env);
+    ba.push_back(bob);
+    ba.push_back(alice);
+    client.batch_payout(&reverse, &ba, &amounts);
+
+    assert_ne!(
+        client.payout_history_digest(&forward),
+        client.payout_history_digest(&reverse)
+    );
+}
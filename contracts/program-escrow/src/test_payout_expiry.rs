@@ -0,0 +1,64 @@
+#![cfg(test)]
+
+//! # Payout Expiry Tests — Program Escrow
+//!
+//! `single_payout` takes a `valid_until` deadline so a signed payout that
+//! sits in a queue cannot be submitted after it was meant to lapse.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const NOW: u64 = 50_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "expiry-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_payout_before_deadline_accepted() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let data = client.single_payout(&program_id, &Address::generate(&env), &500, &(NOW + 60));
+    assert_eq!(data.remaining_balance, 9_500);
+}
+
+#[test]
+fn test_payout_at_deadline_accepted() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let data = client.single_payout(&program_id, &Address::generate(&env), &500, &NOW);
+    assert_eq!(data.remaining_balance, 9_500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+fn test_payout_after_deadline_rejected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &500, &(NOW - 1));
+}
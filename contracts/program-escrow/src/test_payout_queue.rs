@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+//! # Payout Queue Tests — Program Escrow
+//!
+//! `enqueue_payout` appends to a per-program FIFO and `process_queue`
+//! drains it in order, stopping at the first entry that cannot be paid
+//! right now.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env, funding: i128) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "queue");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &funding);
+    client.lock_program_funds(&program_id, &funding);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+    }
+}
+
+#[test]
+fn test_queue_drains_in_fifo_order_across_calls() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let recipients = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for (nonce, recipient) in recipients.iter().enumerate() {
+        let len = s
+            .client
+            .enqueue_payout(&s.program_id, recipient, &(100 * (nonce as i128 + 1)), &(nonce as u64));
+        assert_eq!(len, nonce as u32 + 1);
+    }
+
+    assert_eq!(s.client.process_queue(&s.program_id, &2), 2);
+    assert_eq!(s.token.balance(&recipients[0]), 100);
+    assert_eq!(s.token.balance(&recipients[1]), 200);
+    assert_eq!(s.token.balance(&recipients[2]), 0);
+    assert_eq!(s.client.get_payout_queue(&s.program_id).len(), 1);
+
+    assert_eq!(s.client.process_queue(&s.program_id, &2), 1);
+    assert_eq!(s.token.balance(&recipients[2]), 300);
+    assert!(s.client.get_payout_queue(&s.program_id).is_empty());
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_400);
+}
+
+#[test]
+fn test_processing_stops_at_unpayable_head() {
+    let env = Env::default();
+    let s = setup(&env, 1_000);
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+
+    s.client.enqueue_payout(&s.program_id, &first, &400, &0);
+    s.client.enqueue_payout(&s.program_id, &second, &700, &1);
+
+    assert_eq!(s.client.process_queue(&s.program_id, &10), 1);
+    assert_eq!(s.token.balance(&first), 400);
+
+    let queue = s.client.get_payout_queue(&s.program_id);
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue.get(0).unwrap().recipient, second);
+    assert_eq!(s.client.process_queue(&s.program_id, &10), 0);
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+//! # Payout Receipt Tests — Program Escrow
+//!
+//! `single_payout_v2` pays exactly like `single_payout` but returns a
+//! compact `PayoutReceipt` instead of the full `ProgramData`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "receipt");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id, payout_key)
+}
+
+#[test]
+fn test_receipt_fields_after_payouts() {
+    let env = Env::default();
+    let (client, program_id, payout_key) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    let first = client.single_payout_v2(&program_id, &alice, &300, &u64::MAX);
+    assert_eq!(
+        first,
+        PayoutReceipt {
+            payout_index: 0,
+            signer: payout_key.clone(),
+            new_nonce: 1,
+            remaining_balance: 9_700,
+            timestamp: 1_000,
+        }
+    );
+
+    env.ledger().set_timestamp(1_500);
+    let second = client.single_payout_v2(&program_id, &bob, &200, &u64::MAX);
+    assert_eq!(second.payout_index, 1);
+    assert_eq!(second.new_nonce, client.get_nonce(&payout_key));
+    assert_eq!(second.remaining_balance, 9_500);
+    assert_eq!(second.timestamp, 1_500);
+
+    assert_eq!(client.get_payout_at(&program_id, &second.payout_index).recipient, bob);
+}
+
+#[test]
+fn test_delegated_receipt_reports_nonce_it_spent() {
+    let env = Env::default();
+    let (client, program_id, payout_key) = setup(&env);
+    let delegate = Address::generate(&env);
+    client.set_delegate(&program_id, &delegate, &5_000, &0);
+
+    let receipt = client.single_payout_v2(&program_id, &Address::generate(&env), &300, &u64::MAX);
+    assert_eq!(receipt.signer, delegate);
+    assert_eq!(receipt.new_nonce, 2);
+    assert_eq!(receipt.new_nonce, client.get_nonce(&payout_key));
+
+    // The reported nonce is the one the delegate signs next
+    let next = client.single_payout_v2(&program_id, &Address::generate(&env), &200, &u64::MAX);
+    assert_eq!(next.new_nonce, receipt.new_nonce + 1);
+}
@@ -120,7 +120,7 @@ fn test_set_split_config_success_two_beneficiaries() {
 }
 
 #[test]
-#[should_panic(expected = "SplitConfig: shares must sum to 10000 basis points")]
+#[should_panic(expected = "Error(Contract, #10)")]
 fn test_set_split_config_rejects_wrong_sum() {
     let s = setup();
     let env = &s.env;
@@ -150,7 +150,7 @@ fn test_set_split_config_rejects_wrong_sum() {
 }
 
 #[test]
-#[should_panic(expected = "SplitConfig: must have at least one beneficiary")]
+#[should_panic(expected = "Error(Contract, #10)")]
 fn test_set_split_config_rejects_empty() {
     let s = setup();
     let env = &s.env;
@@ -173,7 +173,7 @@ fn test_set_split_config_rejects_empty() {
 }
 
 #[test]
-#[should_panic(expected = "SplitConfig: share_bps must be positive")]
+#[should_panic(expected = "Error(Contract, #10)")]
 fn test_set_split_config_rejects_zero_share() {
     let s = setup();
     let env = &s.env;
@@ -366,7 +366,7 @@ fn test_partial_release_respects_ratio() {
 }
 
 #[test]
-#[should_panic(expected = "SplitPayout: insufficient escrow balance")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_execute_split_payout_insufficient_balance() {
     let env = Env::default();
     env.mock_all_auths();
@@ -400,7 +400,7 @@ fn test_execute_split_payout_insufficient_balance() {
 }
 
 #[test]
-#[should_panic(expected = "SplitPayout: split config is disabled")]
+#[should_panic(expected = "Error(Contract, #10)")]
 fn test_execute_split_payout_disabled_config() {
     let env = Env::default();
     env.mock_all_auths();
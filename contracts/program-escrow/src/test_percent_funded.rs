@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+//! # Percent-Funded Event Tests — Program Escrow
+//!
+//! When a program has a `max_total_funds` cap, each `FundsLocked` event
+//! carries `percent_funded_bps` for funding progress bars.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, String, TryFromVal,
+};
+
+const CAP: i128 = 8_000;
+
+fn setup(env: &Env, cap: i128) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "progress");
+    client.initialize_program(
+        &program_id,
+        &Address::generate(env),
+        &token_addr,
+        &admin,
+        &None,
+        &None,
+        &false,
+        &cap,
+        &0,
+        &ProgramStorageTier::Instance,
+    );
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &CAP);
+
+    (client, program_id)
+}
+
+fn last_lock_event(env: &Env) -> FundsLockedEvent {
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .map_or(false, |t| Symbol::try_from_val(env, &t).map_or(false, |s| s == FUNDS_LOCKED))
+        })
+        .last()
+        .expect("no FundsLocked event");
+    FundsLockedEvent::try_from_val(env, &data).unwrap()
+}
+
+#[test]
+fn test_partial_then_full_funding() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, CAP);
+
+    client.lock_program_funds(&program_id, &2_000);
+    assert_eq!(last_lock_event(&env).percent_funded_bps, Some(2_500));
+
+    client.lock_program_funds(&program_id, &6_000);
+    assert_eq!(last_lock_event(&env).percent_funded_bps, Some(10_000));
+}
+
+#[test]
+fn test_disbursed_funds_still_count_as_funded() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, CAP);
+
+    client.lock_program_funds(&program_id, &4_000);
+    client.single_payout(&program_id, &Address::generate(&env), &3_000, &u64::MAX);
+    client.lock_program_funds(&program_id, &2_000);
+
+    let event = last_lock_event(&env);
+    assert_eq!(event.remaining_balance, 3_000);
+    assert_eq!(event.percent_funded_bps, Some(7_500));
+}
+
+#[test]
+fn test_uncapped_program_omits_percent() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 0);
+
+    client.lock_program_funds(&program_id, &2_000);
+    assert_eq!(last_lock_event(&env).percent_funded_bps, None);
+}
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+//! # Fee Preview Tests — Program Escrow
+//!
+//! `preview_fee` reports the `(fee, net)` split of a payout up front and
+//! must agree with what `single_payout` actually transfers.
+
+use super::*;
+use crate::token_math::RoundingMode;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    fee_recipient: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "preview");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let fee_recipient = Address::generate(env);
+    client.update_fee_config(&None, &Some(250), &Some(fee_recipient.clone()), &Some(true));
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        fee_recipient,
+        program_id,
+    }
+}
+
+#[test]
+fn test_preview_matches_single_payout_split() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_rounding_mode(&RoundingMode::Ceil, &0);
+    let recipient = Address::generate(&env);
+
+    // 2.5% of 999 is 24.975; ceil rounds the fee up to 25.
+    let (fee, net) = s.client.preview_fee(&999);
+    assert_eq!((fee, net), (25, 974));
+
+    s.client.single_payout(&s.program_id, &recipient, &999, &u64::MAX);
+    assert_eq!(s.token.balance(&recipient), net);
+    assert_eq!(s.token.balance(&s.fee_recipient), fee);
+}
+
+#[test]
+fn test_preview_is_fee_free_when_disabled() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.update_fee_config(&None, &None, &None, &Some(false));
+
+    assert_eq!(s.client.preview_fee(&999), (0, 999));
+}
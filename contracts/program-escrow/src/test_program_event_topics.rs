@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Per-Program Event Topic Tests — Program Escrow
+//!
+//! `FUNDS_LOCKED`, `PAYOUT` and payout-path breach events carry the
+//! program id as a topic so indexers can subscribe per program. Payloads
+//! are unchanged.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    token, Address, Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "indexed");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+
+    (client, program_id)
+}
+
+/// Topics and data of the most recent event whose first topic is `name`.
+fn last_event(env: &Env, name: Symbol) -> (Vec<Val>, Val) {
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let first = topics.get(0).unwrap();
+        if Symbol::try_from_val(env, &first).map_or(false, |s| s == name) {
+            found = Some((topics, data));
+        }
+    }
+    found.unwrap()
+}
+
+#[test]
+fn test_lock_and_payout_topics_carry_program_id() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.lock_program_funds(&program_id, &10_000);
+    let (topics, data) = last_event(&env, FUNDS_LOCKED);
+    assert_eq!(topics.len(), 2);
+    assert_eq!(String::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), program_id);
+    let locked = FundsLockedEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(locked.program_id, program_id);
+    assert_eq!(locked.amount, 10_000);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&program_id, &recipient, &400, &u64::MAX);
+    let (topics, data) = last_event(&env, PAYOUT);
+    assert_eq!(String::try_from_val(&env, &topics.get(1).unwrap()).unwrap(), program_id);
+    let payout = PayoutEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(payout.recipient, recipient);
+    assert_eq!(payout.amount, 400);
+}
+
+#[test]
+fn test_program_breach_event_topics() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    env.as_contract(&client.address, || {
+        let breach = threshold_monitor::ThresholdBreach {
+            metric_type: Symbol::new(&env, "outflow"),
+            threshold_value: 1_000,
+            actual_value: 1_500,
+            timestamp: 0,
+            breach_count: 1,
+        };
+        threshold_monitor::emit_program_threshold_breach_event(&env, &program_id, &breach);
+    });
+
+    let (_, topics, _) = env.events().all().last().unwrap();
+    let expected: Vec<Val> = (
+        Symbol::new(&env, "th_breach"),
+        Symbol::new(&env, "outflow"),
+        program_id,
+    )
+        .into_val(&env);
+    assert_eq!(topics, expected);
+}
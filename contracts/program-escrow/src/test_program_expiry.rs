@@ -0,0 +1,118 @@
+#![cfg(test)]
+
+//! # Program Expiry Tests — Program Escrow
+//!
+//! Once a program's `program_expiry` passes, anyone can call
+//! `execute_expiry` to return its unused balance to the auto-reclaim
+//! destination and close it to further funding.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const EXPIRY: u64 = 50_000;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    destination: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(10_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let owner = Address::generate(env);
+    client.initialize_contract(&Address::generate(env));
+
+    let program_id = String::from_str(env, "expiring");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &owner, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let destination = Address::generate(env);
+    client.set_program_expiry(&program_id, &EXPIRY, &0);
+    client.set_auto_reclaim_destination(&program_id, &destination, &1);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        destination,
+    }
+}
+
+#[test]
+fn test_expiry_before_deadline_fails() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(EXPIRY - 1);
+    assert_eq!(
+        s.client.try_execute_expiry(&s.program_id),
+        Err(Ok(EscrowError::ProgramNotExpired))
+    );
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
+
+#[test]
+fn test_expiry_after_deadline_reclaims_and_closes() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &2_500, &u64::MAX);
+
+    env.ledger().set_timestamp(EXPIRY);
+    assert_eq!(s.client.execute_expiry(&s.program_id), 7_500);
+
+    assert_eq!(s.token.balance(&s.destination), 7_500);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+    assert_eq!(
+        s.client.try_execute_expiry(&s.program_id),
+        Err(Ok(EscrowError::AlreadyProcessed))
+    );
+    assert_eq!(
+        s.client.try_lock_program_funds(&s.program_id, &100),
+        Err(Ok(EscrowError::Expired))
+    );
+}
+
+#[test]
+fn test_program_without_expiry_cannot_be_expired() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_program_expiry(&s.program_id, &0, &2);
+
+    env.ledger().set_timestamp(EXPIRY * 2);
+    assert_eq!(
+        s.client.try_execute_expiry(&s.program_id),
+        Err(Ok(EscrowError::ProgramNotExpired))
+    );
+}
+
+#[test]
+fn test_expiry_settings_frozen_after_finalize() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.finalize_program(&0);
+
+    assert_eq!(
+        s.client.try_set_program_expiry(&s.program_id, &(EXPIRY * 2), &2),
+        Err(Ok(EscrowError::Finalized))
+    );
+    assert_eq!(
+        s.client
+            .try_set_auto_reclaim_destination(&s.program_id, &Address::generate(&env), &2),
+        Err(Ok(EscrowError::Finalized))
+    );
+}
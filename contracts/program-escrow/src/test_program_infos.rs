@@ -0,0 +1,46 @@
+#![cfg(test)]
+
+//! # Bulk Program Info Tests — Program Escrow
+//!
+//! `get_program_infos` returns one `ProgramSummary` per existing id in a
+//! single call and skips ids that were never initialized.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+#[test]
+fn test_infos_for_two_real_and_one_missing_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &15_000);
+
+    let alpha = String::from_str(&env, "alpha");
+    let beta = String::from_str(&env, "beta");
+    for id in [alpha.clone(), beta.clone()] {
+        client.init_program(&id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    }
+    client.lock_program_funds(&alpha, &10_000);
+    client.lock_program_funds(&beta, &5_000);
+    client.single_payout(&beta, &Address::generate(&env), &1_200, &u64::MAX);
+
+    let ids = vec![&env, alpha.clone(), String::from_str(&env, "missing"), beta.clone()];
+    let infos = client.get_program_infos(&ids);
+
+    assert_eq!(infos.len(), 2);
+    assert_eq!(infos.get(0).unwrap(), client.get_program_summary(&alpha));
+    let second = infos.get(1).unwrap();
+    assert_eq!(second.program_id, beta);
+    assert_eq!(second.remaining_balance, 3_800);
+    assert_eq!(second.total_disbursed, 1_200);
+    assert_eq!(second.payout_count, 1);
+}
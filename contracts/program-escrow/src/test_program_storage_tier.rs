@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+//! # Program Storage Tier Tests — Program Escrow
+//!
+//! `initialize_program` can keep a program's `ProgramData` in instance or
+//! persistent storage; every later read and write uses the chosen tier.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn run_lifecycle(env: &Env, tier: ProgramStorageTier) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "tiered");
+    client.initialize_program(
+        &program_id,
+        &Address::generate(env),
+        &token_addr,
+        &admin,
+        &None,
+        &None,
+        &false,
+        &0,
+        &0,
+        &tier,
+    );
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+    client.single_payout(&program_id, &Address::generate(env), &1_000, &u64::MAX);
+    client.batch_payout(
+        &program_id,
+        &vec![env, Address::generate(env), Address::generate(env)],
+        &vec![env, 500_i128, 250],
+    );
+
+    (client, program_id)
+}
+
+fn assert_stored_in(env: &Env, client: &ProgramEscrowContractClient, program_id: &String, tier: ProgramStorageTier) {
+    let key = DataKey::Program(program_id.clone());
+    env.as_contract(&client.address, || {
+        let in_instance = env.storage().instance().has(&key);
+        let in_persistent = env.storage().persistent().has(&key);
+        assert_eq!(in_instance, tier == ProgramStorageTier::Instance);
+        assert_eq!(in_persistent, tier == ProgramStorageTier::Persistent);
+    });
+}
+
+#[test]
+fn test_instance_tier_reads_and_writes() {
+    let env = Env::default();
+    let (client, program_id) = run_lifecycle(&env, ProgramStorageTier::Instance);
+
+    assert_eq!(client.get_program_storage_tier(&program_id), ProgramStorageTier::Instance);
+    assert_stored_in(&env, &client, &program_id, ProgramStorageTier::Instance);
+    assert_eq!(client.get_remaining_balance(&program_id), 8_250);
+    assert_eq!(client.get_program_summary(&program_id).payout_count, 3);
+}
+
+#[test]
+fn test_persistent_tier_reads_and_writes() {
+    let env = Env::default();
+    let (client, program_id) = run_lifecycle(&env, ProgramStorageTier::Persistent);
+
+    assert_eq!(client.get_program_storage_tier(&program_id), ProgramStorageTier::Persistent);
+    assert_stored_in(&env, &client, &program_id, ProgramStorageTier::Persistent);
+    assert!(client.program_exists(&program_id));
+    assert_eq!(client.get_remaining_balance(&program_id), 8_250);
+    let summary = client.get_program_summary(&program_id);
+    assert_eq!(summary.payout_count, 3);
+    assert_eq!(summary.total_disbursed, 1_750);
+}
+
+#[test]
+fn test_default_init_uses_instance_tier() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let program_id = String::from_str(&env, "default-tier");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+
+    assert_eq!(client.get_program_storage_tier(&program_id), ProgramStorageTier::Instance);
+}
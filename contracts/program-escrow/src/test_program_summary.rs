@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+//! # Program Summary Tests — Program Escrow
+//!
+//! `get_program_summary` mirrors the headline `ProgramData` figures without
+//! shipping the payout history.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "summary-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_summary_matches_full_program_data() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    let full = client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 500_i128, 250],
+    );
+
+    let summary = client.get_program_summary(&program_id);
+    assert_eq!(summary.program_id, full.program_id);
+    assert_eq!(summary.total_funds, full.total_funds);
+    assert_eq!(summary.remaining_balance, full.remaining_balance);
+    assert_eq!(summary.total_disbursed, 1_750);
+    assert_eq!(summary.payout_count, full.payout_history.len());
+    assert!(!summary.paused);
+    assert!(!summary.breaker_open);
+}
+
+#[test]
+fn test_summary_reports_pause() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.pause(&client.get_admin().unwrap(), &String::from_str(&env, "maintenance"));
+    assert!(client.get_program_summary(&program_id).paused);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_summary_of_unknown_program_panics() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    client.get_program_summary(&String::from_str(&env, "missing"));
+}
@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+//! # History Retention Tests — Program Escrow
+//!
+//! `prune_history` keeps only the newest `retention_count` payout records
+//! while `payout_count` and `total_disbursed` stay accurate.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "retention");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+fn history(env: &Env, client: &ProgramEscrowContractClient, program_id: &String) -> Vec<PayoutRecord> {
+    env.as_contract(&client.address, || {
+        let data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap();
+        data.payout_history
+    })
+}
+
+#[test]
+fn test_prune_keeps_newest_records_and_counters() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    for amount in [100_i128, 200, 300, 400, 500] {
+        client.single_payout(&program_id, &Address::generate(&env), &amount, &u64::MAX);
+    }
+
+    client.set_retention_count(&program_id, &2, &0);
+    assert_eq!(client.prune_history(&program_id, &1), 3);
+
+    let kept = history(&env, &client, &program_id);
+    assert_eq!(kept.len(), 2);
+    assert_eq!(kept.get(0).unwrap().amount, 400);
+    assert_eq!(kept.get(1).unwrap().amount, 500);
+
+    let summary = client.get_program_summary(&program_id);
+    assert_eq!(summary.payout_count, 5);
+    assert_eq!(summary.total_disbursed, 1_500);
+
+    // Later payouts keep counting on top of the pruned records.
+    client.single_payout(&program_id, &Address::generate(&env), &50, &u64::MAX);
+    assert_eq!(client.get_program_summary(&program_id).payout_count, 6);
+}
+
+#[test]
+fn test_prune_is_noop_without_retention() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(client.prune_history(&program_id, &0), 0);
+    assert_eq!(history(&env, &client, &program_id).len(), 1);
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+//! # Raffle Payout Tests — Program Escrow
+//!
+//! `raffle_payout` draws the winner from `sha256(seed || ledger sequence ||
+//! nonce)`, so the same inputs always pick the same candidate.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, String, Vec,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    candidates: Vec<Address>,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(4_242);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "raffle");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    let mut candidates = Vec::new(env);
+    for _ in 0..5 {
+        candidates.push_back(Address::generate(env));
+    }
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        candidates,
+    }
+}
+
+fn paid_index(s: &Setup) -> Option<u32> {
+    (0..s.candidates.len()).find(|&i| s.token.balance(&s.candidates.get(i).unwrap()) > 0)
+}
+
+#[test]
+fn test_fixed_seed_picks_expected_winner() {
+    let env = Env::default();
+    let s = setup(&env);
+    let seed = BytesN::from_array(&env, &[1; 32]);
+
+    let expected = raffle_winner_index(&env, &seed, 0, s.candidates.len());
+    s.client
+        .raffle_payout(&s.program_id, &s.candidates, &700, &seed, &0);
+
+    assert_eq!(paid_index(&s), Some(expected));
+    assert_eq!(s.token.balance(&s.candidates.get(expected).unwrap()), 700);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_300);
+}
+
+#[test]
+fn test_changing_seed_changes_winner() {
+    let env = Env::default();
+    let s = setup(&env);
+    let count = s.candidates.len();
+
+    let base = raffle_winner_index(&env, &BytesN::from_array(&env, &[1; 32]), 0, count);
+    let other_seed = (2..=u8::MAX)
+        .map(|b| BytesN::from_array(&env, &[b; 32]))
+        .find(|seed| raffle_winner_index(&env, seed, 0, count) != base)
+        .expect("some seed draws a different candidate");
+
+    s.client
+        .raffle_payout(&s.program_id, &s.candidates, &700, &other_seed, &0);
+
+    let winner = paid_index(&s).unwrap();
+    assert_ne!(winner, base);
+    assert_eq!(winner, raffle_winner_index(&env, &other_seed, 0, count));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_empty_candidate_pool_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.raffle_payout(
+        &s.program_id,
+        &Vec::new(&env),
+        &700,
+        &BytesN::from_array(&env, &[1; 32]),
+        &0,
+    );
+}
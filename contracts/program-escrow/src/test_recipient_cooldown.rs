@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+//! # Recipient Cooldown Tests — Program Escrow
+//!
+//! `set_recipient_cooldown` spaces out payouts to the same address; other
+//! recipients are unaffected.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+const COOLDOWN: u64 = 600;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_recipient_cooldown(&COOLDOWN, &0);
+
+    let program_id = String::from_str(env, "cooldown");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_repeat_payout_within_cooldown_fails() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let bot = Address::generate(&env);
+
+    client.single_payout(&program_id, &bot, &100, &u64::MAX);
+    assert_eq!(client.get_recipient_last_payout(&bot), Some(1_000));
+
+    env.ledger().set_timestamp(1_000 + COOLDOWN - 1);
+    let result = client.try_single_payout(&program_id, &bot, &100, &u64::MAX);
+    assert_eq!(result, Err(Ok(EscrowError::RateLimitExceeded)));
+
+    let result = client.try_batch_payout(&program_id, &vec![&env, bot.clone()], &vec![&env, 100_i128]);
+    assert_eq!(result, Err(Ok(EscrowError::RateLimitExceeded)));
+}
+
+#[test]
+fn test_other_recipient_is_unaffected() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    let data = client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 9_800);
+}
+
+#[test]
+fn test_payout_allowed_after_cooldown() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let alice = Address::generate(&env);
+
+    client.single_payout(&program_id, &alice, &100, &u64::MAX);
+    env.ledger().set_timestamp(1_000 + COOLDOWN);
+    let data = client.single_payout(&program_id, &alice, &100, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 9_800);
+    assert_eq!(client.get_recipient_last_payout(&alice), Some(1_000 + COOLDOWN));
+}
@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+//! # Recipient Stats Tests — Program Escrow
+//!
+//! Every `single_payout` / `batch_payout` bumps the recipient's lifetime
+//! payout count and total received, matching what filtering the payout
+//! history would give.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "stats-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_unknown_recipient_has_empty_stats() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    assert_eq!(client.get_recipient_stats(&Address::generate(&env)), (0, 0));
+}
+
+#[test]
+fn test_repeated_payouts_accumulate() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let contributor = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.single_payout(&program_id, &contributor, &100, &u64::MAX);
+    client.single_payout(&program_id, &contributor, &250, &u64::MAX);
+    let data = client.batch_payout(
+        &program_id,
+        &vec![&env, contributor.clone(), other.clone()],
+        &vec![&env, 50_i128, 75],
+    );
+
+    assert_eq!(client.get_recipient_stats(&contributor), (3, 400));
+    assert_eq!(client.get_recipient_stats(&other), (1, 75));
+
+    // Derived state agrees with the payout history.
+    let mut count = 0u32;
+    let mut total = 0i128;
+    for record in data.payout_history.iter() {
+        if record.recipient == contributor {
+            count += 1;
+            total += record.amount;
+        }
+    }
+    assert_eq!((count, total), (3, 400));
+}
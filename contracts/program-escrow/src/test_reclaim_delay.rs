@@ -0,0 +1,126 @@
+#![cfg(test)]
+
+//! # Reclaim Delay Tests — Program Escrow
+//!
+//! With `set_reclaim_delay`, `withdraw_excess` and `batch_reclaim` only move
+//! a program's funds once `reclaim_delay_secs` have passed since its last
+//! lock.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+const DELAY: u64 = 3_600;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    owner: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    client.initialize_contract(&Address::generate(env));
+    client.set_reclaim_delay(&DELAY, &0);
+
+    let owner = Address::generate(env);
+    let program_id = String::from_str(env, "cool-off");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &owner, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &5_000);
+    client.lock_program_funds(&program_id, &5_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        owner,
+        program_id,
+    }
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "fat-finger")
+}
+
+#[test]
+fn test_withdraw_excess_too_soon_fails() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(1_000 + DELAY - 1);
+    let result = s.client.try_withdraw_excess(
+        &s.program_id,
+        &1_000,
+        &Address::generate(&env),
+        &reason(&env),
+        &1,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::ReclaimTooSoon)));
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 5_000);
+}
+
+#[test]
+fn test_withdraw_excess_succeeds_after_delay() {
+    let env = Env::default();
+    let s = setup(&env);
+    let destination = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    let data = s
+        .client
+        .withdraw_excess(&s.program_id, &1_000, &destination, &reason(&env), &1);
+
+    assert_eq!(data.remaining_balance, 4_000);
+    assert_eq!(s.token.balance(&destination), 1_000);
+}
+
+#[test]
+fn test_new_lock_restarts_delay() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    token::StellarAssetClient::new(&env, &s.token.address).mint(&s.client.address, &500);
+    s.client.lock_program_funds(&s.program_id, &500);
+
+    let result = s.client.try_withdraw_excess(
+        &s.program_id,
+        &1_000,
+        &Address::generate(&env),
+        &reason(&env),
+        &1,
+    );
+    assert_eq!(result, Err(Ok(EscrowError::ReclaimTooSoon)));
+}
+
+#[test]
+fn test_batch_reclaim_skips_until_delay_elapses() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    let ids = vec![&env, s.program_id.clone()];
+
+    let total = s
+        .client
+        .batch_reclaim(&s.owner, &ids, &treasury, &reason(&env), &0);
+    assert_eq!(total, 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 5_000);
+
+    env.ledger().set_timestamp(1_000 + DELAY);
+    let total = s
+        .client
+        .batch_reclaim(&s.owner, &ids, &treasury, &reason(&env), &1);
+    assert_eq!(total, 5_000);
+    assert_eq!(s.token.balance(&treasury), 5_000);
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+//! # Reclaim Split Tests — Program Escrow
+//!
+//! `reclaim_split` returns a program's leftover balance to several
+//! destinations by basis-point share, with rounding dust going to the last
+//! destination.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let owner = Address::generate(env);
+    client.initialize_contract(&Address::generate(env));
+
+    let program_id = String::from_str(env, "splitting");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &owner, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_001);
+    client.lock_program_funds(&program_id, &10_001);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+    }
+}
+
+#[test]
+fn test_two_way_split_gives_dust_to_last() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    let reclaimed = s.client.reclaim_split(
+        &s.program_id,
+        &vec![&env, treasury.clone(), sponsor.clone()],
+        &vec![&env, 3_333_u32, 6_667_u32],
+        &0,
+    );
+
+    assert_eq!(reclaimed, 10_001);
+    assert_eq!(s.token.balance(&treasury), 3_333);
+    assert_eq!(s.token.balance(&sponsor), 6_668);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+}
+
+#[test]
+fn test_shares_not_summing_to_full_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let treasury = Address::generate(&env);
+    let sponsor = Address::generate(&env);
+
+    assert_eq!(
+        s.client.try_reclaim_split(
+            &s.program_id,
+            &vec![&env, treasury.clone(), sponsor.clone()],
+            &vec![&env, 6_000_u32, 3_000_u32],
+            &0,
+        ),
+        Err(Ok(EscrowError::InvalidConfig))
+    );
+    assert_eq!(
+        s.client.try_reclaim_split(
+            &s.program_id,
+            &vec![&env, treasury.clone(), sponsor],
+            &vec![&env, 10_000_u32, 0_u32],
+            &0,
+        ),
+        Err(Ok(EscrowError::InvalidConfig))
+    );
+
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_001);
+    assert_eq!(s.token.balance(&treasury), 0);
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+
+//! # Reconciliation Tests — Program Escrow
+//!
+//! `reconcile` compares the contract's token balance with the balances the
+//! escrow tracks, surfacing direct transfers as surplus.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+#[test]
+fn test_reconcile_reports_direct_transfer_surplus() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_sac = token::StellarAssetClient::new(&env, &token_addr);
+
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+    for (name, amount) in [("recon-a", 3_000_i128), ("recon-b", 2_000)] {
+        let program_id = String::from_str(&env, name);
+        client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+        token_sac.mint(&client.address, &amount);
+        client.lock_program_funds(&program_id, &amount);
+    }
+    let program_a = String::from_str(&env, "recon-a");
+    client.single_payout(&program_a, &Address::generate(&env), &500, &u64::MAX);
+
+    assert_eq!(client.reconcile(&token_addr), (4_500, 4_500, 0));
+
+    token_sac.mint(&client.address, &750);
+    assert_eq!(client.reconcile(&token_addr), (5_250, 4_500, 750));
+}
+
+#[test]
+fn test_reconcile_tracks_reservations_and_pending_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    let token_sac = token::StellarAssetClient::new(&env, &token_addr);
+
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+    let program_id = String::from_str(&env, "recon-res");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token_sac.mint(&client.address, &5_000);
+    client.lock_program_funds(&program_id, &5_000);
+
+    let recipient = Address::generate(&env);
+    let stream_id = client.create_stream(&program_id, &recipient, &10, &1_000, &1_100, &0);
+    client.create_recurring(&program_id, &recipient, &200, &60, &3, &1);
+    assert_eq!(client.get_remaining_balance(&program_id), 3_400);
+    assert_eq!(client.reconcile(&token_addr), (5_000, 5_000, 0));
+
+    let depositor = Address::generate(&env);
+    token_sac.mint(&depositor, &700);
+    client.prepare_lock(&program_id, &depositor, &700);
+    assert_eq!(client.reconcile(&token_addr), (5_700, 5_700, 0));
+
+    env.ledger().set_timestamp(1_050);
+    client.withdraw_stream(&stream_id);
+    client.cancel_stream(&stream_id, &2);
+    assert_eq!(client.reconcile(&token_addr), (5_200, 5_200, 0));
+}
@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+//! # Recurring Payout Tests — Program Escrow
+//!
+//! `create_recurring` reserves a stipend schedule; anyone may trigger
+//! `execute_recurring` once an installment is due, and `cancel_recurring`
+//! returns the unpaid remainder to the program. Installments pass the same
+//! pause, breaker and outflow gates as `single_payout`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const INTERVAL: u64 = 86_400;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    recipient: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "stipend");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        recipient: Address::generate(env),
+    }
+}
+
+#[test]
+fn test_installments_follow_cadence() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .create_recurring(&s.program_id, &s.recipient, &500, &INTERVAL, &3, &0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 8_500);
+
+    for n in 1..=3u32 {
+        env.ledger().set_timestamp(1_000 + INTERVAL * n as u64);
+        let recurring = s.client.execute_recurring(&id);
+        assert_eq!(recurring.paid, n);
+    }
+
+    assert_eq!(s.token.balance(&s.recipient), 1_500);
+    let recurring = s.client.get_recurring(&id).unwrap();
+    assert_eq!(recurring.status, StreamStatus::Completed);
+    assert_eq!(
+        s.client.try_execute_recurring(&id),
+        Err(Ok(EscrowError::AlreadyProcessed))
+    );
+}
+
+#[test]
+fn test_early_execution_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .create_recurring(&s.program_id, &s.recipient, &500, &INTERVAL, &3, &0);
+
+    env.ledger().set_timestamp(1_000 + INTERVAL - 1);
+    assert_eq!(
+        s.client.try_execute_recurring(&id),
+        Err(Ok(EscrowError::InstallmentNotDue))
+    );
+
+    env.ledger().set_timestamp(1_000 + INTERVAL);
+    s.client.execute_recurring(&id);
+    assert_eq!(
+        s.client.try_execute_recurring(&id),
+        Err(Ok(EscrowError::InstallmentNotDue))
+    );
+    assert_eq!(s.token.balance(&s.recipient), 500);
+}
+
+#[test]
+fn test_cancel_refunds_unpaid_installments() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .create_recurring(&s.program_id, &s.recipient, &500, &INTERVAL, &4, &0);
+    env.ledger().set_timestamp(1_000 + INTERVAL);
+    s.client.execute_recurring(&id);
+
+    let refund = s.client.cancel_recurring(&id, &1);
+
+    assert_eq!(refund, 1_500);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 9_500);
+    assert_eq!(s.client.get_recurring(&id).unwrap().status, StreamStatus::Cancelled);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_reservation_cannot_exceed_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client
+        .create_recurring(&s.program_id, &s.recipient, &5_000, &INTERVAL, &3, &0);
+}
+
+#[test]
+fn test_paused_contract_blocks_installment() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .create_recurring(&s.program_id, &s.recipient, &500, &INTERVAL, &3, &0);
+    s.client.pause(&s.client.get_admin().unwrap(), &String::from_str(&env, "incident"));
+
+    env.ledger().set_timestamp(1_000 + INTERVAL);
+    assert_eq!(
+        s.client.try_execute_recurring(&id),
+        Err(Ok(EscrowError::FundsPaused))
+    );
+    assert_eq!(s.token.balance(&s.recipient), 0);
+}
+
+#[test]
+fn test_open_breaker_blocks_installment() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s
+        .client
+        .create_recurring(&s.program_id, &s.recipient, &500, &INTERVAL, &3, &0);
+    env.as_contract(&s.client.address, || error_recovery::open_circuit(&env));
+
+    env.ledger().set_timestamp(1_000 + INTERVAL);
+    assert_eq!(
+        s.client.try_execute_recurring(&id),
+        Err(Ok(EscrowError::CircuitBreakerOpen))
+    );
+    assert_eq!(s.token.balance(&s.recipient), 0);
+}
@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+//! # Threshold Config Default-Deny Tests — Program Escrow
+//!
+//! With `require_threshold_config` set at contract init, payouts are refused
+//! until `set_threshold_config` is called. Without it, the built-in default
+//! thresholds apply immediately.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+fn setup(env: &Env, require_threshold_config: bool) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract_with_options(&admin, &require_threshold_config);
+
+    let program_id = String::from_str(env, "deny-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_default_posture_allows_payouts_without_config() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, false);
+
+    let data = client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(data.remaining_balance, 9_900);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+fn test_default_deny_blocks_single_payout() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, true);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+fn test_default_deny_blocks_batch_payout() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, true);
+
+    client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+}
+
+#[test]
+fn test_default_deny_lifts_once_config_is_set() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, true);
+
+    client.set_threshold_config(&threshold_monitor::ThresholdConfig::default(), &0);
+
+    let data = client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(data.remaining_balance, 9_900);
+    let data = client.batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 100_i128],
+    );
+    assert_eq!(data.remaining_balance, 9_800);
+}
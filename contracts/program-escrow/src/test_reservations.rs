@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+//! # Reservation Listing Tests — Program Escrow
+//!
+//! `get_reservations` itemizes what a program's funds are committed to:
+//! unreleased release schedules, active streams and recurring payouts.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    symbol_short, token, Address, Env, String,
+};
+
+const NOW: u64 = 1_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(NOW);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "reserved");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_schedule_and_vesting_stream_listed() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.create_program_release_schedule(&program_id, &1_500, &(NOW + 500), &Address::generate(&env));
+    let stream_id = client.create_stream(&program_id, &Address::generate(&env), &5, &NOW, &(NOW + 200), &0);
+
+    let reservations = client.get_reservations(&program_id, &0, &10);
+    assert_eq!(reservations.len(), 2);
+    assert_eq!(
+        reservations.get(0).unwrap(),
+        Reservation {
+            kind: symbol_short!("schedule"),
+            id: 1,
+            amount: 1_500,
+            unlock_ts: NOW + 500,
+        }
+    );
+    assert_eq!(
+        reservations.get(1).unwrap(),
+        Reservation {
+            kind: symbol_short!("stream"),
+            id: stream_id,
+            amount: 1_000,
+            unlock_ts: NOW + 200,
+        }
+    );
+}
+
+#[test]
+fn test_reservations_shrink_and_paginate() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let recurring_id = client.create_recurring(&program_id, &Address::generate(&env), &100, &60, &3, &0);
+    let stream_id = client.create_stream(&program_id, &Address::generate(&env), &5, &NOW, &(NOW + 200), &1);
+
+    env.ledger().set_timestamp(NOW + 60);
+    client.execute_recurring(&recurring_id);
+    client.cancel_stream(&stream_id, &2);
+
+    let reservations = client.get_reservations(&program_id, &0, &10);
+    assert_eq!(reservations.len(), 1);
+    let recurring = reservations.get(0).unwrap();
+    assert_eq!(recurring.kind, symbol_short!("recurring"));
+    assert_eq!(recurring.amount, 200);
+    assert_eq!(recurring.unlock_ts, NOW + 180);
+
+    assert_eq!(client.get_reservations(&program_id, &1, &10).len(), 0);
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+//! # Rounding Policy Tests — Program Escrow
+//!
+//! The configured `RoundingMode` drives fee and share-split division. Each
+//! mode is checked against fractional results, and split totals must never
+//! exceed the amount being distributed.
+
+use super::*;
+use crate::payout_splits::{self, BeneficiarySplit};
+use crate::token_math::{self, RoundingMode};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+// ── mul_div ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_floor_rounds_down() {
+    // 999 * 100 / 10_000 = 9.99
+    assert_eq!(token_math::mul_div(999, 100, 10_000, RoundingMode::Floor), Some(9));
+}
+
+#[test]
+fn test_ceil_rounds_up() {
+    assert_eq!(token_math::mul_div(999, 100, 10_000, RoundingMode::Ceil), Some(10));
+    // Exact results are untouched.
+    assert_eq!(token_math::mul_div(1_000, 100, 10_000, RoundingMode::Ceil), Some(10));
+}
+
+#[test]
+fn test_bankers_round_half_to_even() {
+    // 2.5 -> 2, 3.5 -> 4, 9.99 -> 10, 9.01 -> 9
+    assert_eq!(token_math::mul_div(25, 1, 10, RoundingMode::BankersRound), Some(2));
+    assert_eq!(token_math::mul_div(35, 1, 10, RoundingMode::BankersRound), Some(4));
+    assert_eq!(token_math::mul_div(999, 100, 10_000, RoundingMode::BankersRound), Some(10));
+    assert_eq!(token_math::mul_div(901, 100, 10_000, RoundingMode::BankersRound), Some(9));
+}
+
+#[test]
+fn test_fee_never_exceeds_amount_in_any_mode() {
+    for mode in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::BankersRound] {
+        for amount in [1_i128, 3, 999, 10_001] {
+            let fee = token_math::calculate_fee_rounded(amount, token_math::MAX_FEE_RATE, mode);
+            assert!(fee >= 0 && fee <= amount);
+        }
+    }
+}
+
+// ── Contract integration ─────────────────────────────────────────────────────
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "rounding-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+
+    client.update_fee_config(&Some(100), &None, &Some(Address::generate(env)), &Some(true));
+
+    (client, program_id)
+}
+
+#[test]
+fn test_default_mode_is_floor() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    assert_eq!(client.get_rounding_mode(), RoundingMode::Floor);
+
+    // 1% of 999 is 9.99; floor keeps the fee at 9.
+    let data = client.lock_program_funds(&program_id, &999);
+    assert_eq!(data.total_funds, 990);
+}
+
+#[test]
+fn test_ceil_mode_applies_to_fees() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_rounding_mode(&RoundingMode::Ceil, &0);
+
+    let data = client.lock_program_funds(&program_id, &999);
+    assert_eq!(data.total_funds, 989);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_set_rounding_mode_rejects_stale_nonce() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    client.set_rounding_mode(&RoundingMode::Ceil, &1);
+}
+
+#[test]
+fn test_split_totals_never_exceed_source_in_any_mode() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    // Split helpers operate on the legacy single-program slot.
+    env.as_contract(&client.address, || {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&DataKey::Program(program_id.clone()))
+            .unwrap();
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+    });
+
+    let beneficiaries = vec![
+        &env,
+        BeneficiarySplit { recipient: Address::generate(&env), share_bps: 3_333 },
+        BeneficiarySplit { recipient: Address::generate(&env), share_bps: 3_333 },
+        BeneficiarySplit { recipient: Address::generate(&env), share_bps: 3_334 },
+    ];
+
+    for (nonce, mode) in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::BankersRound]
+        .into_iter()
+        .enumerate()
+    {
+        client.set_rounding_mode(&mode, &(nonce as u64));
+        env.as_contract(&client.address, || {
+            payout_splits::set_split_config(&env, &program_id, beneficiaries.clone());
+            let preview = payout_splits::preview_split(&env, &program_id, 101);
+
+            let mut total = 0_i128;
+            for entry in preview.iter() {
+                assert!(entry.share_bps >= 0);
+                total += entry.share_bps;
+            }
+            assert_eq!(total, 101);
+        });
+    }
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+//! # Post-Breach Safe Mode Tests — Program Escrow
+//!
+//! Once window outflow reaches `outflow_volume_threshold`, safe mode keeps
+//! payouts whitelist-only for `safe_mode_duration_secs`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String, Address) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_admin(&admin);
+    client.set_threshold_config(
+        &threshold_monitor::ThresholdConfig {
+            failure_rate_threshold: 10,
+            outflow_volume_threshold: 1_000,
+            max_single_payout: 10_000,
+            time_window_secs: 600,
+            cooldown_period_secs: 300,
+            cooldown_multiplier: 2,
+            warn_ratio_bps: 0,
+            window_max_single_outflow: 0,
+        },
+        &0,
+    );
+    client.set_safe_mode_config(
+        &SafeModeConfig {
+            safe_mode_after_breach: true,
+            safe_mode_duration_secs: 3_600,
+        },
+        &1,
+    );
+
+    let program_id = String::from_str(env, "safe-mode");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id, admin)
+}
+
+#[test]
+fn test_safe_mode_restricts_payouts_after_breach() {
+    let env = Env::default();
+    let (client, program_id, _admin) = setup(&env);
+    let trusted = Address::generate(&env);
+    client.set_whitelist(&trusted, &true);
+
+    assert!(!client.is_safe_mode_active());
+    client.single_payout(&program_id, &Address::generate(&env), &600, &u64::MAX);
+    client.single_payout(&program_id, &Address::generate(&env), &500, &u64::MAX);
+    assert!(client.is_safe_mode_active());
+
+    // The outflow window has rolled over, but safe mode is still on.
+    env.ledger().set_timestamp(1_700);
+    let rejected = client.try_single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(rejected, Err(Ok(EscrowError::RecipientNotWhitelisted)));
+    client.single_payout(&program_id, &trusted, &100, &u64::MAX);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    assert!(!client.is_safe_mode_active());
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(client.get_remaining_balance(&program_id), 8_700);
+}
+
+#[test]
+fn test_safe_mode_off_by_default() {
+    let env = Env::default();
+    let (client, program_id, _admin) = setup(&env);
+    client.set_safe_mode_config(&SafeModeConfig::default(), &2);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    env.ledger().set_timestamp(1_700);
+
+    assert!(!client.is_safe_mode_active());
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+}
@@ -0,0 +1,115 @@
+#![cfg(test)]
+
+//! # Scoped Payout Authorization Tests — Program Escrow
+//!
+//! Under `PayoutAuthMode::ScopedArgs` the payout key signs
+//! `(recipient, amount, nonce)`, so an authorization cannot be replayed for
+//! a different recipient, amount or nonce.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+    payout_key: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_auth_mode(&PayoutAuthMode::ScopedArgs, &0);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "scoped-auth");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        program_id,
+        payout_key,
+    }
+}
+
+/// Pays `amount` to `recipient` with an auth entry scoped to
+/// `(signed_recipient, amount, nonce)`.
+fn pay_with_scoped_auth(
+    env: &Env,
+    s: &Setup,
+    signed_recipient: &Address,
+    recipient: &Address,
+    amount: i128,
+    nonce: u64,
+) -> ProgramData {
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.payout_key,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "single_payout",
+                args: (signed_recipient.clone(), amount, nonce).into_val(env),
+                sub_invokes: &[],
+            },
+        }])
+        .single_payout(&s.program_id, recipient, &amount, &u64::MAX)
+}
+
+#[test]
+fn test_default_mode_is_invocation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_auth_mode(), PayoutAuthMode::Invocation);
+}
+
+#[test]
+fn test_scoped_auth_pays_signed_recipient_and_consumes_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let data = pay_with_scoped_auth(&env, &s, &recipient, &recipient, 500, 0);
+
+    assert_eq!(data.remaining_balance, 9_500);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_scoped_auth_cannot_pay_different_recipient() {
+    let env = Env::default();
+    let s = setup(&env);
+    let signed = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    pay_with_scoped_auth(&env, &s, &signed, &attacker, 500, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_scoped_auth_cannot_be_replayed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    pay_with_scoped_auth(&env, &s, &recipient, &recipient, 500, 0);
+    // The same signed intent no longer matches the advanced nonce.
+    pay_with_scoped_auth(&env, &s, &recipient, &recipient, 500, 0);
+}
@@ -0,0 +1,79 @@
+#![cfg(test)]
+
+//! # Self-Payout Prevention Tests — Program Escrow
+//!
+//! With `forbid_self_payout` on (the default), payouts to the program's
+//! payout key, owner or the escrow itself are rejected.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    payout_key: Address,
+    owner: Address,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    client.initialize_contract(&Address::generate(env));
+
+    let payout_key = Address::generate(env);
+    let owner = Address::generate(env);
+    let program_id = String::from_str(env, "self-pay");
+    client.init_program(&program_id, &payout_key, &token_addr, &owner, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        payout_key,
+        owner,
+        program_id,
+    }
+}
+
+#[test]
+fn test_self_payouts_rejected_by_default() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(s.client.get_forbid_self_payout());
+
+    for recipient in [s.payout_key.clone(), s.owner.clone(), s.client.address.clone()] {
+        assert_eq!(
+            s.client.try_single_payout(&s.program_id, &recipient, &100, &u64::MAX),
+            Err(Ok(EscrowError::SelfPayout))
+        );
+    }
+    assert_eq!(
+        s.client.try_batch_payout(
+            &s.program_id,
+            &vec![&env, Address::generate(&env), s.payout_key.clone()],
+            &vec![&env, 100_i128, 100],
+        ),
+        Err(Ok(EscrowError::SelfPayout))
+    );
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
+
+#[test]
+fn test_paying_payout_key_allowed_when_disabled() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.set_forbid_self_payout(&false, &0);
+    let data = s
+        .client
+        .single_payout(&s.program_id, &s.payout_key, &100, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 9_900);
+}
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+//! # Signed Payout Tests — Program Escrow
+//!
+//! `single_payout_signed` lets a relayer submit a payout authorized by an
+//! off-chain ed25519 signature from the program's payout key.
+
+extern crate std;
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, token, xdr, Address, Env, String, TryFromVal};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    signing_key: SigningKey,
+    payout_key: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    // The payout key is the account whose ed25519 key signs off-chain.
+    let signing_key = SigningKey::from_bytes(&[7; 32]);
+    let account_id = xdr::AccountId(xdr::PublicKey::PublicKeyTypeEd25519(xdr::Uint256(
+        signing_key.verifying_key().to_bytes(),
+    )));
+    let payout_key = Address::try_from_val(env, &xdr::ScAddress::Account(account_id)).unwrap();
+
+    env.mock_all_auths();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    let program_id = String::from_str(env, "signed");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+    // From here on no auth is mocked: only the signature authorizes payouts.
+    env.set_auths(&[]);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        signing_key,
+        payout_key,
+    }
+}
+
+fn sign(env: &Env, s: &Setup, recipient: &Address, amount: i128, nonce: u64) -> BytesN<64> {
+    let message = (
+        s.program_id.clone(),
+        recipient.clone(),
+        amount,
+        nonce,
+        s.client.address.clone(),
+    )
+        .to_xdr(env);
+    let mut buf = std::vec![0u8; message.len() as usize];
+    message.copy_into_slice(&mut buf);
+    BytesN::from_array(env, &s.signing_key.sign(&buf).to_bytes())
+}
+
+fn pubkey(env: &Env, s: &Setup) -> BytesN<32> {
+    BytesN::from_array(env, &s.signing_key.verifying_key().to_bytes())
+}
+
+#[test]
+fn test_relayer_submits_valid_signature() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let signature = sign(&env, &s, &recipient, 1_500, 0);
+    let data = s.client.single_payout_signed(
+        &s.program_id,
+        &recipient,
+        &1_500,
+        &0,
+        &signature,
+        &pubkey(&env, &s),
+    );
+
+    assert_eq!(data.remaining_balance, 8_500);
+    assert_eq!(s.token.balance(&recipient), 1_500);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_tampered_amount_fails_verification() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let signature = sign(&env, &s, &recipient, 1_500, 0);
+    s.client.single_payout_signed(
+        &s.program_id,
+        &recipient,
+        &9_000,
+        &0,
+        &signature,
+        &pubkey(&env, &s),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_signature_cannot_be_replayed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let signature = sign(&env, &s, &recipient, 1_000, 0);
+    let key = pubkey(&env, &s);
+    s.client
+        .single_payout_signed(&s.program_id, &recipient, &1_000, &0, &signature, &key);
+    s.client
+        .single_payout_signed(&s.program_id, &recipient, &1_000, &0, &signature, &key);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_foreign_pubkey_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+    let recipient = Address::generate(&env);
+
+    let other = SigningKey::from_bytes(&[9; 32]);
+    let signature = sign(&env, &s, &recipient, 1_000, 0);
+    s.client.single_payout_signed(
+        &s.program_id,
+        &recipient,
+        &1_000,
+        &0,
+        &signature,
+        &BytesN::from_array(&env, &other.verifying_key().to_bytes()),
+    );
+}
@@ -0,0 +1,123 @@
+#![cfg(test)]
+
+//! # Signer Rate Limit Tests — Program Escrow
+//!
+//! `set_rate_limit` enforces a minimum spacing between consecutive payouts
+//! authorized by the same signer, bounding how fast a compromised payout
+//! key can drain a program.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String, Vec,
+};
+
+const INTERVAL: u64 = 300;
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+    payout_key: Address,
+}
+
+fn setup(env: &Env, initial_balance: i128) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "rate-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    client.set_rate_limit(&payout_key, &INTERVAL, &0);
+
+    Setup {
+        client,
+        program_id,
+        payout_key,
+    }
+}
+
+#[test]
+fn test_rate_limit_is_recorded_per_signer() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    let limit = s.client.get_rate_limit(&s.payout_key).unwrap();
+    assert_eq!(limit.min_interval_secs, INTERVAL);
+    assert_eq!(limit.last_payout_ts, None);
+    assert_eq!(s.client.get_rate_limit(&Address::generate(&env)), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_second_payout_too_soon_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    env.ledger().set_timestamp(1_000 + INTERVAL - 1);
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &100, &u64::MAX);
+}
+
+#[test]
+fn test_payout_after_interval_succeeds() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    env.ledger().set_timestamp(1_000 + INTERVAL);
+    let data = s
+        .client
+        .single_payout(&s.program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    assert_eq!(data.remaining_balance, 9_800);
+    assert_eq!(
+        s.client.get_rate_limit(&s.payout_key).unwrap().last_payout_ts,
+        Some(1_000 + INTERVAL)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_batch_payout_shares_signer_interval() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client
+        .single_payout(&s.program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    let mut recipients = Vec::new(&env);
+    recipients.push_back(Address::generate(&env));
+    let mut amounts = Vec::new(&env);
+    amounts.push_back(100);
+    s.client.batch_payout(&s.program_id, &recipients, &amounts);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_set_rate_limit_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client.set_rate_limit(&s.payout_key, &60, &0);
+}
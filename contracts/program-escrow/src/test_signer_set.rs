@@ -0,0 +1,62 @@
+#![cfg(test)]
+
+//! # Signer Set Tests — Program Escrow
+//!
+//! `get_signer_set` lists a program's multisig signers together with
+//! their current nonces, so the whole set can be audited in one call.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+#[test]
+fn test_signer_set_reflects_nonces() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let admin = Address::generate(&env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(&env, "multisig");
+    client.init_program(&program_id, &Address::generate(&env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(&env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+    assert_eq!(client.get_signer_set(&program_id).len(), 0);
+
+    let (alice, bob, carol) = (
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    );
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &DataKey::MultisigConfig(program_id.clone()),
+            &MultisigConfig {
+                threshold_amount: i128::MAX,
+                signers: vec![&env, alice.clone(), bob.clone(), carol.clone()],
+                required_signatures: 2,
+            },
+        );
+    });
+
+    // Bob co-signs two entries, advancing only his nonce
+    client.multi_signer_batch(
+        &program_id,
+        &vec![
+            &env,
+            (bob.clone(), Address::generate(&env), 100_i128, 0_u64),
+            (bob.clone(), Address::generate(&env), 100_i128, 1_u64),
+        ],
+    );
+
+    assert_eq!(
+        client.get_signer_set(&program_id),
+        vec![&env, (alice, 0_u64), (bob, 2_u64), (carol, 0_u64)]
+    );
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Outflow Spike Detection Tests — Program Escrow
+//!
+//! `window_max_single_outflow` caps the largest single payout within a
+//! window; a payout above it is refused before any transfer, even while
+//! aggregate outflow is well under `outflow_volume_threshold`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup(env: &Env, window_max_single_outflow: i128) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_threshold_config(
+        &threshold_monitor::ThresholdConfig {
+            failure_rate_threshold: 10,
+            outflow_volume_threshold: 10_000,
+            max_single_payout: 10_000,
+            time_window_secs: 600,
+            cooldown_period_secs: 300,
+            cooldown_multiplier: 2,
+            warn_ratio_bps: 0,
+            window_max_single_outflow,
+        },
+        &0,
+    );
+
+    let program_id = String::from_str(env, "spike");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_spike_under_aggregate_limit_is_refused() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+
+    client.single_payout(&program_id, &Address::generate(&env), &300, &u64::MAX);
+    let spike = client.try_single_payout(&program_id, &Address::generate(&env), &1_500, &u64::MAX);
+    assert_eq!(spike, Err(Ok(EscrowError::SinglePayoutExceeded)));
+    assert_eq!(client.get_remaining_balance(&program_id), 9_700);
+}
+
+#[test]
+fn test_spike_inside_batch_is_refused() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+
+    let spike = client.try_batch_payout(
+        &program_id,
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 300_i128, 1_500],
+    );
+    assert_eq!(spike, Err(Ok(EscrowError::SinglePayoutExceeded)));
+    assert_eq!(client.get_remaining_balance(&program_id), 10_000);
+}
+
+#[test]
+fn test_payout_at_limit_does_not_trip() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 1_000);
+
+    client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    let data = client.single_payout(&program_id, &Address::generate(&env), &1_000, &u64::MAX);
+    assert_eq!(data.remaining_balance, 8_000);
+}
+
+#[test]
+fn test_zero_disables_spike_detection() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env, 0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &5_000, &u64::MAX);
+    let data = client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(data.remaining_balance, 4_900);
+}
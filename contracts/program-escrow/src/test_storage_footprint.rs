@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+//! # Storage Footprint Tests — Program Escrow
+//!
+//! `get_storage_footprint` reports maintained counters of payouts, distinct
+//! recipients and release schedules.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "footprint");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+#[test]
+fn test_empty_footprint() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    assert_eq!(client.get_storage_footprint(), StorageFootprint::default());
+}
+
+#[test]
+fn test_footprint_counts_payouts_recipients_and_schedules() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    client.single_payout(&program_id, &alice, &100, &u64::MAX);
+    client.single_payout(&program_id, &alice, &100, &u64::MAX);
+    client.batch_payout(
+        &program_id,
+        &vec![&env, alice.clone(), bob.clone()],
+        &vec![&env, 50_i128, 50],
+    );
+
+    client.create_program_release_schedule(&program_id, &500, &2_000, &bob);
+    client.create_program_release_schedule(&program_id, &500, &3_000, &alice);
+
+    assert_eq!(
+        client.get_storage_footprint(),
+        StorageFootprint {
+            payout_count: 4,
+            recipient_count: 2,
+            schedule_count: 2,
+        }
+    );
+}
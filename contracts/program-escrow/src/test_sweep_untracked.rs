@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+//! # Sweep Untracked Funds Tests — Program Escrow
+//!
+//! `sweep_untracked` credits direct transfers into the contract to a
+//! program so they become spendable.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, token::StellarAssetClient<'static>, String) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "donations");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token_sac.mint(&client.address, &1_000);
+    client.lock_program_funds(&program_id, &1_000);
+
+    (client, token_sac, program_id)
+}
+
+#[test]
+fn test_sweep_credits_direct_donation() {
+    let env = Env::default();
+    let (client, token_sac, program_id) = setup(&env);
+
+    token_sac.mint(&client.address, &400);
+    assert_eq!(client.sweep_untracked(&program_id, &0), 400);
+
+    let summary = client.get_program_summary(&program_id);
+    assert_eq!(summary.remaining_balance, 1_400);
+    assert_eq!(summary.total_funds, 1_400);
+    assert_eq!(client.reconcile(&token_sac.address).2, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+fn test_sweep_without_surplus_rejected() {
+    let env = Env::default();
+    let (client, _token_sac, program_id) = setup(&env);
+
+    client.sweep_untracked(&program_id, &0);
+}
+
+#[test]
+fn test_sweep_leaves_pending_prepared_lock_escrow() {
+    let env = Env::default();
+    let (client, token_sac, program_id) = setup(&env);
+    let depositor = Address::generate(&env);
+    token_sac.mint(&depositor, &600);
+    let lock_id = client.prepare_lock(&program_id, &depositor, &600);
+    token_sac.mint(&client.address, &250);
+
+    assert_eq!(client.sweep_untracked(&program_id, &0), 250);
+    assert_eq!(client.get_remaining_balance(&program_id), 1_250);
+
+    client.abort_lock(&lock_id, &1);
+    let token = token::Client::new(&env, &token_sac.address);
+    assert_eq!(token.balance(&depositor), 600);
+    assert_eq!(client.reconcile(&token_sac.address), (1_250, 1_250, 0));
+}
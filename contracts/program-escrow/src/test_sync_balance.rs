@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+//! # Balance Sync Tests — Program Escrow
+//!
+//! After an issuer claws tokens back from the escrow, `sync_balance`
+//! lowers the program's `remaining_balance` to what the ledger actually
+//! holds. It never raises it.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, IssuerFlags},
+    token, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token_sac: token::StellarAssetClient<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let sac = env.register_stellar_asset_contract_v2(Address::generate(env));
+    sac.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let token_sac = token::StellarAssetClient::new(env, &sac.address());
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "synced");
+    client.init_program(&program_id, &Address::generate(env), &sac.address(), &admin, &None, &None);
+    token_sac.mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    Setup {
+        client,
+        token_sac,
+        program_id,
+    }
+}
+
+#[test]
+fn test_sync_after_external_clawback() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.token_sac.clawback(&s.client.address, &1_500);
+
+    assert_eq!(s.client.sync_balance(&s.program_id, &0), 1_500);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 8_500);
+    let (ledger, tracked, difference) = s.client.reconcile(&s.token_sac.address);
+    assert_eq!((ledger, tracked, difference), (8_500, 8_500, 0));
+
+    assert_eq!(s.client.sync_balance(&s.program_id, &1), 0);
+}
+
+#[test]
+fn test_sync_never_increases_balance() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.token_sac.mint(&s.client.address, &700);
+
+    assert_eq!(s.client.sync_balance(&s.program_id, &0), 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 10_000);
+}
+
+#[test]
+fn test_sync_counts_pending_prepared_locks_as_owed() {
+    let env = Env::default();
+    let s = setup(&env);
+    let depositor = Address::generate(&env);
+    s.token_sac.mint(&depositor, &500);
+    s.client.prepare_lock(&s.program_id, &depositor, &500);
+
+    s.token_sac.clawback(&s.client.address, &1_500);
+
+    assert_eq!(s.client.sync_balance(&s.program_id, &0), 1_500);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 8_500);
+    assert_eq!(s.client.reconcile(&s.token_sac.address).2, 0);
+}
@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Threshold Hot-Reload Tests — Program Escrow
+//!
+//! `set_threshold_config` keeps the current window's metrics and re-checks
+//! them against the new limits, so lowering a threshold below what has
+//! already flowed out opens the breaker straight away.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "hot-reload");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.as_contract(&client.address, || {
+        threshold_monitor::init_threshold_monitor(env);
+    });
+
+    (client, program_id)
+}
+
+fn config(outflow_volume_threshold: i128) -> threshold_monitor::ThresholdConfig {
+    threshold_monitor::ThresholdConfig {
+        failure_rate_threshold: 10,
+        outflow_volume_threshold,
+        max_single_payout: 10_000,
+        time_window_secs: 600,
+        cooldown_period_secs: 300,
+        cooldown_multiplier: 2,
+        warn_ratio_bps: 0,
+        window_max_single_outflow: 0,
+    }
+}
+
+fn window_outflow(env: &Env, client: &ProgramEscrowContractClient) -> i128 {
+    env.as_contract(&client.address, || {
+        threshold_monitor::get_current_metrics(env).total_outflow
+    })
+}
+
+#[test]
+fn test_lowering_outflow_threshold_trips_breaker() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_threshold_config(&config(5_000), &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &400, &u64::MAX);
+    client.single_payout(&program_id, &Address::generate(&env), &400, &u64::MAX);
+    assert_eq!(window_outflow(&env, &client), 800);
+
+    client.set_threshold_config(&config(500), &1);
+
+    assert_eq!(window_outflow(&env, &client), 800);
+    assert!(client.program_health_check(&program_id).breaker_open);
+    assert_eq!(
+        client.try_single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX),
+        Err(Ok(EscrowError::CircuitBreakerOpen))
+    );
+    assert_eq!(client.get_remaining_balance(&program_id), 9_200);
+}
+
+#[test]
+fn test_raising_threshold_keeps_metrics_and_breaker_closed() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_threshold_config(&config(1_000), &0);
+
+    client.single_payout(&program_id, &Address::generate(&env), &800, &u64::MAX);
+    client.set_threshold_config(&config(2_000), &1);
+
+    assert_eq!(window_outflow(&env, &client), 800);
+    assert!(!client.program_health_check(&program_id).breaker_open);
+    client.single_payout(&program_id, &Address::generate(&env), &800, &u64::MAX);
+    assert_eq!(window_outflow(&env, &client), 1_600);
+}
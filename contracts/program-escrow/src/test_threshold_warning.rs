@@ -0,0 +1,93 @@
+#![cfg(test)]
+
+//! # Threshold Warning Tests — Program Escrow
+//!
+//! With `warn_ratio_bps` set, crossing that share of the window's outflow
+//! threshold emits one `THRESHOLD_WARNING` without blocking payouts; the
+//! breaker still trips at 100%.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, String, Symbol, TryFromVal,
+};
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_threshold_config(
+        &threshold_monitor::ThresholdConfig {
+            failure_rate_threshold: 10,
+            outflow_volume_threshold: 1_000,
+            max_single_payout: 10_000,
+            time_window_secs: 600,
+            cooldown_period_secs: 300,
+            cooldown_multiplier: 2,
+            warn_ratio_bps: 8_000,
+            window_max_single_outflow: 0,
+        },
+        &0,
+    );
+
+    let program_id = String::from_str(env, "warned");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+fn warnings(env: &Env) -> usize {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics.get(0).map_or(false, |t| {
+                Symbol::try_from_val(env, &t).map_or(false, |s| s == threshold_monitor::THRESHOLD_WARNING)
+            })
+        })
+        .count()
+}
+
+#[test]
+fn test_warning_fires_once_then_breaker_trips() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &500, &u64::MAX);
+    assert_eq!(warnings(&env), 0);
+
+    // 850 of 1000 crosses the 80% mark: warn, but keep paying.
+    client.single_payout(&program_id, &Address::generate(&env), &350, &u64::MAX);
+    assert_eq!(warnings(&env), 1);
+
+    // Further outflow in the same window does not warn again.
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(warnings(&env), 1);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(client.get_remaining_balance(&program_id), 8_950);
+
+    let tripped = client.try_single_payout(&program_id, &Address::generate(&env), &10, &u64::MAX);
+    assert_eq!(tripped, Err(Ok(EscrowError::CircuitBreakerOpen)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_warn_ratio_above_100_percent_rejected() {
+    let env = Env::default();
+    let (client, _program_id) = setup(&env);
+
+    let mut config = client.get_threshold_config();
+    config.warn_ratio_bps = 10_001;
+    client.set_threshold_config(&config, &1);
+}
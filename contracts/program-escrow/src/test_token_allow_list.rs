@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+//! # Token Allow-List Tests — Program Escrow
+//!
+//! Once `add_allowed_token` has populated the list, only programs holding
+//! a listed token can be funded.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    approved: Address,
+    unapproved: Address,
+    approved_program: String,
+    unapproved_program: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let approved = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let unapproved = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let approved_program = String::from_str(env, "approved");
+    let unapproved_program = String::from_str(env, "unapproved");
+    for (program_id, token_addr) in [(&approved_program, &approved), (&unapproved_program, &unapproved)] {
+        client.init_program(program_id, &Address::generate(env), token_addr, &admin, &None, &None);
+        token::StellarAssetClient::new(env, token_addr).mint(&client.address, &1_000);
+    }
+
+    Setup {
+        client,
+        approved,
+        unapproved,
+        approved_program,
+        unapproved_program,
+    }
+}
+
+#[test]
+fn test_empty_list_allows_every_token() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    assert!(s.client.is_token_allowed(&s.unapproved));
+    s.client.lock_program_funds(&s.unapproved_program, &1_000);
+}
+
+#[test]
+fn test_only_listed_tokens_can_be_locked() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.add_allowed_token(&s.approved, &0);
+    assert!(s.client.is_token_allowed(&s.approved));
+    assert!(!s.client.is_token_allowed(&s.unapproved));
+
+    assert_eq!(
+        s.client.try_lock_program_funds(&s.unapproved_program, &1_000),
+        Err(Ok(EscrowError::TokenNotAllowed))
+    );
+    let data = s.client.lock_program_funds(&s.approved_program, &1_000);
+    assert_eq!(data.remaining_balance, 1_000);
+}
+
+#[test]
+fn test_removing_last_token_reopens_funding() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    s.client.add_allowed_token(&s.approved, &0);
+    s.client.remove_allowed_token(&s.approved, &1);
+
+    assert_eq!(s.client.get_allowed_tokens().len(), 0);
+    s.client.lock_program_funds(&s.unapproved_program, &1_000);
+}
@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+//! # Per-Token Threshold Tests — Program Escrow
+//!
+//! `set_threshold_config_for` gives a token its own outflow window, so heavy
+//! outflow in token A trips A's breaker while token B keeps paying out.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token_a: Address,
+    program_a: String,
+    program_b: String,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let token_a = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    let token_b = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let program_a = String::from_str(env, "token-a");
+    let program_b = String::from_str(env, "token-b");
+    for (program_id, token_addr) in [(&program_a, &token_a), (&program_b, &token_b)] {
+        client.init_program(program_id, &Address::generate(env), token_addr, &admin, &None, &None);
+        token::StellarAssetClient::new(env, token_addr).mint(&client.address, &10_000);
+        client.lock_program_funds(program_id, &10_000);
+    }
+
+    Setup {
+        client,
+        token_a,
+        program_a,
+        program_b,
+    }
+}
+
+fn tight_config() -> threshold_monitor::ThresholdConfig {
+    threshold_monitor::ThresholdConfig {
+        failure_rate_threshold: 10,
+        outflow_volume_threshold: 1_000,
+        max_single_payout: 10_000,
+        time_window_secs: 600,
+        cooldown_period_secs: 300,
+        cooldown_multiplier: 2,
+        warn_ratio_bps: 0,
+        window_max_single_outflow: 0,
+    }
+}
+
+#[test]
+fn test_token_a_outflow_trips_only_token_a() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_threshold_config_for(&s.token_a, &tight_config(), &0);
+
+    s.client
+        .single_payout(&s.program_a, &Address::generate(&env), &600, &u64::MAX);
+    s.client
+        .single_payout(&s.program_a, &Address::generate(&env), &500, &u64::MAX);
+    assert_eq!(s.client.get_window_metrics_for(&s.token_a).total_outflow, 1_100);
+
+    let tripped = s
+        .client
+        .try_single_payout(&s.program_a, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(tripped, Err(Ok(EscrowError::CircuitBreakerOpen)));
+
+    // Token B has no per-token config and keeps paying out.
+    for _ in 0..3 {
+        s.client
+            .single_payout(&s.program_b, &Address::generate(&env), &600, &u64::MAX);
+    }
+    assert_eq!(s.client.get_remaining_balance(&s.program_b), 8_200);
+}
+
+#[test]
+fn test_token_window_rolls_over() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_threshold_config_for(&s.token_a, &tight_config(), &0);
+
+    s.client
+        .single_payout(&s.program_a, &Address::generate(&env), &1_000, &u64::MAX);
+
+    env.ledger().set_timestamp(1_000 + tight_config().time_window_secs);
+    s.client
+        .single_payout(&s.program_a, &Address::generate(&env), &200, &u64::MAX);
+
+    let metrics = s.client.get_window_metrics_for(&s.token_a);
+    assert_eq!(metrics.window_start, 1_600);
+    assert_eq!(metrics.total_outflow, 200);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_invalid_token_config_rejected() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let mut config = tight_config();
+    config.outflow_volume_threshold = 0;
+    s.client.set_threshold_config_for(&s.token_a, &config, &0);
+}
@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+//! # Two-Phase Lock Tests — Program Escrow
+//!
+//! `prepare_lock` pulls tokens into a pending bucket; `commit_lock` credits
+//! them to the program and `abort_lock` refunds the depositor. Each
+//! prepared lock settles exactly once.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+    depositor: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "two-phase");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+
+    let depositor = Address::generate(env);
+    token::StellarAssetClient::new(env, &token_addr).mint(&depositor, &5_000);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+        depositor,
+    }
+}
+
+#[test]
+fn test_prepare_then_commit_credits_program() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.prepare_lock(&s.program_id, &s.depositor, &2_000);
+
+    // Tokens are held but not yet spendable by the program.
+    assert_eq!(s.token.balance(&s.depositor), 3_000);
+    assert_eq!(s.token.balance(&s.client.address), 2_000);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+    assert_eq!(
+        s.client.get_prepared_lock(&id).unwrap().status,
+        PreparedLockStatus::Pending
+    );
+
+    let data = s.client.commit_lock(&id, &0);
+
+    assert_eq!(data.remaining_balance, 2_000);
+    assert_eq!(data.total_funds, 2_000);
+    assert_eq!(
+        s.client.get_prepared_lock(&id).unwrap().status,
+        PreparedLockStatus::Committed
+    );
+}
+
+#[test]
+fn test_prepare_then_abort_refunds_depositor() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.prepare_lock(&s.program_id, &s.depositor, &2_000);
+    s.client.abort_lock(&id, &0);
+
+    assert_eq!(s.token.balance(&s.depositor), 5_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 0);
+    assert_eq!(
+        s.client.get_prepared_lock(&id).unwrap().status,
+        PreparedLockStatus::Aborted
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_committed_lock_cannot_be_aborted() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.prepare_lock(&s.program_id, &s.depositor, &2_000);
+    s.client.commit_lock(&id, &0);
+    s.client.abort_lock(&id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+fn test_aborted_lock_cannot_be_committed() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let id = s.client.prepare_lock(&s.program_id, &s.depositor, &2_000);
+    s.client.abort_lock(&id, &0);
+    s.client.commit_lock(&id, &1);
+}
+
+#[test]
+fn test_abort_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let first = s.client.prepare_lock(&s.program_id, &s.depositor, &1_000);
+    let second = s.client.prepare_lock(&s.program_id, &s.depositor, &1_000);
+    s.client.abort_lock(&first, &0);
+
+    assert_eq!(
+        s.client.try_abort_lock(&second, &0),
+        Err(Ok(EscrowError::InvalidNonce))
+    );
+    assert_eq!(
+        s.client.get_prepared_lock(&second).unwrap().status,
+        PreparedLockStatus::Pending
+    );
+}
+
+#[test]
+fn test_prepared_lock_ids_are_unique() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let first = s.client.prepare_lock(&s.program_id, &s.depositor, &1_000);
+    let second = s.client.prepare_lock(&s.program_id, &s.depositor, &1_000);
+    assert_ne!(first, second);
+}
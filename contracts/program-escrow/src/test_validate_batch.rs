@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+//! # Batch Pre-flight Tests — Program Escrow
+//!
+//! `validate_batch` reports per-entry validity and overall feasibility of a
+//! batch without auth, nonces or state changes.
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env, String};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    program_id: String,
+    payout_key: Address,
+    token_addr: Address,
+}
+
+fn setup(env: &Env) -> Setup {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "preflight");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &1_000);
+    client.lock_program_funds(&program_id, &1_000);
+
+    Setup {
+        client,
+        program_id,
+        payout_key,
+        token_addr,
+    }
+}
+
+#[test]
+fn test_mixed_batch_flags_only_invalid_entry() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let recipients = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let amounts = vec![&env, 100_i128, 0, 200];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, true, false, true]);
+    assert!(!feasible);
+    assert_eq!(s.client.get_nonce(&s.payout_key), 0);
+}
+
+#[test]
+fn test_valid_batch_is_feasible() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 400_i128, 600];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, true, true]);
+    assert!(feasible);
+    assert_eq!(s.client.get_remaining_balance(&s.program_id), 1_000);
+}
+
+#[test]
+fn test_batch_over_balance_is_infeasible() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 700_i128, 700];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, true, true]);
+    assert!(!feasible);
+}
+
+#[test]
+fn test_payout_to_escrow_itself_is_invalid() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let recipients = vec![&env, s.client.address.clone()];
+    let amounts = vec![&env, 100_i128];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, false]);
+    assert!(!feasible);
+}
+
+#[test]
+fn test_paused_contract_is_infeasible() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.pause(
+        &s.client.get_admin().unwrap(),
+        &String::from_str(&env, "incident"),
+    );
+
+    let recipients = vec![&env, Address::generate(&env)];
+    let amounts = vec![&env, 100_i128];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, true]);
+    assert!(!feasible);
+}
+
+#[test]
+fn test_repeat_recipient_under_cooldown_is_invalid() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.client.set_recipient_cooldown(&3_600, &0);
+
+    let alice = Address::generate(&env);
+    let recipients = vec![&env, alice.clone(), alice];
+    let amounts = vec![&env, 100_i128, 100];
+
+    let (entries, feasible) = s
+        .client
+        .validate_batch(&s.program_id, &recipients, &amounts);
+
+    assert_eq!(entries, vec![&env, true, false]);
+    assert!(!feasible);
+}
+
+#[test]
+fn test_batch_into_others_earmark_is_infeasible() {
+    let env = Env::default();
+    let s = setup(&env);
+
+    let donor = Address::generate(&env);
+    let alice = Address::generate(&env);
+    token::StellarAssetClient::new(&env, &s.token_addr).mint(&donor, &500);
+    s.client.lock_earmarked(&s.program_id, &donor, &alice, &500);
+
+    let bob = Address::generate(&env);
+    let (_, over) = s.client.validate_batch(
+        &s.program_id,
+        &vec![&env, bob.clone()],
+        &vec![&env, 1_200_i128],
+    );
+    assert!(!over);
+
+    let (_, fits) = s.client.validate_batch(
+        &s.program_id,
+        &vec![&env, alice, bob],
+        &vec![&env, 500_i128, 1_000],
+    );
+    assert!(fits);
+}
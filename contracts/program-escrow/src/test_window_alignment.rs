@@ -0,0 +1,83 @@
+#![cfg(test)]
+
+//! # Window Alignment Tests — Program Escrow
+//!
+//! With `WindowAlignment::Calendar`, outflow windows start on multiples of
+//! `time_window_secs` instead of whenever the previous window rolled over.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+const HOUR: u64 = 3_600;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_addr = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+    client.set_threshold_config(
+        &threshold_monitor::ThresholdConfig {
+            failure_rate_threshold: 10,
+            outflow_volume_threshold: 1_000_000,
+            max_single_payout: 100_000,
+            time_window_secs: HOUR,
+            cooldown_period_secs: 300,
+            cooldown_multiplier: 2,
+            warn_ratio_bps: 0,
+            window_max_single_outflow: 0,
+        },
+        &0,
+    );
+
+    let program_id = String::from_str(env, "aligned");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    (client, program_id)
+}
+
+fn window_start(env: &Env, client: &ProgramEscrowContractClient) -> u64 {
+    env.as_contract(&client.address, || {
+        threshold_monitor::get_current_metrics(env).window_start
+    })
+}
+
+#[test]
+fn test_calendar_windows_land_on_boundaries() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    client.set_window_alignment(&threshold_monitor::WindowAlignment::Calendar, &1);
+    assert_eq!(client.get_window_alignment(), threshold_monitor::WindowAlignment::Calendar);
+
+    // The window opened at init rotates out on the first payout past it
+    env.ledger().set_timestamp(2 * HOUR + 250);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(window_start(&env, &client), 2 * HOUR);
+
+    env.ledger().set_timestamp(3 * HOUR + 1_700);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(window_start(&env, &client), 3 * HOUR);
+}
+
+#[test]
+fn test_rolling_windows_start_at_rotation_time() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+    assert_eq!(client.get_window_alignment(), threshold_monitor::WindowAlignment::Rolling);
+
+    env.ledger().set_timestamp(2 * HOUR + 250);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    assert_eq!(window_start(&env, &client), 2 * HOUR + 250);
+}
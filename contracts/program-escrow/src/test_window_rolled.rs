@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+//! # Window Rollover Event Tests — Program Escrow
+//!
+//! When a payout crosses `time_window_secs`, the threshold monitor emits
+//! `WINDOW_ROLLED` with the closed window's accumulated `WindowMetrics`.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, Address, Env, String, Symbol, TryFromVal,
+};
+
+const START: u64 = 1_000;
+
+fn setup(env: &Env) -> (ProgramEscrowContractClient<'static>, String) {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(START);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let program_id = String::from_str(env, "window-prog");
+    client.init_program(&program_id, &Address::generate(env), &token_addr, &admin, &None, &None);
+    token::StellarAssetClient::new(env, &token_addr).mint(&client.address, &10_000);
+    client.lock_program_funds(&program_id, &10_000);
+
+    env.as_contract(&client.address, || {
+        threshold_monitor::init_threshold_monitor(env);
+    });
+
+    (client, program_id)
+}
+
+/// The `WindowMetrics` payload of the last `win_roll` event, if any.
+fn rolled_window(env: &Env) -> Option<threshold_monitor::WindowMetrics> {
+    let topic = Symbol::new(env, "win_roll");
+    let mut found = None;
+    for (_, topics, data) in env.events().all().iter() {
+        let first = topics.get(0).and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if first == Some(topic.clone()) {
+            found = threshold_monitor::WindowMetrics::try_from_val(env, &data).ok();
+        }
+    }
+    found
+}
+
+#[test]
+fn test_crossing_window_emits_prior_metrics() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    client.single_payout(&program_id, &Address::generate(&env), &250, &u64::MAX);
+    assert!(rolled_window(&env).is_none());
+
+    let window = client.get_threshold_config().time_window_secs;
+    env.ledger().set_timestamp(START + window);
+    client.single_payout(&program_id, &Address::generate(&env), &50, &u64::MAX);
+
+    let closed = rolled_window(&env).expect("window rolled");
+    assert_eq!(closed.window_start, START);
+    assert_eq!(closed.failure_count, 0);
+    assert_eq!(closed.success_count, 2);
+    assert_eq!(closed.total_outflow, 350);
+    assert_eq!(closed.max_single_outflow, 250);
+}
+
+#[test]
+fn test_no_rollover_within_window() {
+    let env = Env::default();
+    let (client, program_id) = setup(&env);
+
+    let window = client.get_threshold_config().time_window_secs;
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+    env.ledger().set_timestamp(START + window - 1);
+    client.single_payout(&program_id, &Address::generate(&env), &100, &u64::MAX);
+
+    assert!(rolled_window(&env).is_none());
+}
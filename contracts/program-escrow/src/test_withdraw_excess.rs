@@ -0,0 +1,125 @@
+#![cfg(test)]
+
+//! # Excess Withdrawal Tests — Program Escrow
+//!
+//! `withdraw_excess` lets the admin correct an over-funded program without
+//! touching funds already reserved by pending release schedules.
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, Env, String,
+};
+
+struct Setup {
+    client: ProgramEscrowContractClient<'static>,
+    token: token::Client<'static>,
+    program_id: String,
+}
+
+fn setup(env: &Env, initial_balance: i128) -> Setup {
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+
+    let token_admin = Address::generate(env);
+    let token_addr = env
+        .register_stellar_asset_contract_v2(token_admin)
+        .address();
+    let token_sac = token::StellarAssetClient::new(env, &token_addr);
+
+    let admin = Address::generate(env);
+    client.initialize_contract(&admin);
+
+    let payout_key = Address::generate(env);
+    let program_id = String::from_str(env, "excess-prog");
+    client.init_program(&program_id, &payout_key, &token_addr, &admin, &None, &None);
+
+    token_sac.mint(&client.address, &initial_balance);
+    client.lock_program_funds(&program_id, &initial_balance);
+
+    Setup {
+        client,
+        token: token::Client::new(env, &token_addr),
+        program_id,
+    }
+}
+
+#[test]
+fn test_withdraw_valid_excess() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let treasury = Address::generate(&env);
+
+    let data = s
+        .client
+        .withdraw_excess(&s.program_id, &4_000, &treasury, &reason(&env), &0);
+
+    assert_eq!(data.remaining_balance, 6_000);
+    assert_eq!(data.total_funds, 6_000);
+    assert_eq!(s.token.balance(&treasury), 4_000);
+    assert_eq!(s.token.balance(&s.client.address), 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_withdraw_below_reserved_fails() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client.create_program_release_schedule(
+        &s.program_id,
+        &7_000,
+        &2_000,
+        &Address::generate(&env),
+    );
+
+    // Only 3_000 is unreserved.
+    s.client
+        .withdraw_excess(&s.program_id, &3_001, &Address::generate(&env), &reason(&env), &0);
+}
+
+#[test]
+fn test_withdraw_up_to_reserved_boundary() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client.create_program_release_schedule(
+        &s.program_id,
+        &7_000,
+        &2_000,
+        &Address::generate(&env),
+    );
+
+    let data = s
+        .client
+        .withdraw_excess(&s.program_id, &3_000, &Address::generate(&env), &reason(&env), &0);
+    assert_eq!(data.remaining_balance, 7_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_withdraw_zero_rejected() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+
+    s.client
+        .withdraw_excess(&s.program_id, &0, &Address::generate(&env), &reason(&env), &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")]
+fn test_withdraw_rejects_replayed_nonce() {
+    let env = Env::default();
+    let s = setup(&env, 10_000);
+    let treasury = Address::generate(&env);
+
+    s.client.withdraw_excess(&s.program_id, &100, &treasury, &reason(&env), &0);
+    s.client.withdraw_excess(&s.program_id, &100, &treasury, &reason(&env), &0);
+}
+
+fn reason(env: &Env) -> String {
+    String::from_str(env, "over-funded")
+}
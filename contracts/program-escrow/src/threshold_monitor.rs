@@ -6,7 +6,7 @@
 // for failure rates and token outflow volumes. Monitors operations in sliding
 // time windows and opens the circuit breaker when abnormal patterns are detected.
 
-use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
 // ─────────────────────────────────────────────────────────
 // Types
@@ -28,18 +28,34 @@ pub struct ThresholdConfig {
     pub cooldown_period_secs: u64,
     /// Backoff multiplier for repeated breaches
     pub cooldown_multiplier: u32,
+    /// Share of `outflow_volume_threshold`, in basis points, at which a
+    /// `THRESHOLD_WARNING` fires once per window (`0` disables warnings)
+    pub warn_ratio_bps: u32,
+    /// Largest single outflow permitted within one window; a larger payout
+    /// is refused even under the aggregate limit (`0` disables)
+    pub window_max_single_outflow: i128,
 }
 
 impl ThresholdConfig {
-    /// Default configuration with conservative thresholds
+    /// Default configuration with conservative thresholds, assuming a
+    /// 7-decimal token
     pub fn default() -> Self {
+        Self::default_for_decimals(7)
+    }
+
+    /// Default configuration with the amount-based limits expressed in
+    /// whole tokens of a `decimals`-decimal asset
+    pub fn default_for_decimals(decimals: u32) -> Self {
+        let unit = 10_i128.saturating_pow(decimals);
         ThresholdConfig {
             failure_rate_threshold: 10,
-            outflow_volume_threshold: 5_000_000_0000000, // 5M tokens (7 decimals)
-            max_single_payout: 500_000_0000000,          // 500K tokens
-            time_window_secs: 600,                       // 10 minutes
-            cooldown_period_secs: 300,                   // 5 minutes
+            outflow_volume_threshold: unit.saturating_mul(5_000_000), // 5M tokens
+            max_single_payout: unit.saturating_mul(500_000),          // 500K tokens
+            time_window_secs: 600,                                    // 10 minutes
+            cooldown_period_secs: 300,                                // 5 minutes
             cooldown_multiplier: 2,
+            warn_ratio_bps: 0,
+            window_max_single_outflow: 0,
         }
     }
 
@@ -61,10 +77,27 @@ impl ThresholdConfig {
         if self.cooldown_period_secs < 60 || self.cooldown_period_secs > 3600 {
             return Err("Cooldown period must be between 60 and 3600 seconds");
         }
+        if self.warn_ratio_bps > 10_000 {
+            return Err("Warn ratio must be at most 10000 basis points");
+        }
+        if self.window_max_single_outflow < 0 {
+            return Err("Window max single outflow must not be negative");
+        }
         Ok(())
     }
 }
 
+/// How the start of a new outflow window is chosen
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WindowAlignment {
+    /// A window starts whenever the previous one is rotated out
+    Rolling,
+    /// A window starts on a multiple of `time_window_secs` (e.g. on the
+    /// hour for 3600), for predictable reporting periods
+    Calendar,
+}
+
 /// Current metrics for a time window
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -121,6 +154,27 @@ pub enum ThresholdKey {
     PreviousMetrics,
     LastCooldownEnd,
     CooldownMultiplier,
+    DailyLimit,
+    DailyOutflow,
+    TokenConfig(Address),
+    TokenMetrics(Address),
+    WarnedWindow,
+    LastBreachAt,
+    MetricsHistory,
+    MetricsHistoryLen,
+    DefaultDecimals,
+    WindowAlignment,
+    ConfiguredTokens,
+}
+
+/// Outflow accumulated during a single UTC day
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DailyOutflow {
+    /// Day index (`timestamp / SECONDS_PER_DAY`)
+    pub day: u64,
+    /// Total outflow recorded for that day
+    pub total_outflow: i128,
 }
 
 // ─────────────────────────────────────────────────────────
@@ -131,6 +185,24 @@ pub const ERR_THRESHOLD_BREACHED: u32 = 2001;
 pub const ERR_INVALID_THRESHOLD_CONFIG: u32 = 2002;
 pub const ERR_COOLDOWN_ACTIVE: u32 = 2003;
 pub const ERR_WINDOW_NOT_EXPIRED: u32 = 2004;
+pub const ERR_DAILY_LIMIT_EXCEEDED: u32 = 2005;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Closed windows kept in the metrics history unless configured otherwise
+pub const DEFAULT_METRICS_HISTORY_LEN: u32 = 24;
+
+/// Upper bound on the configurable metrics history length
+pub const MAX_METRICS_HISTORY_LEN: u32 = 168;
+
+/// Event topic carrying the full `WindowMetrics` of a window that just closed
+pub const WINDOW_ROLLED: Symbol = symbol_short!("win_roll");
+
+/// Event topic published when an operator clears an active cooldown.
+pub const COOLDOWN_CLEARED: Symbol = symbol_short!("cd_clear");
+
+/// Event topic published once per window when outflow crosses `warn_ratio_bps`.
+pub const THRESHOLD_WARNING: Symbol = symbol_short!("th_warn");
 
 
 // ─────────────────────────────────────────────────────────
@@ -144,7 +216,7 @@ pub fn init_threshold_monitor(env: &Env) {
         .persistent()
         .set(&ThresholdKey::Config, &config);
     
-    let metrics = WindowMetrics::new(env.ledger().timestamp());
+    let metrics = WindowMetrics::new(window_start_at(env, &config, env.ledger().timestamp()));
     env.storage()
         .persistent()
         .set(&ThresholdKey::CurrentMetrics, &metrics);
@@ -176,12 +248,37 @@ pub fn set_threshold_config(env: &Env, config: ThresholdConfig) -> Result<(), u3
     Ok(())
 }
 
-/// Get current threshold configuration
+/// Whether a configuration has been stored (otherwise defaults apply)
+pub fn has_threshold_config(env: &Env) -> bool {
+    env.storage().persistent().has(&ThresholdKey::Config)
+}
+
+/// Get current threshold configuration. Without a stored one, defaults
+/// are scaled to the decimals recorded by `set_default_decimals`.
 pub fn get_threshold_config(env: &Env) -> ThresholdConfig {
     env.storage()
         .persistent()
         .get(&ThresholdKey::Config)
-        .unwrap_or(ThresholdConfig::default())
+        .unwrap_or_else(|| match get_default_decimals(env) {
+            Some(decimals) => ThresholdConfig::default_for_decimals(decimals),
+            None => ThresholdConfig::default(),
+        })
+}
+
+/// Record the token decimals the fallback defaults are scaled to. Only
+/// the first call takes effect, so defaults never shift under a live
+/// deployment.
+pub fn set_default_decimals(env: &Env, decimals: u32) {
+    if !env.storage().persistent().has(&ThresholdKey::DefaultDecimals) {
+        env.storage()
+            .persistent()
+            .set(&ThresholdKey::DefaultDecimals, &decimals);
+    }
+}
+
+/// Decimals the fallback defaults are scaled to, if recorded
+pub fn get_default_decimals(env: &Env) -> Option<u32> {
+    env.storage().persistent().get(&ThresholdKey::DefaultDecimals)
 }
 
 
@@ -218,6 +315,7 @@ pub fn record_outflow(env: &Env, amount: i128) {
     rotate_window_if_needed(env);
     
     let mut metrics = get_current_metrics(env);
+    let before = metrics.total_outflow;
     metrics.total_outflow = metrics.total_outflow.saturating_add(amount);
     
     if amount > metrics.max_single_outflow {
@@ -227,6 +325,53 @@ pub fn record_outflow(env: &Env, amount: i128) {
     env.storage()
         .persistent()
         .set(&ThresholdKey::CurrentMetrics, &metrics);
+
+    let threshold = get_threshold_config(env).outflow_volume_threshold;
+    if before < threshold && metrics.total_outflow >= threshold {
+        env.storage()
+            .persistent()
+            .set(&ThresholdKey::LastBreachAt, &env.ledger().timestamp());
+    }
+
+    warn_if_near_outflow_threshold(env, &metrics);
+    record_daily_outflow(env, amount);
+}
+
+/// When window outflow last reached `outflow_volume_threshold`, if ever.
+pub fn get_last_breach_at(env: &Env) -> Option<u64> {
+    env.storage().persistent().get(&ThresholdKey::LastBreachAt)
+}
+
+/// Emit `THRESHOLD_WARNING` the first time a window's outflow reaches
+/// `warn_ratio_bps` of `outflow_volume_threshold`. Never blocks.
+fn warn_if_near_outflow_threshold(env: &Env, metrics: &WindowMetrics) {
+    let config = get_threshold_config(env);
+    if config.warn_ratio_bps == 0 {
+        return;
+    }
+    let warn_at = config
+        .outflow_volume_threshold
+        .saturating_mul(config.warn_ratio_bps as i128)
+        / 10_000;
+    if metrics.total_outflow < warn_at {
+        return;
+    }
+    let warned: Option<u64> = env.storage().persistent().get(&ThresholdKey::WarnedWindow);
+    if warned == Some(metrics.window_start) {
+        return;
+    }
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::WarnedWindow, &metrics.window_start);
+    env.events().publish(
+        (THRESHOLD_WARNING, symbol_short!("outflow")),
+        (
+            metrics.total_outflow,
+            config.outflow_volume_threshold,
+            config.warn_ratio_bps,
+            metrics.window_start,
+        ),
+    );
 }
 
 /// Get current window metrics
@@ -234,7 +379,27 @@ pub fn get_current_metrics(env: &Env) -> WindowMetrics {
     env.storage()
         .persistent()
         .get(&ThresholdKey::CurrentMetrics)
-        .unwrap_or_else(|| WindowMetrics::new(env.ledger().timestamp()))
+        .unwrap_or_else(|| {
+            let config = get_threshold_config(env);
+            WindowMetrics::new(window_start_at(env, &config, env.ledger().timestamp()))
+        })
+}
+
+/// Failures and total recorded operations `(failures, failures + successes)`
+/// in the current window. Returns `(0, 0)` once the window has elapsed, as
+/// the next recorded operation will rotate it.
+pub fn get_failure_rate(env: &Env) -> (u32, u32) {
+    let config = get_threshold_config(env);
+    let metrics = get_current_metrics(env);
+
+    if env.ledger().timestamp() >= metrics.window_start + config.time_window_secs {
+        return (0, 0);
+    }
+
+    (
+        metrics.failure_count,
+        metrics.failure_count.saturating_add(metrics.success_count),
+    )
 }
 
 /// Check if window has expired and rotate if needed
@@ -250,18 +415,97 @@ fn rotate_window_if_needed(env: &Env) {
         env.storage()
             .persistent()
             .set(&ThresholdKey::PreviousMetrics, &metrics);
+        push_metrics_history(env, &metrics);
         
         // Emit window rotation event
         emit_window_rotation_event(env, &metrics);
+        env.events().publish((WINDOW_ROLLED,), metrics.clone());
         
         // Create new window
-        let new_metrics = WindowMetrics::new(now);
+        let new_metrics = WindowMetrics::new(window_start_at(env, &config, now));
         env.storage()
             .persistent()
             .set(&ThresholdKey::CurrentMetrics, &new_metrics);
     }
 }
 
+/// Set how new windows are aligned (caller must enforce auth). Takes
+/// effect from the next rotation.
+pub fn set_window_alignment(env: &Env, alignment: WindowAlignment) {
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::WindowAlignment, &alignment);
+}
+
+/// Current window alignment (`Rolling` unless configured)
+pub fn get_window_alignment(env: &Env) -> WindowAlignment {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::WindowAlignment)
+        .unwrap_or(WindowAlignment::Rolling)
+}
+
+/// Start of a window opened at `now`: `now` itself when rolling, else
+/// `now` rounded down to a multiple of `time_window_secs`
+fn window_start_at(env: &Env, config: &ThresholdConfig, now: u64) -> u64 {
+    match get_window_alignment(env) {
+        WindowAlignment::Rolling => now,
+        WindowAlignment::Calendar => now / config.time_window_secs * config.time_window_secs,
+    }
+}
+
+/// Append a closed window to the history ring, dropping the oldest
+/// snapshots beyond the configured length
+fn push_metrics_history(env: &Env, metrics: &WindowMetrics) {
+    let len = get_metrics_history_len(env);
+    let mut history = get_metrics_history(env);
+    history.push_back(metrics.clone());
+    while history.len() > len {
+        history.pop_front();
+    }
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::MetricsHistory, &history);
+}
+
+/// Closed windows in the history ring, oldest first
+pub fn get_metrics_history(env: &Env) -> Vec<WindowMetrics> {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::MetricsHistory)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Number of closed windows the history ring keeps
+pub fn get_metrics_history_len(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::MetricsHistoryLen)
+        .unwrap_or(DEFAULT_METRICS_HISTORY_LEN)
+}
+
+/// Set the history ring length (caller must enforce auth), trimming
+/// already stored snapshots to fit. `0` stops keeping history.
+pub fn set_metrics_history_len(env: &Env, len: u32) -> Result<(), u32> {
+    if len > MAX_METRICS_HISTORY_LEN {
+        return Err(ERR_INVALID_THRESHOLD_CONFIG);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::MetricsHistoryLen, &len);
+
+    let mut history = get_metrics_history(env);
+    while history.len() > len {
+        history.pop_front();
+    }
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::MetricsHistory, &history);
+
+    Ok(())
+}
+
 
 // ─────────────────────────────────────────────────────────
 // Threshold Checking
@@ -273,6 +517,15 @@ pub fn check_thresholds(env: &Env) -> Result<(), ThresholdBreach> {
     
     let config = get_threshold_config(env);
     let metrics = get_current_metrics(env);
+    evaluate_thresholds(env, &config, &metrics)
+}
+
+/// Compare window metrics against a configuration
+fn evaluate_thresholds(
+    env: &Env,
+    config: &ThresholdConfig,
+    metrics: &WindowMetrics,
+) -> Result<(), ThresholdBreach> {
     let now = env.ledger().timestamp();
     
     // Check failure rate threshold
@@ -310,10 +563,42 @@ pub fn check_thresholds(env: &Env) -> Result<(), ThresholdBreach> {
         };
         return Err(breach);
     }
+
+    // Check for a spike above the per-window single outflow limit
+    if config.window_max_single_outflow > 0
+        && metrics.max_single_outflow > config.window_max_single_outflow
+    {
+        let breach = ThresholdBreach {
+            metric_type: symbol_short!("spike"),
+            threshold_value: config.window_max_single_outflow,
+            actual_value: metrics.max_single_outflow,
+            timestamp: now,
+            breach_count: metrics.breach_count + 1,
+        };
+        return Err(breach);
+    }
     
     Ok(())
 }
 
+/// Outflow still allowed in the current window before the volume threshold
+/// trips (the full threshold once the window has elapsed)
+pub fn get_window_outflow_remaining(env: &Env) -> i128 {
+    let config = get_threshold_config(env);
+    let metrics = get_current_metrics(env);
+    outflow_remaining(env, &config, &metrics)
+}
+
+fn outflow_remaining(env: &Env, config: &ThresholdConfig, metrics: &WindowMetrics) -> i128 {
+    if env.ledger().timestamp() >= metrics.window_start + config.time_window_secs {
+        return config.outflow_volume_threshold;
+    }
+    config
+        .outflow_volume_threshold
+        .saturating_sub(metrics.total_outflow)
+        .max(0)
+}
+
 /// Check a single payout amount before execution
 pub fn check_single_payout_threshold(env: &Env, amount: i128) -> Result<(), ThresholdBreach> {
     let config = get_threshold_config(env);
@@ -334,6 +619,243 @@ pub fn check_single_payout_threshold(env: &Env, amount: i128) -> Result<(), Thre
     Ok(())
 }
 
+/// Check a payout amount against `window_max_single_outflow` before it is
+/// paid, so a spike is refused instead of only tripping the next payout.
+pub fn check_window_single_outflow(env: &Env, amount: i128) -> Result<(), ThresholdBreach> {
+    let config = get_threshold_config(env);
+    if config.window_max_single_outflow > 0 && amount > config.window_max_single_outflow {
+        return Err(ThresholdBreach {
+            metric_type: symbol_short!("spike"),
+            threshold_value: config.window_max_single_outflow,
+            actual_value: amount,
+            timestamp: env.ledger().timestamp(),
+            breach_count: get_current_metrics(env).breach_count + 1,
+        });
+    }
+    Ok(())
+}
+
+/// Check every entry of a batch against `max_single_payout`.
+///
+/// The batch aggregate can sit under the outflow volume threshold while a
+/// single recipient does not, so an oversized entry is reported as an
+/// `outflow` breach for the whole batch.
+pub fn check_batch_payout_amounts(env: &Env, amounts: &Vec<i128>) -> Result<(), ThresholdBreach> {
+    for amount in amounts.iter() {
+        if let Err(mut breach) = check_single_payout_threshold(env, amount) {
+            breach.metric_type = symbol_short!("outflow");
+            return Err(breach);
+        }
+    }
+    Ok(())
+}
+
+
+// ─────────────────────────────────────────────────────────
+// Per-Token Thresholds
+// ─────────────────────────────────────────────────────────
+
+/// Set a threshold configuration for payouts in `token` (admin only -
+/// caller must enforce auth). Tokens without one are only subject to the
+/// global configuration.
+pub fn set_threshold_config_for(env: &Env, token: &Address, config: ThresholdConfig) -> Result<(), u32> {
+    config.validate()
+        .map_err(|_| ERR_INVALID_THRESHOLD_CONFIG)?;
+    
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::TokenConfig(token.clone()), &config);
+
+    let mut tokens = get_configured_tokens(env);
+    if !tokens.contains(token) {
+        tokens.push_back(token.clone());
+        env.storage()
+            .persistent()
+            .set(&ThresholdKey::ConfiguredTokens, &tokens);
+    }
+    
+    emit_config_event(env, symbol_short!("th_token"), &config);
+    Ok(())
+}
+
+/// Tokens that have a per-token threshold configuration, in the order
+/// they were first configured
+pub fn get_configured_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::ConfiguredTokens)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Get the threshold configuration for `token`, if one has been set
+pub fn get_threshold_config_for(env: &Env, token: &Address) -> Option<ThresholdConfig> {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::TokenConfig(token.clone()))
+}
+
+/// Get the current window metrics for `token`
+pub fn get_window_metrics_for(env: &Env, token: &Address) -> WindowMetrics {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::TokenMetrics(token.clone()))
+        .unwrap_or_else(|| {
+            let now = env.ledger().timestamp();
+            match get_threshold_config_for(env, token) {
+                Some(config) => WindowMetrics::new(window_start_at(env, &config, now)),
+                None => WindowMetrics::new(now),
+            }
+        })
+}
+
+/// Rotate `token`'s window if it has expired and return the live metrics
+fn rotate_token_window_if_needed(env: &Env, token: &Address, config: &ThresholdConfig) -> WindowMetrics {
+    let metrics = get_window_metrics_for(env, token);
+    let now = env.ledger().timestamp();
+    
+    if now < metrics.window_start + config.time_window_secs {
+        return metrics;
+    }
+    
+    let new_metrics = WindowMetrics::new(window_start_at(env, config, now));
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::TokenMetrics(token.clone()), &new_metrics);
+    new_metrics
+}
+
+/// Check `token`'s thresholds before a payout. Always passes for tokens
+/// without their own configuration.
+pub fn check_token_thresholds(env: &Env, token: &Address) -> Result<(), ThresholdBreach> {
+    let config = match get_threshold_config_for(env, token) {
+        Some(config) => config,
+        None => return Ok(()),
+    };
+    
+    let mut metrics = rotate_token_window_if_needed(env, token, &config);
+    if let Err(breach) = evaluate_thresholds(env, &config, &metrics) {
+        metrics.breach_count += 1;
+        env.storage()
+            .persistent()
+            .set(&ThresholdKey::TokenMetrics(token.clone()), &metrics);
+        return Err(breach);
+    }
+    
+    Ok(())
+}
+
+/// Per-token counterpart of `get_window_outflow_remaining`; `i128::MAX` for
+/// tokens without their own configuration
+pub fn get_token_outflow_remaining(env: &Env, token: &Address) -> i128 {
+    match get_threshold_config_for(env, token) {
+        Some(config) => outflow_remaining(env, &config, &get_window_metrics_for(env, token)),
+        None => i128::MAX,
+    }
+}
+
+/// Record an outflow in `token` against its own window (no-op for tokens
+/// without their own configuration)
+pub fn record_token_outflow(env: &Env, token: &Address, amount: i128) {
+    let config = match get_threshold_config_for(env, token) {
+        Some(config) => config,
+        None => return,
+    };
+    
+    let mut metrics = rotate_token_window_if_needed(env, token, &config);
+    metrics.total_outflow = metrics.total_outflow.saturating_add(amount);
+    if amount > metrics.max_single_outflow {
+        metrics.max_single_outflow = amount;
+    }
+    
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::TokenMetrics(token.clone()), &metrics);
+}
+
+
+// ─────────────────────────────────────────────────────────
+// Daily Outflow Limit
+// ─────────────────────────────────────────────────────────
+
+/// Set the calendar-day outflow cap (admin only - caller must enforce auth).
+/// A limit of `0` disables the daily cap.
+pub fn set_daily_outflow_limit(env: &Env, limit: i128) -> Result<(), u32> {
+    if limit < 0 {
+        return Err(ERR_INVALID_THRESHOLD_CONFIG);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::DailyLimit, &limit);
+
+    env.events().publish(
+        (symbol_short!("th_cfg"), symbol_short!("daily")),
+        (limit, env.ledger().timestamp()),
+    );
+
+    Ok(())
+}
+
+/// Get the calendar-day outflow cap (`0` means unlimited)
+pub fn get_daily_outflow_limit(env: &Env) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::DailyLimit)
+        .unwrap_or(0)
+}
+
+/// Get the outflow recorded for the current UTC day
+pub fn get_daily_outflow(env: &Env) -> DailyOutflow {
+    let today = env.ledger().timestamp() / SECONDS_PER_DAY;
+    let stored: Option<DailyOutflow> = env.storage().persistent().get(&ThresholdKey::DailyOutflow);
+
+    match stored {
+        Some(outflow) if outflow.day == today => outflow,
+        _ => DailyOutflow {
+            day: today,
+            total_outflow: 0,
+        },
+    }
+}
+
+/// Remaining allowance for the current UTC day (`i128::MAX` when unlimited)
+pub fn get_daily_remaining(env: &Env) -> i128 {
+    let limit = get_daily_outflow_limit(env);
+    if limit == 0 {
+        return i128::MAX;
+    }
+
+    let outflow = get_daily_outflow(env);
+    limit.saturating_sub(outflow.total_outflow).max(0)
+}
+
+/// Check that `amount` fits in the current day's remaining allowance
+pub fn check_daily_limit(env: &Env, amount: i128) -> Result<(), u32> {
+    if amount > get_daily_remaining(env) {
+        env.events().publish(
+            (symbol_short!("th_breach"), symbol_short!("daily")),
+            (
+                get_daily_outflow_limit(env),
+                get_daily_outflow(env).total_outflow.saturating_add(amount),
+                env.ledger().timestamp(),
+            ),
+        );
+        return Err(ERR_DAILY_LIMIT_EXCEEDED);
+    }
+
+    Ok(())
+}
+
+/// Add `amount` to the current day's outflow, resetting at the day boundary
+fn record_daily_outflow(env: &Env, amount: i128) {
+    let mut outflow = get_daily_outflow(env);
+    outflow.total_outflow = outflow.total_outflow.saturating_add(amount);
+
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::DailyOutflow, &outflow);
+}
+
 
 // ─────────────────────────────────────────────────────────
 // Cooldown and Anti-Flapping Logic
@@ -351,6 +873,19 @@ pub fn is_cooldown_active(env: &Env) -> bool {
     now < last_cooldown_end
 }
 
+/// Get the timestamp at which the current cooldown ends (`0` if none applied)
+pub fn get_cooldown_end(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&ThresholdKey::LastCooldownEnd)
+        .unwrap_or(0)
+}
+
+/// Seconds until the current cooldown ends (`0` once it has expired)
+pub fn get_cooldown_remaining(env: &Env) -> u64 {
+    get_cooldown_end(env).saturating_sub(env.ledger().timestamp())
+}
+
 /// Get current cooldown multiplier
 pub fn get_cooldown_multiplier(env: &Env) -> u32 {
     env.storage()
@@ -391,6 +926,24 @@ pub fn reset_cooldown_multiplier(env: &Env) {
         .set(&ThresholdKey::CooldownMultiplier, &1u32);
 }
 
+/// End any active cooldown now and drop the backoff multiplier back to 1,
+/// so the next breach uses the base cooldown period (caller must enforce auth)
+pub fn clear_cooldown(env: &Env, admin: &Address) {
+    let now = env.ledger().timestamp();
+    let previous_end = get_cooldown_end(env);
+    let previous_multiplier = get_cooldown_multiplier(env);
+
+    env.storage()
+        .persistent()
+        .set(&ThresholdKey::LastCooldownEnd, &now);
+    reset_cooldown_multiplier(env);
+
+    env.events().publish(
+        (COOLDOWN_CLEARED,),
+        (admin.clone(), previous_end, previous_multiplier, now),
+    );
+}
+
 // ─────────────────────────────────────────────────────────
 // Manual Metrics Reset
 // ─────────────────────────────────────────────────────────
@@ -399,8 +952,9 @@ pub fn reset_cooldown_multiplier(env: &Env) {
 pub fn reset_metrics(env: &Env, admin: &Address) {
     let now = env.ledger().timestamp();
     
-    // Create new window starting now
-    let new_metrics = WindowMetrics::new(now);
+    // Create new window starting now (or at the enclosing boundary)
+    let config = get_threshold_config(env);
+    let new_metrics = WindowMetrics::new(window_start_at(env, &config, now));
     env.storage()
         .persistent()
         .set(&ThresholdKey::CurrentMetrics, &new_metrics);
@@ -427,6 +981,24 @@ pub fn emit_threshold_breach_event(env: &Env, breach: &ThresholdBreach) {
     );
 }
 
+/// Emit a threshold breach event with `program_id` as an extra topic, so
+/// indexers can subscribe to breaches hit by one program's payouts
+pub fn emit_program_threshold_breach_event(env: &Env, program_id: &String, breach: &ThresholdBreach) {
+    env.events().publish(
+        (
+            symbol_short!("th_breach"),
+            breach.metric_type.clone(),
+            program_id.clone(),
+        ),
+        (
+            breach.threshold_value,
+            breach.actual_value,
+            breach.timestamp,
+            breach.breach_count,
+        ),
+    );
+}
+
 /// Emit configuration initialization event
 fn emit_config_event(env: &Env, event_type: Symbol, config: &ThresholdConfig) {
     env.events().publish(
@@ -2,17 +2,22 @@
 //!
 //! ## Rounding Policy
 //!
-//! All fee calculations use **floor (round-down)** rounding. This means the
+//! Fee calculations default to **floor (round-down)** rounding. This means the
 //! protocol never overcharges — any remainder from basis-point division stays
 //! with the payer rather than being collected as fee. The invariant
 //! `fee + net == gross` holds for every split.
 //!
+//! The contract's configured [`RoundingMode`] can switch fee and share math
+//! to ceiling or banker's rounding via [`mul_div`].
+//!
 //! ## Token Decimals
 //!
 //! Stellar tokens can have different decimal places (e.g. 7 for XLM/stroops,
 //! 6 for USDC). The helpers here convert between decimal scales using floor
 //! rounding when scaling down (higher → lower precision).
 
+use soroban_sdk::contracttype;
+
 /// Basis-point denominator (1 bp = 0.01%).
 pub const BASIS_POINTS: i128 = 10_000;
 
@@ -34,6 +39,55 @@ pub fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         .unwrap_or(0)
 }
 
+/// Rounding direction applied to fee and share-split division.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    /// Round toward zero; never pays out more than the exact share.
+    Floor,
+    /// Round up whenever there is a remainder.
+    Ceil,
+    /// Round half to even; other fractions round to nearest.
+    BankersRound,
+}
+
+/// Compute `amount * numerator / denominator` rounded according to `mode`.
+///
+/// Intended for non-negative inputs. Returns `None` on overflow or when
+/// `denominator` is zero.
+pub fn mul_div(amount: i128, numerator: i128, denominator: i128, mode: RoundingMode) -> Option<i128> {
+    let product = amount.checked_mul(numerator)?;
+    let quotient = product.checked_div_euclid(denominator)?;
+    let remainder = product.rem_euclid(denominator);
+    if remainder == 0 {
+        return Some(quotient);
+    }
+    match mode {
+        RoundingMode::Floor => Some(quotient),
+        RoundingMode::Ceil => quotient.checked_add(1),
+        RoundingMode::BankersRound => {
+            let twice = remainder.checked_mul(2)?;
+            if twice > denominator || (twice == denominator && quotient % 2 != 0) {
+                quotient.checked_add(1)
+            } else {
+                Some(quotient)
+            }
+        }
+    }
+}
+
+/// Calculate fee using the given rounding mode.
+///
+/// `fee = round(amount * fee_rate / BASIS_POINTS)`
+///
+/// Returns 0 when `fee_rate` is 0 or on overflow.
+pub fn calculate_fee_rounded(amount: i128, fee_rate: i128, mode: RoundingMode) -> i128 {
+    if fee_rate == 0 {
+        return 0;
+    }
+    mul_div(amount, fee_rate, BASIS_POINTS, mode).unwrap_or(0)
+}
+
 /// Split `amount` into `(fee, net)` where `fee + net == amount`.
 ///
 /// Fee is floored; any remainder from division stays in `net`.